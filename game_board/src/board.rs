@@ -1,7 +1,39 @@
+use crate::coord::{Coord, ALL_DIRECTIONS, ORTHOGONAL_DIRECTIONS};
 use crate::square::Square;
+use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
 
+/// The errors that can happen when building or accessing a board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoardError {
+    /// The board was given a width or height of 0.
+    InvalidDimensions { width: usize, height: usize },
+    /// A square was accessed outside of the bounds of the board.
+    OutOfBounds { col: usize, row: usize },
+}
+
+impl Display for BoardError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BoardError::InvalidDimensions { .. } => {
+                write!(
+                    f,
+                    "Height and Width must be positive integers greater then 0"
+                )
+            }
+            BoardError::OutOfBounds { col, row } => {
+                write!(
+                    f,
+                    "square at column {col} and row {row} is outside of board bounds"
+                )
+            }
+        }
+    }
+}
+
+impl Error for BoardError {}
+
 /// Represents a game board that holds pieces of a certain type.
 ///
 /// The board is made up of a grid of squares, each of which can hold a piece.
@@ -19,8 +51,8 @@ pub struct Board<P> {
 impl<P> Board<P> {
     /// Create a new board of any size,
     ///
-    /// # Panics
-    /// The function will panic if either height or width is 0
+    /// # Errors
+    /// Returns `BoardError::InvalidDimensions` if either height or width is 0
     ///
     /// # Example
     /// ```
@@ -30,7 +62,7 @@ impl<P> Board<P> {
     ///
     ///assert!(board.is_ok());
     /// ```
-    pub fn build(width: usize, height: usize) -> Result<Board<P>, String> {
+    pub fn build(width: usize, height: usize) -> Result<Board<P>, BoardError> {
         Ok(Board {
             squares: Board::generate_board(width, height)?,
             width,
@@ -48,6 +80,137 @@ impl<P> Board<P> {
         self.height
     }
 
+    /// Iterates over every square of the board, rank by rank starting at `a1`.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let board = Board::<u8>::build(3, 2).unwrap();
+    ///
+    /// let names = board.iter().map(|square| square.get_name()).collect::<Vec<String>>();
+    /// assert_eq!(vec!["a1", "b1", "c1", "a2", "b2", "c2"], names);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &Square<P>> {
+        self.squares.iter()
+    }
+
+    /// Iterates mutably over every square of the board, rank by rank starting at `a1`.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let mut board = Board::<u8>::build(3, 3).unwrap();
+    /// board
+    ///     .iter_mut()
+    ///     .filter(|square| square.get_row() == 1)
+    ///     .for_each(|square| square.place_piece(1));
+    ///
+    /// assert_eq!(3, board.iter_pieces().count());
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Square<P>> {
+        self.squares.iter_mut()
+    }
+
+    /// Iterates over the pieces on the board, rank by rank starting at `a1`.
+    ///
+    /// # Returns
+    ///
+    /// An iterator of the column, row and a reference to the piece of every
+    /// occupied square.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let mut board = Board::<u8>::build(8, 8).unwrap();
+    /// board.place_piece(1, 3, 4);
+    /// board.place_piece(2, 0, 0);
+    ///
+    /// let pieces = board.iter_pieces().collect::<Vec<(usize, usize, &u8)>>();
+    /// assert_eq!(vec![(0, 0, &2), (3, 4, &1)], pieces);
+    /// ```
+    pub fn iter_pieces(&self) -> impl Iterator<Item = (usize, usize, &P)> {
+        self.squares.iter().filter_map(|square| {
+            square
+                .get_piece()
+                .map(|piece| (square.get_column(), square.get_row(), piece))
+        })
+    }
+
+    /// Iterates over the squares of a single row, from column 0 upwards.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(impl Iterator)` - The squares of the row.
+    /// * `Err(BoardError::OutOfBounds)` - If the row is not on the board.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::{Board, BoardError};
+    ///
+    /// let board = Board::<u8>::build(3, 3).unwrap();
+    ///
+    /// let names = board.iter_rank(1).unwrap().map(|square| square.get_name()).collect::<Vec<String>>();
+    /// assert_eq!(vec!["a2", "b2", "c2"], names);
+    /// assert_eq!(Some(BoardError::OutOfBounds { col: 0, row: 3 }), board.iter_rank(3).err());
+    /// ```
+    pub fn iter_rank(&self, row: usize) -> Result<impl Iterator<Item = &Square<P>>, BoardError> {
+        self.check_bounds(0, row)?;
+        let start = self.get_square_index(0, row);
+        Ok(self.squares[start..start + self.width].iter())
+    }
+
+    /// Iterates over the squares of a single column, from row 0 upwards.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(impl Iterator)` - The squares of the column.
+    /// * `Err(BoardError::OutOfBounds)` - If the column is not on the board.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::{Board, BoardError};
+    ///
+    /// let board = Board::<u8>::build(3, 3).unwrap();
+    ///
+    /// let names = board.iter_file(1).unwrap().map(|square| square.get_name()).collect::<Vec<String>>();
+    /// assert_eq!(vec!["b1", "b2", "b3"], names);
+    /// assert_eq!(Some(BoardError::OutOfBounds { col: 3, row: 0 }), board.iter_file(3).err());
+    /// ```
+    pub fn iter_file(&self, col: usize) -> Result<impl Iterator<Item = &Square<P>>, BoardError> {
+        self.check_bounds(col, 0)?;
+        Ok(self.squares[col..].iter().step_by(self.width))
+    }
+
+    /// Finds the first piece matching a predicate, searching rank by rank starting at `a1`.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<(usize, usize)>` - Some containing the column and row of the
+    ///   first matching piece, or None if no piece matches.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let mut board = Board::<u8>::build(8, 8).unwrap();
+    /// board.place_piece(1, 3, 4);
+    /// board.place_piece(2, 5, 6);
+    ///
+    /// assert_eq!(Some((5, 6)), board.find(|piece| *piece == 2));
+    /// assert_eq!(None, board.find(|piece| *piece == 3));
+    /// ```
+    pub fn find<F>(&self, mut predicate: F) -> Option<(usize, usize)>
+    where
+        F: FnMut(&P) -> bool,
+    {
+        self.iter_pieces()
+            .find(|(_, _, piece)| predicate(piece))
+            .map(|(col, row, _)| (col, row))
+    }
+
     /// get piece at square
     ///
     /// # Arguments
@@ -165,11 +328,239 @@ impl<P> Board<P> {
         self.squares[square_index].clear_piece()
     }
 
-    fn generate_board(width: usize, height: usize) -> Result<Vec<Square<P>>, String> {
+    /// Check if a square is on the board
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column index (x-coordinate) of the square.
+    /// * `row` - The row index (y-coordinate) of the square.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let board = Board::<u8>::build(8, 8).unwrap();
+    ///
+    /// assert!(board.is_in_bounds(7, 7));
+    /// assert!(!board.is_in_bounds(8, 7));
+    /// ```
+    pub fn is_in_bounds(&self, col: usize, row: usize) -> bool {
+        col < self.width && row < self.height
+    }
+
+    /// Get the square at a signed offset from another square
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column index (x-coordinate) to start from.
+    /// * `row` - The row index (y-coordinate) to start from.
+    /// * `col_offset` - The number of columns to move, negative to move left.
+    /// * `row_offset` - The number of rows to move, negative to move down.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<(usize, usize)>` - Some containing the column and row of the
+    ///   square at the offset, or None if it is off the board.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let board = Board::<u8>::build(8, 8).unwrap();
+    ///
+    /// assert_eq!(Some((1, 2)), board.get_offset(0, 0, 1, 2));
+    /// assert_eq!(None, board.get_offset(0, 0, -1, 2));
+    /// assert_eq!(None, board.get_offset(7, 7, 0, 1));
+    /// ```
+    pub fn get_offset(
+        &self,
+        col: usize,
+        row: usize,
+        col_offset: i32,
+        row_offset: i32,
+    ) -> Option<(usize, usize)> {
+        let new_col = col.checked_add_signed(col_offset as isize)?;
+        let new_row = row.checked_add_signed(row_offset as isize)?;
+        match self.is_in_bounds(new_col, new_row) {
+            true => Some((new_col, new_row)),
+            false => None,
+        }
+    }
+
+    /// get piece at square, without panicking
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column index (x-coordinate) of the space to check.
+    /// * `row` - The row index (y-coordinate) of the space to check.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Option<&P>)` - Some containing a reference to the piece if there
+    ///   is one at the specified position, or None if the space is empty.
+    /// * `Err(BoardError::OutOfBounds)` - If the space is not on the board.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::{Board, BoardError};
+    ///
+    /// let mut board = Board::<u8>::build(8, 8).unwrap();
+    /// board.place_piece(1, 3, 4);
+    ///
+    /// assert_eq!(Ok(Some(&1)), board.try_get(3, 4));
+    /// assert_eq!(Ok(None), board.try_get(4, 4));
+    /// assert_eq!(Err(BoardError::OutOfBounds { col: 8, row: 4 }), board.try_get(8, 4));
+    /// ```
+    pub fn try_get(&self, col: usize, row: usize) -> Result<Option<&P>, BoardError> {
+        self.check_bounds(col, row)?;
+        let square_index = self.get_square_index(col, row);
+        Ok(self.squares[square_index].get_piece())
+    }
+
+    /// Places a piece at the given square, without panicking
+    ///
+    /// # Arguments
+    ///
+    /// * `piece` - The piece to place on the board.
+    /// * `col` - The column index (x-coordinate) where the piece will be placed.
+    /// * `row` - The row index (y-coordinate) where the piece will be placed.
+    ///
+    /// # Returns
+    ///
+    /// * `Err(BoardError::OutOfBounds)` - If the square is not on the board, in
+    ///   which case the piece is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let mut board = Board::<u8>::build(8, 8).unwrap();
+    ///
+    /// assert!(board.try_place(1, 3, 4).is_ok());
+    /// assert!(board.try_place(1, 3, 8).is_err());
+    /// ```
+    pub fn try_place(&mut self, piece: P, col: usize, row: usize) -> Result<(), BoardError> {
+        self.check_bounds(col, row)?;
+        let square_index = self.get_square_index(col, row);
+        self.squares[square_index].place_piece(piece);
+        Ok(())
+    }
+
+    /// Removes a piece from the given square, without panicking
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column index (x-coordinate) where the piece will be removed.
+    /// * `row` - The row index (y-coordinate) where the piece will be removed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Option<P>)` - Some containing the removed piece if there was one,
+    ///   or None if the space was already empty.
+    /// * `Err(BoardError::OutOfBounds)` - If the space is not on the board.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let mut board = Board::<u8>::build(8, 8).unwrap();
+    /// board.place_piece(1, 3, 4);
+    ///
+    /// assert_eq!(Ok(Some(1)), board.try_remove(3, 4));
+    /// assert_eq!(Ok(None), board.try_remove(3, 4));
+    /// assert!(board.try_remove(3, 8).is_err());
+    /// ```
+    pub fn try_remove(&mut self, col: usize, row: usize) -> Result<Option<P>, BoardError> {
+        self.check_bounds(col, row)?;
+        let square_index = self.get_square_index(col, row);
+        Ok(self.squares[square_index].clear_piece())
+    }
+
+    /// Follows a direction from a square until the edge of the board or the first occupied square.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The square to start from, which is not part of the ray.
+    /// * `direction` - The column and row offset of a single step, like `(1, 1)` for a diagonal.
+    ///
+    /// # Returns
+    ///
+    /// An iterator of the squares along the ray in order, including the first occupied square
+    /// if there is one, so the piece blocking the ray can be looked up.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::{Board, Coord};
+    ///
+    /// let mut board = Board::<u8>::build(8, 8).unwrap();
+    /// board.place_piece(1, 0, 3);
+    ///
+    /// let ray = board.ray(Coord::new(0, 0), (0, 1)).collect::<Vec<Coord>>();
+    /// assert_eq!(vec![Coord::new(0, 1), Coord::new(0, 2), Coord::new(0, 3)], ray);
+    /// ```
+    pub fn ray(&self, from: Coord, direction: (i32, i32)) -> impl Iterator<Item = Coord> + '_ {
+        let mut col = from.get_col() as isize;
+        let mut row = from.get_row() as isize;
+        let mut blocked = direction == (0, 0);
+        std::iter::from_fn(move || {
+            if blocked {
+                return None;
+            }
+            col += direction.0 as isize;
+            row += direction.1 as isize;
+            if col < 0 || row < 0 || col as usize >= self.width || row as usize >= self.height {
+                blocked = true;
+                return None;
+            }
+
+            let (col, row) = (col as usize, row as usize);
+            blocked = self.squares[self.get_square_index(col, row)]
+                .get_piece()
+                .is_some();
+            Some(Coord::new(col, row))
+        })
+    }
+
+    /// Gets the squares on the board that share an edge with a square.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::{Board, Coord};
+    ///
+    /// let board = Board::<u8>::build(8, 8).unwrap();
+    ///
+    /// assert_eq!(2, board.neighbours_4(Coord::new(0, 0)).len());
+    /// assert_eq!(4, board.neighbours_4(Coord::new(3, 3)).len());
+    /// ```
+    pub fn neighbours_4(&self, coord: Coord) -> Vec<Coord> {
+        self.neighbours(coord, &ORTHOGONAL_DIRECTIONS)
+    }
+
+    /// Gets the squares on the board that share an edge or a corner with a square.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::{Board, Coord};
+    ///
+    /// let board = Board::<u8>::build(8, 8).unwrap();
+    ///
+    /// assert_eq!(3, board.neighbours_8(Coord::new(0, 0)).len());
+    /// assert_eq!(8, board.neighbours_8(Coord::new(3, 3)).len());
+    /// ```
+    pub fn neighbours_8(&self, coord: Coord) -> Vec<Coord> {
+        self.neighbours(coord, &ALL_DIRECTIONS)
+    }
+
+    fn neighbours(&self, coord: Coord, directions: &[(i32, i32)]) -> Vec<Coord> {
+        directions
+            .iter()
+            .filter_map(|(col_offset, row_offset)| coord.offset_on(self, *col_offset, *row_offset))
+            .collect()
+    }
+
+    fn generate_board(width: usize, height: usize) -> Result<Vec<Square<P>>, BoardError> {
         if width == 0 || height == 0 {
-            return Err(String::from(
-                "Height and Width must be positive integers greater then 0",
-            ));
+            return Err(BoardError::InvalidDimensions { width, height });
         }
 
         let mut spaces = Vec::with_capacity(width * height);
@@ -188,13 +579,103 @@ impl<P> Board<P> {
         col + row * self.width
     }
 
+    fn check_bounds(&self, col: usize, row: usize) -> Result<(), BoardError> {
+        match self.is_in_bounds(col, row) {
+            true => Ok(()),
+            false => Err(BoardError::OutOfBounds { col, row }),
+        }
+    }
+
     fn validate_col_and_row(&self, col: usize, row: usize) {
-        if col >= self.width {
-            panic!("column outside of board bounds");
+        if let Err(e) = self.check_bounds(col, row) {
+            panic!("{e}");
         }
-        if row >= self.height {
-            panic!("row is outside of board bounds");
+    }
+}
+
+impl<P: Clone> Board<P> {
+    /// Flips the board top to bottom, so the first row becomes the last.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let mut board = Board::<u8>::build(3, 4).unwrap();
+    /// board.place_piece(1, 0, 0);
+    ///
+    /// assert_eq!(Some(&1), board.flipped_vertical().get_piece_at_space(0, 3));
+    /// ```
+    pub fn flipped_vertical(&self) -> Board<P> {
+        self.transformed(self.width, self.height, |col, row| {
+            (col, self.height - 1 - row)
+        })
+    }
+
+    /// Mirrors the board left to right, so the first column becomes the last.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let mut board = Board::<u8>::build(3, 4).unwrap();
+    /// board.place_piece(1, 0, 0);
+    ///
+    /// assert_eq!(Some(&1), board.mirrored_horizontal().get_piece_at_space(2, 0));
+    /// ```
+    pub fn mirrored_horizontal(&self) -> Board<P> {
+        self.transformed(self.width, self.height, |col, row| {
+            (self.width - 1 - col, row)
+        })
+    }
+
+    /// Rotates the board half a turn.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let mut board = Board::<u8>::build(3, 4).unwrap();
+    /// board.place_piece(1, 0, 0);
+    ///
+    /// assert_eq!(Some(&1), board.rotated_180().get_piece_at_space(2, 3));
+    /// ```
+    pub fn rotated_180(&self) -> Board<P> {
+        self.transformed(self.width, self.height, |col, row| {
+            (self.width - 1 - col, self.height - 1 - row)
+        })
+    }
+
+    /// Swaps the rows and columns of the board, mirroring it along the diagonal through `a1`.
+    ///
+    /// The width of the new board is the height of this one, and the other way around.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Board;
+    ///
+    /// let mut board = Board::<u8>::build(3, 4).unwrap();
+    /// board.place_piece(1, 2, 0);
+    ///
+    /// let transposed = board.transpose();
+    /// assert_eq!((4, 3), (transposed.get_width(), transposed.get_height()));
+    /// assert_eq!(Some(&1), transposed.get_piece_at_space(0, 2));
+    /// ```
+    pub fn transpose(&self) -> Board<P> {
+        self.transformed(self.height, self.width, |col, row| (row, col))
+    }
+
+    /// Builds a board of the given size, where every square of this board
+    /// is moved to the square `new_position` maps it to.
+    fn transformed<F>(&self, width: usize, height: usize, new_position: F) -> Board<P>
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let mut board = Board::build(width, height).unwrap();
+        for (col, row, piece) in self.iter_pieces() {
+            let (new_col, new_row) = new_position(col, row);
+            board.place_piece(piece.clone(), new_col, new_row);
         }
+        board
     }
 }
 
@@ -251,16 +732,28 @@ mod tests {
     #[test]
     fn can_not_make_board_with_height_or_width_of_0() {
         match Board::<MockPiece>::build(8, 0) {
-            Err(e) => assert_eq!(
-                "Height and Width must be positive integers greater then 0",
-                e
-            ),
+            Err(e) => {
+                assert_eq!(
+                    BoardError::InvalidDimensions {
+                        width: 8,
+                        height: 0
+                    },
+                    e
+                );
+                assert_eq!(
+                    "Height and Width must be positive integers greater then 0",
+                    e.to_string()
+                );
+            }
             _ => panic!("expected Err"),
         };
 
         match Board::<MockPiece>::build(0, 8) {
             Err(e) => assert_eq!(
-                "Height and Width must be positive integers greater then 0",
+                BoardError::InvalidDimensions {
+                    width: 0,
+                    height: 8
+                },
                 e
             ),
             _ => panic!("expected Err"),
@@ -306,4 +799,202 @@ mod tests {
     fn can_not_access_square_out_of_bounds_remove_piece() {
         Board::<MockPiece>::build(1, 1).unwrap().remove_piece(0, 1);
     }
+
+    #[test]
+    fn try_methods_return_errors_out_of_bounds() {
+        let mut board = Board::<u8>::build(2, 3).unwrap();
+        let out_of_bounds = BoardError::OutOfBounds { col: 2, row: 0 };
+
+        assert_eq!(Err(out_of_bounds), board.try_get(2, 0));
+        assert_eq!(Err(out_of_bounds), board.try_place(1, 2, 0));
+        assert_eq!(Err(out_of_bounds), board.try_remove(2, 0));
+        assert_eq!(
+            Err(BoardError::OutOfBounds { col: 0, row: 3 }),
+            board.try_get(0, 3)
+        );
+    }
+
+    #[test]
+    fn try_methods_place_retrieve_and_remove_pieces() {
+        let mut board = Board::<u8>::build(2, 3).unwrap();
+
+        assert_eq!(Ok(()), board.try_place(7, 1, 2));
+        assert_eq!(Ok(Some(&7)), board.try_get(1, 2));
+        assert_eq!(Ok(Some(7)), board.try_remove(1, 2));
+        assert_eq!(Ok(None), board.try_get(1, 2));
+    }
+
+    #[test]
+    fn get_offset_stays_on_the_board() {
+        let board = Board::<u8>::build(3, 3).unwrap();
+
+        assert_eq!(Some((2, 0)), board.get_offset(1, 1, 1, -1));
+        assert_eq!(Some((0, 2)), board.get_offset(2, 0, -2, 2));
+        assert_eq!(None, board.get_offset(0, 1, -1, 0));
+        assert_eq!(None, board.get_offset(1, 0, 0, -1));
+        assert_eq!(None, board.get_offset(1, 1, 2, 0));
+        assert_eq!(None, board.get_offset(1, 1, 0, 2));
+    }
+
+    #[test]
+    fn iterators_visit_squares_rank_by_rank() {
+        let mut board = Board::<u8>::build(3, 2).unwrap();
+        board.place_piece(1, 2, 0);
+        board.place_piece(2, 0, 1);
+
+        let positions = board
+            .iter()
+            .map(|square| (square.get_column(), square.get_row()))
+            .collect::<Vec<(usize, usize)>>();
+        assert_eq!(
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)],
+            positions
+        );
+
+        let pieces = board.iter_pieces().collect::<Vec<(usize, usize, &u8)>>();
+        assert_eq!(vec![(2, 0, &1), (0, 1, &2)], pieces);
+
+        board.iter_mut().for_each(|square| {
+            square.clear_piece();
+        });
+        assert_eq!(0, board.iter_pieces().count());
+    }
+
+    #[test]
+    fn iterate_ranks_and_files() {
+        let mut board = Board::<u8>::build(3, 4).unwrap();
+        board.place_piece(1, 1, 2);
+
+        let rank = board
+            .iter_rank(2)
+            .unwrap()
+            .map(|square| square.get_piece().copied())
+            .collect::<Vec<Option<u8>>>();
+        assert_eq!(vec![None, Some(1), None], rank);
+
+        let file = board
+            .iter_file(1)
+            .unwrap()
+            .map(|square| square.get_piece().copied())
+            .collect::<Vec<Option<u8>>>();
+        assert_eq!(vec![None, None, Some(1), None], file);
+    }
+
+    #[test]
+    fn iterate_ranks_and_files_out_of_bounds() {
+        let board = Board::<u8>::build(3, 4).unwrap();
+        assert_eq!(
+            Some(BoardError::OutOfBounds { col: 0, row: 4 }),
+            board.iter_rank(4).err()
+        );
+        assert_eq!(
+            Some(BoardError::OutOfBounds { col: 3, row: 0 }),
+            board.iter_file(3).err()
+        );
+    }
+
+    #[test]
+    fn find_returns_first_matching_piece() {
+        let mut board = Board::<u8>::build(4, 4).unwrap();
+        board.place_piece(1, 3, 3);
+        board.place_piece(1, 2, 1);
+        board.place_piece(2, 0, 0);
+
+        assert_eq!(Some((2, 1)), board.find(|piece| *piece == 1));
+        assert_eq!(None, board.find(|piece| *piece > 2));
+    }
+
+    #[test]
+    fn ray_stops_at_first_piece_or_edge() {
+        let mut board = Board::<u8>::build(5, 5).unwrap();
+        board.place_piece(1, 3, 3);
+        let ray = |col, row, direction| {
+            board
+                .ray(Coord::new(col, row), direction)
+                .collect::<Vec<Coord>>()
+        };
+
+        assert_eq!(vec![Coord::new(2, 2), Coord::new(3, 3)], ray(1, 1, (1, 1)));
+        assert_eq!(vec![Coord::new(0, 1)], ray(1, 0, (-1, 1)));
+        assert!(ray(4, 4, (1, 0)).is_empty());
+        assert!(ray(2, 2, (0, 0)).is_empty());
+        assert_eq!(vec![Coord::new(2, 1), Coord::new(4, 2)], ray(0, 0, (2, 1)));
+    }
+
+    #[test]
+    fn neighbourhoods_stay_on_the_board() {
+        let board = Board::<u8>::build(3, 2).unwrap();
+
+        let mut neighbours = board.neighbours_4(Coord::new(1, 0));
+        neighbours.sort();
+        assert_eq!(
+            vec![Coord::new(0, 0), Coord::new(2, 0), Coord::new(1, 1)],
+            neighbours
+        );
+        assert_eq!(5, board.neighbours_8(Coord::new(1, 0)).len());
+        assert_eq!(3, board.neighbours_8(Coord::new(2, 1)).len());
+    }
+
+    fn build_l_shape() -> Board<u8> {
+        let mut board = Board::<u8>::build(3, 2).unwrap();
+        board.place_piece(1, 0, 0);
+        board.place_piece(2, 1, 0);
+        board.place_piece(3, 0, 1);
+        board
+    }
+
+    fn pieces(board: &Board<u8>) -> Vec<(usize, usize, u8)> {
+        board
+            .iter_pieces()
+            .map(|(col, row, piece)| (col, row, *piece))
+            .collect()
+    }
+
+    #[test]
+    fn flip_mirror_and_rotate() {
+        let board = build_l_shape();
+
+        assert_eq!(
+            vec![(0, 0, 3), (0, 1, 1), (1, 1, 2)],
+            pieces(&board.flipped_vertical())
+        );
+        assert_eq!(
+            vec![(1, 0, 2), (2, 0, 1), (2, 1, 3)],
+            pieces(&board.mirrored_horizontal())
+        );
+        assert_eq!(
+            vec![(2, 0, 3), (1, 1, 2), (2, 1, 1)],
+            pieces(&board.rotated_180())
+        );
+    }
+
+    #[test]
+    fn transpose_swaps_width_and_height() {
+        let board = build_l_shape();
+        let transposed = board.transpose();
+
+        assert_eq!(2, transposed.get_width());
+        assert_eq!(3, transposed.get_height());
+        assert_eq!(vec![(0, 0, 1), (1, 0, 3), (0, 1, 2)], pieces(&transposed));
+    }
+
+    #[test]
+    fn transforms_undo_themselves() {
+        let board = build_l_shape();
+
+        assert_eq!(
+            pieces(&board),
+            pieces(&board.flipped_vertical().flipped_vertical())
+        );
+        assert_eq!(
+            pieces(&board),
+            pieces(&board.mirrored_horizontal().mirrored_horizontal())
+        );
+        assert_eq!(pieces(&board), pieces(&board.rotated_180().rotated_180()));
+        assert_eq!(pieces(&board), pieces(&board.transpose().transpose()));
+        assert_eq!(
+            pieces(&board.rotated_180()),
+            pieces(&board.flipped_vertical().mirrored_horizontal())
+        );
+    }
 }
@@ -0,0 +1,401 @@
+use crate::square::get_square_name_from_row_and_col;
+use crate::Board;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+/// The four directions along ranks and files.
+pub const ORTHOGONAL_DIRECTIONS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
+
+/// The four diagonal directions.
+pub const DIAGONAL_DIRECTIONS: [(i32, i32); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// All eight directions along ranks, files and diagonals.
+pub const ALL_DIRECTIONS: [(i32, i32); 8] = [
+    (0, 1),
+    (0, -1),
+    (1, 0),
+    (-1, 0),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// The error returned when a string can not be parsed as a `Coord`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCoordError {
+    input: String,
+}
+
+impl Display for ParseCoordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a valid square name", self.input)
+    }
+}
+
+impl Error for ParseCoordError {}
+
+/// The column and row of a square on a board.
+///
+/// Coordinates are ordered rank by rank, so `a1 < b1 < a2`, and can be
+/// used as keys in maps and sets.
+///
+/// # Examples
+///
+/// ```
+/// use game_board::Coord;
+///
+/// let coord: Coord = "e4".parse().unwrap();
+/// assert_eq!(4, coord.get_col());
+/// assert_eq!(3, coord.get_row());
+/// assert_eq!("e4", coord.to_string());
+///
+/// assert_eq!(Some(Coord::new(5, 5)), coord + (1, 2));
+/// assert_eq!(None, coord + (-5, 0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coord {
+    // The row comes first so the derived ordering goes rank by rank.
+    row: usize,
+    col: usize,
+}
+
+impl Coord {
+    /// Creates a new coordinate
+    ///
+    /// # Arguments
+    ///
+    /// * `col` - The column index (x-coordinate) of the square.
+    /// * `row` - The row index (y-coordinate) of the square.
+    pub const fn new(col: usize, row: usize) -> Coord {
+        Coord { row, col }
+    }
+
+    pub fn get_col(&self) -> usize {
+        self.col
+    }
+
+    pub fn get_row(&self) -> usize {
+        self.row
+    }
+
+    /// Get the coordinate at a signed offset from this one
+    ///
+    /// # Arguments
+    ///
+    /// * `col_offset` - The number of columns to move, negative to move left.
+    /// * `row_offset` - The number of rows to move, negative to move down.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<Coord>` - None if the offset would move past column or row 0.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Coord;
+    ///
+    /// assert_eq!(Some(Coord::new(1, 2)), Coord::new(0, 0).offset(1, 2));
+    /// assert_eq!(None, Coord::new(0, 0).offset(-1, 2));
+    /// ```
+    pub fn offset(&self, col_offset: i32, row_offset: i32) -> Option<Coord> {
+        let col = self.col.checked_add_signed(col_offset as isize)?;
+        let row = self.row.checked_add_signed(row_offset as isize)?;
+        Some(Coord::new(col, row))
+    }
+
+    /// Get the coordinate at a signed offset from this one, if it is on the board
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The board the coordinate has to be on.
+    /// * `col_offset` - The number of columns to move, negative to move left.
+    /// * `row_offset` - The number of rows to move, negative to move down.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::{Board, Coord};
+    ///
+    /// let board = Board::<u8>::build(8, 8).unwrap();
+    ///
+    /// assert_eq!(Some(Coord::new(7, 7)), Coord::new(6, 6).offset_on(&board, 1, 1));
+    /// assert_eq!(None, Coord::new(7, 7).offset_on(&board, 1, 0));
+    /// ```
+    pub fn offset_on<P>(
+        &self,
+        board: &Board<P>,
+        col_offset: i32,
+        row_offset: i32,
+    ) -> Option<Coord> {
+        self.offset(col_offset, row_offset)
+            .filter(|coord| coord.is_on(board))
+    }
+
+    /// Checks if the coordinate is within the bounds of a board
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::{Board, Coord};
+    ///
+    /// let board = Board::<u8>::build(8, 8).unwrap();
+    ///
+    /// assert!(Coord::new(7, 7).is_on(&board));
+    /// assert!(!Coord::new(8, 0).is_on(&board));
+    /// ```
+    pub fn is_on<P>(&self, board: &Board<P>) -> bool {
+        board.is_in_bounds(self.col, self.row)
+    }
+
+    /// The number of king moves needed to go from one square to another.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Coord;
+    ///
+    /// assert_eq!(3, Coord::new(0, 0).king_distance(&Coord::new(3, 2)));
+    /// ```
+    pub fn king_distance(&self, other: &Coord) -> usize {
+        self.col
+            .abs_diff(other.col)
+            .max(self.row.abs_diff(other.row))
+    }
+
+    /// The number of rook steps of one square needed to go from one square to another.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Coord;
+    ///
+    /// assert_eq!(5, Coord::new(0, 0).manhattan_distance(&Coord::new(3, 2)));
+    /// ```
+    pub fn manhattan_distance(&self, other: &Coord) -> usize {
+        self.col.abs_diff(other.col) + self.row.abs_diff(other.row)
+    }
+
+    /// Gets the squares strictly between two squares on the same rank, file or diagonal.
+    ///
+    /// # Returns
+    ///
+    /// The squares in order going from `self` to `other`, or an empty `Vec` if
+    /// the squares are not on a shared line or are next to each other.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Coord;
+    ///
+    /// let a1 = Coord::new(0, 0);
+    /// assert_eq!(vec![Coord::new(1, 1), Coord::new(2, 2)], a1.squares_between(&Coord::new(3, 3)));
+    /// assert!(a1.squares_between(&Coord::new(1, 2)).is_empty());
+    /// ```
+    pub fn squares_between(&self, other: &Coord) -> Vec<Coord> {
+        let (col_difference, row_difference) = *other - *self;
+        let is_line = col_difference == 0
+            || row_difference == 0
+            || col_difference.abs() == row_difference.abs();
+        if !is_line {
+            return Vec::new();
+        }
+
+        let direction = (col_difference.signum(), row_difference.signum());
+        let steps = col_difference.abs().max(row_difference.abs());
+        (1..steps)
+            .filter_map(|step| self.offset(direction.0 * step, direction.1 * step))
+            .collect()
+    }
+
+    /// Checks if three squares lie on a single rank, file or diagonal.
+    ///
+    /// # Example
+    /// ```
+    /// use game_board::Coord;
+    ///
+    /// let a1 = Coord::new(0, 0);
+    /// let c3 = Coord::new(2, 2);
+    /// assert!(Coord::is_aligned(&a1, &c3, &Coord::new(5, 5)));
+    /// assert!(!Coord::is_aligned(&a1, &c3, &Coord::new(5, 4)));
+    /// ```
+    pub fn is_aligned(a: &Coord, b: &Coord, c: &Coord) -> bool {
+        let is_line = |(col, row): (i32, i32)| col == 0 || row == 0 || col.abs() == row.abs();
+        let ab = *b - *a;
+        let ac = *c - *a;
+        let bc = *c - *b;
+        let on_one_line = ab.0 * ac.1 == ab.1 * ac.0;
+        on_one_line && is_line(ab) && is_line(ac) && is_line(bc)
+    }
+}
+
+impl From<(usize, usize)> for Coord {
+    fn from((col, row): (usize, usize)) -> Self {
+        Coord::new(col, row)
+    }
+}
+
+impl From<Coord> for (usize, usize) {
+    fn from(coord: Coord) -> Self {
+        (coord.col, coord.row)
+    }
+}
+
+impl Add<(i32, i32)> for Coord {
+    type Output = Option<Coord>;
+
+    fn add(self, (col_offset, row_offset): (i32, i32)) -> Self::Output {
+        self.offset(col_offset, row_offset)
+    }
+}
+
+impl Sub for Coord {
+    type Output = (i32, i32);
+
+    /// The offset that has to be added to `other` to reach `self`.
+    fn sub(self, other: Coord) -> Self::Output {
+        (
+            self.col as i32 - other.col as i32,
+            self.row as i32 - other.row as i32,
+        )
+    }
+}
+
+impl Display for Coord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            get_square_name_from_row_and_col(self.col, self.row)
+        )
+    }
+}
+
+impl FromStr for Coord {
+    type Err = ParseCoordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseCoordError {
+            input: s.to_string(),
+        };
+
+        let split = s
+            .find(|c: char| !c.is_ascii_lowercase())
+            .ok_or_else(error)?;
+        let (file, rank) = s.split_at(split);
+        if file.is_empty() || !rank.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(error());
+        }
+
+        let col = file.bytes().try_fold(0_usize, |col, b| {
+            col.checked_mul(26)?.checked_add((b - b'a') as usize + 1)
+        });
+        let row = rank.parse::<usize>().ok().filter(|row| *row > 0);
+
+        match (col, row) {
+            (Some(col), Some(row)) => Ok(Coord::new(col - 1, row - 1)),
+            _ => Err(error()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        [
+            (0, 0, "a1"),
+            (25, 1, "z2"),
+            (26, 0, "aa1"),
+            (18277, 99, "zzz100"),
+        ]
+        .into_iter()
+        .for_each(|(col, row, name)| {
+            let coord = Coord::new(col, row);
+            assert_eq!(Ok(coord), name.parse::<Coord>());
+            assert_eq!(name, coord.to_string());
+        });
+    }
+
+    #[test]
+    fn invalid_names_are_rejected() {
+        ["", "a", "1", "a0", "A1", "1a", "a1b", "a-1", "e 4"]
+            .into_iter()
+            .for_each(|name| {
+                assert_eq!(
+                    Err(ParseCoordError {
+                        input: name.to_string()
+                    }),
+                    name.parse::<Coord>()
+                );
+            });
+    }
+
+    #[test]
+    fn offsets() {
+        let coord = Coord::new(3, 3);
+        assert_eq!(Some(Coord::new(0, 6)), coord + (-3, 3));
+        assert_eq!(None, coord + (0, -4));
+        assert_eq!((-3, 3), Coord::new(0, 6) - coord);
+
+        let board = Board::<u8>::build(4, 4).unwrap();
+        assert!(coord.is_on(&board));
+        assert_eq!(None, coord.offset_on(&board, 1, 0));
+        assert_eq!(Some(Coord::new(2, 3)), coord.offset_on(&board, -1, 0));
+    }
+
+    #[test]
+    fn ordered_rank_by_rank_and_hashable() {
+        let mut coords = vec![Coord::new(0, 1), Coord::new(1, 0), Coord::new(0, 0)];
+        coords.sort();
+        assert_eq!(
+            vec![Coord::new(0, 0), Coord::new(1, 0), Coord::new(0, 1)],
+            coords
+        );
+
+        let set: HashSet<Coord> = coords.into_iter().chain([Coord::new(1, 0)]).collect();
+        assert_eq!(3, set.len());
+    }
+
+    #[test]
+    fn converts_to_and_from_tuples() {
+        let coord = Coord::from((2, 5));
+        assert_eq!(Coord::new(2, 5), coord);
+        assert_eq!((2, 5), coord.into());
+    }
+
+    #[test]
+    fn distances() {
+        let coord = Coord::new(4, 4);
+        assert_eq!(0, coord.king_distance(&coord));
+        assert_eq!(2, coord.king_distance(&Coord::new(2, 3)));
+        assert_eq!(3, coord.manhattan_distance(&Coord::new(2, 3)));
+        assert_eq!(8, coord.manhattan_distance(&Coord::new(0, 0)));
+    }
+
+    #[test]
+    fn squares_between_lines() {
+        let coord = Coord::new(3, 3);
+        assert_eq!(
+            vec![Coord::new(3, 2), Coord::new(3, 1)],
+            coord.squares_between(&Coord::new(3, 0))
+        );
+        assert_eq!(
+            vec![Coord::new(2, 4), Coord::new(1, 5)],
+            coord.squares_between(&Coord::new(0, 6))
+        );
+        assert!(coord.squares_between(&Coord::new(4, 4)).is_empty());
+        assert!(coord.squares_between(&coord).is_empty());
+        assert!(coord.squares_between(&Coord::new(5, 4)).is_empty());
+    }
+
+    #[test]
+    fn aligned_squares() {
+        let a = Coord::new(1, 1);
+        assert!(Coord::is_aligned(&a, &Coord::new(1, 5), &Coord::new(1, 0)));
+        assert!(Coord::is_aligned(&a, &Coord::new(4, 1), &Coord::new(7, 1)));
+        assert!(Coord::is_aligned(&a, &Coord::new(0, 2), &Coord::new(2, 0)));
+        assert!(!Coord::is_aligned(&a, &Coord::new(2, 3), &Coord::new(3, 5)));
+        assert!(!Coord::is_aligned(&a, &Coord::new(1, 5), &Coord::new(2, 5)));
+    }
+}
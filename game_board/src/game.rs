@@ -0,0 +1,165 @@
+/// How a finished game ended.
+///
+/// # Type Parameters
+///
+/// * `P` - The type identifying the players of the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome<P> {
+    /// The game was won by the given player.
+    Win(P),
+    /// The game ended without a winner.
+    Draw,
+}
+
+impl<P: Copy> Outcome<P> {
+    /// The winning player, `None` if the game was drawn.
+    pub fn winner(&self) -> Option<P> {
+        match self {
+            Outcome::Win(winner) => Some(*winner),
+            Outcome::Draw => None,
+        }
+    }
+}
+
+/// A game where players take turns making moves.
+///
+/// Implemented by the games built on `Board`, so tooling like random players,
+/// search and game loops can be written once and used for any of them.
+pub trait TurnBasedGame: Clone {
+    /// A single move in the game.
+    type Move: Clone + PartialEq;
+    /// Identifies one of the players, such as a color.
+    type Player: Copy + PartialEq;
+
+    /// The player whose turn it is.
+    fn current_player(&self) -> Self::Player;
+
+    /// Every move the current player can legally make.
+    ///
+    /// Takes `&mut self` so games can try moves on their own board while
+    /// generating them, the game has to be left as it was.
+    ///
+    /// # Returns
+    ///
+    /// The legal moves, an empty vector if the game is over.
+    fn legal_moves(&mut self) -> Vec<Self::Move>;
+
+    /// Plays a move for the current player and passes the turn.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_move` - One of the moves returned by `legal_moves`.
+    fn apply(&mut self, game_move: Self::Move);
+
+    /// Takes back the last move applied, does nothing if no moves have been
+    /// made.
+    fn undo(&mut self);
+
+    /// How the game ended.
+    ///
+    /// Takes `&mut self` for the same reason as `legal_moves`.
+    ///
+    /// # Returns
+    ///
+    /// `None` while the game is still being played. Games that let players
+    /// claim a draw, such as by repetition, decide whether a draw that could be
+    /// claimed ends the game. `legal_moves` is empty once this is `Some`.
+    fn outcome(&mut self) -> Option<Outcome<Self::Player>>;
+}
+
+/// Counts the leaf positions of the game tree to the given depth.
+///
+/// Positions where the game is over before `depth` is reached are not
+/// counted. Useful to verify move generation against known totals.
+///
+/// # Arguments
+///
+/// * `game` - The position to count from, restored before returning.
+/// * `depth` - The number of moves to look ahead.
+///
+/// # Returns
+///
+/// The number of move sequences of length `depth` from the position.
+pub fn perft<G: TurnBasedGame>(game: &mut G, depth: usize) -> usize {
+    if game.outcome().is_some() {
+        return 0;
+    }
+    let legal_moves = game.legal_moves();
+    if depth <= 1 {
+        return legal_moves.len();
+    }
+
+    legal_moves
+        .into_iter()
+        .map(|game_move| {
+            game.apply(game_move);
+            let count = perft(game, depth - 1);
+            game.undo();
+            count
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Players take 1 or 2 stones in turn, whoever takes the last stone wins.
+    #[derive(Clone)]
+    struct Nim {
+        stones: usize,
+        taken: Vec<usize>,
+    }
+
+    impl TurnBasedGame for Nim {
+        type Move = usize;
+        type Player = usize;
+
+        fn current_player(&self) -> usize {
+            self.taken.len() % 2
+        }
+
+        fn legal_moves(&mut self) -> Vec<usize> {
+            (1..=2).filter(|take| *take <= self.stones).collect()
+        }
+
+        fn apply(&mut self, game_move: usize) {
+            self.stones -= game_move;
+            self.taken.push(game_move);
+        }
+
+        fn undo(&mut self) {
+            if let Some(take) = self.taken.pop() {
+                self.stones += take;
+            }
+        }
+
+        fn outcome(&mut self) -> Option<Outcome<usize>> {
+            if self.stones == 0 {
+                Some(Outcome::Win(1 - self.current_player()))
+            } else {
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn perft_counts_move_sequences_and_restores_the_game() {
+        let mut game = Nim {
+            stones: 3,
+            taken: vec![],
+        };
+
+        assert_eq!(2, perft(&mut game, 1));
+        assert_eq!(3, perft(&mut game, 2));
+        assert_eq!(1, perft(&mut game, 3));
+        assert_eq!(3, game.stones);
+        assert!(game.taken.is_empty());
+    }
+
+    #[test]
+    fn outcome_winner() {
+        assert_eq!(Some(1), Outcome::Win(1).winner());
+        assert_eq!(None, Outcome::<usize>::Draw.winner());
+    }
+}
@@ -1,9 +1,26 @@
 mod board;
 mod color;
+mod coord;
+mod game;
+mod render;
 mod square;
 
 pub use board::Board;
+pub use board::BoardError;
 pub use color::SquareColor;
+pub use coord::Coord;
+pub use coord::ParseCoordError;
+pub use coord::ALL_DIRECTIONS;
+pub use coord::DIAGONAL_DIRECTIONS;
+pub use coord::ORTHOGONAL_DIRECTIONS;
+pub use game::perft;
+pub use game::Outcome;
+pub use game::TurnBasedGame;
+pub use render::BoardRenderer;
+pub use render::Orientation;
+pub use render::PieceStyle;
+pub use render::RenderPiece;
+pub use render::Theme;
 pub use square::get_column_and_row_from_square_name;
 pub use square::get_file_name;
 pub use square::get_rank_name;
@@ -0,0 +1,347 @@
+use crate::color::SquareColor;
+use crate::square::{get_file_name, get_rank_name};
+use crate::{Board, Coord};
+use std::fmt::Display;
+
+const RESET: &str = "\x1b[0m";
+
+/// A piece that can be drawn by a `BoardRenderer`.
+///
+/// The `Display` implementation is used for the glyph of the piece, usually a
+/// Unicode symbol, and `get_letter` for the plain text alternative.
+pub trait RenderPiece: Display {
+    /// A single ASCII letter for the piece, such as `N` for a white knight.
+    fn get_letter(&self) -> char;
+}
+
+/// The colors used to draw the squares of the board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    /// Background colors from the 256 color ANSI palette, supported by most
+    /// terminals.
+    Ansi256,
+    /// 24-bit RGB background colors, for terminals with truecolor support.
+    TrueColor,
+    /// No escape codes at all, for log files and dumb terminals. Empty squares
+    /// are drawn as `.`, highlighted squares in `[ ]` and the squares of the
+    /// last move in `( )`.
+    Plain,
+}
+
+/// How pieces are drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PieceStyle {
+    /// The `Display` output of the piece.
+    Glyph,
+    /// The letter from `RenderPiece::get_letter`.
+    Letter,
+}
+
+/// Which way round the board is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// Row 0 at the bottom and column 0 on the left, as White sees a chess
+    /// board.
+    Standard,
+    /// Rotated half a turn, with row 0 at the top and column 0 on the right.
+    Flipped,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SquareHighlight {
+    None,
+    LastMove,
+    Highlighted,
+}
+
+/// Draws a `Board` as text, for terminals or log files.
+///
+/// The renderer is configured with chained setters and can be reused for any
+/// number of boards.
+///
+/// # Example
+///
+/// ```
+/// use game_board::{Board, BoardRenderer, Coord, PieceStyle, RenderPiece, Theme};
+/// use std::fmt::{Display, Formatter};
+///
+/// struct Stone;
+///
+/// impl Display for Stone {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "●")
+///     }
+/// }
+///
+/// impl RenderPiece for Stone {
+///     fn get_letter(&self) -> char {
+///         'o'
+///     }
+/// }
+///
+/// let mut board = Board::build(3, 2).unwrap();
+/// board.place_piece(Stone, 0, 0);
+///
+/// let renderer = BoardRenderer::new()
+///     .set_theme(Theme::Plain)
+///     .set_piece_style(PieceStyle::Letter)
+///     .set_show_labels(true)
+///     .set_highlighted_squares(vec![Coord::new(1, 1)]);
+///
+/// assert_eq!("2  . [.] . \n1  o  .  . \n   a  b  c \n", renderer.render(&board));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoardRenderer {
+    theme: Theme,
+    piece_style: PieceStyle,
+    orientation: Orientation,
+    show_labels: bool,
+    last_move: Option<(Coord, Coord)>,
+    highlighted_squares: Vec<Coord>,
+}
+
+impl BoardRenderer {
+    /// Creates a renderer using the 256 color theme and piece glyphs, in the
+    /// standard orientation without labels or highlights.
+    pub fn new() -> Self {
+        Self {
+            theme: Theme::Ansi256,
+            piece_style: PieceStyle::Glyph,
+            orientation: Orientation::Standard,
+            show_labels: false,
+            last_move: None,
+            highlighted_squares: Vec::new(),
+        }
+    }
+
+    pub fn set_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn set_piece_style(mut self, piece_style: PieceStyle) -> Self {
+        self.piece_style = piece_style;
+        self
+    }
+
+    pub fn set_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Draws rank numbers on the left and file letters along the bottom.
+    pub fn set_show_labels(mut self, show_labels: bool) -> Self {
+        self.show_labels = show_labels;
+        self
+    }
+
+    /// Marks the start and end squares of the last move made.
+    pub fn set_last_move(mut self, last_move: Option<(Coord, Coord)>) -> Self {
+        self.last_move = last_move;
+        self
+    }
+
+    /// Marks any other squares of interest, such as the legal moves of a
+    /// selected piece. Highlighted squares are drawn over the last move.
+    pub fn set_highlighted_squares(mut self, highlighted_squares: Vec<Coord>) -> Self {
+        self.highlighted_squares = highlighted_squares;
+        self
+    }
+
+    pub fn get_theme(&self) -> Theme {
+        self.theme
+    }
+
+    pub fn get_piece_style(&self) -> PieceStyle {
+        self.piece_style
+    }
+
+    pub fn get_orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Draws the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The board to draw.
+    ///
+    /// # Returns
+    ///
+    /// The board as text, one line per row ending with a newline, followed by
+    /// a line of file labels when labels are shown.
+    pub fn render<P: RenderPiece>(&self, board: &Board<P>) -> String {
+        let rows: Vec<usize> = match self.orientation {
+            Orientation::Standard => (0..board.get_height()).rev().collect(),
+            Orientation::Flipped => (0..board.get_height()).collect(),
+        };
+        let cols: Vec<usize> = match self.orientation {
+            Orientation::Standard => (0..board.get_width()).collect(),
+            Orientation::Flipped => (0..board.get_width()).rev().collect(),
+        };
+        let label_width = get_rank_name(board.get_height() - 1).len();
+
+        let mut rendered = String::new();
+        for row in &rows {
+            if self.show_labels {
+                rendered.push_str(&format!("{:>label_width$} ", get_rank_name(*row)));
+            }
+            let rank = board
+                .iter_rank(*row)
+                .expect("The rows are on the board")
+                .collect::<Vec<_>>();
+            for col in &cols {
+                let square = rank[*col];
+                let piece = square.get_piece().map(|piece| match self.piece_style {
+                    PieceStyle::Glyph => piece.to_string(),
+                    PieceStyle::Letter => piece.get_letter().to_string(),
+                });
+                let highlight = self.get_highlight(Coord::new(*col, *row));
+                rendered.push_str(&self.render_square(square.get_color(), piece, highlight));
+            }
+            rendered.push('\n');
+        }
+
+        if self.show_labels {
+            rendered.push_str(&" ".repeat(label_width + 1));
+            for col in &cols {
+                rendered.push_str(&format!("{:^3}", get_file_name(*col)));
+            }
+            rendered.push('\n');
+        }
+
+        rendered
+    }
+
+    fn get_highlight(&self, coord: Coord) -> SquareHighlight {
+        if self.highlighted_squares.contains(&coord) {
+            SquareHighlight::Highlighted
+        } else if self
+            .last_move
+            .is_some_and(|(from, to)| from == coord || to == coord)
+        {
+            SquareHighlight::LastMove
+        } else {
+            SquareHighlight::None
+        }
+    }
+
+    fn render_square(
+        &self,
+        color: SquareColor,
+        piece: Option<String>,
+        highlight: SquareHighlight,
+    ) -> String {
+        if self.theme == Theme::Plain {
+            let inner = piece.unwrap_or_else(|| ".".to_string());
+            return match highlight {
+                SquareHighlight::None => format!(" {inner} "),
+                SquareHighlight::LastMove => format!("({inner})"),
+                SquareHighlight::Highlighted => format!("[{inner}]"),
+            };
+        }
+
+        let inner = piece.unwrap_or_else(|| " ".to_string());
+        let background = match (self.theme, highlight, color) {
+            (Theme::Ansi256, SquareHighlight::Highlighted, _) => "\x1b[48;5;67m",
+            (Theme::Ansi256, SquareHighlight::LastMove, _) => "\x1b[48;5;143m",
+            (Theme::Ansi256, SquareHighlight::None, SquareColor::White) => "\x1b[48;5;180m",
+            (Theme::Ansi256, SquareHighlight::None, SquareColor::Black) => "\x1b[48;5;137m",
+            (_, SquareHighlight::Highlighted, _) => "\x1b[48;2;100;140;190m",
+            (_, SquareHighlight::LastMove, _) => "\x1b[48;2;205;210;106m",
+            (_, SquareHighlight::None, SquareColor::White) => "\x1b[48;2;240;217;181m",
+            (_, SquareHighlight::None, SquareColor::Black) => "\x1b[48;2;181;136;99m",
+        };
+        format!("{background} {inner} {RESET}")
+    }
+}
+
+impl Default for BoardRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Formatter;
+
+    struct MockPiece;
+
+    impl Display for MockPiece {
+        fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+            write!(f, "♞")
+        }
+    }
+
+    impl RenderPiece for MockPiece {
+        fn get_letter(&self) -> char {
+            'N'
+        }
+    }
+
+    fn board_with_piece() -> Board<MockPiece> {
+        let mut board = Board::build(2, 2).unwrap();
+        board.place_piece(MockPiece, 0, 0);
+        board
+    }
+
+    fn plain() -> BoardRenderer {
+        BoardRenderer::new().set_theme(Theme::Plain)
+    }
+
+    #[test]
+    fn plain_theme_draws_glyphs_or_letters_without_escapes() {
+        let board = board_with_piece();
+        assert_eq!(" .  . \n ♞  . \n", plain().render(&board));
+        assert_eq!(
+            " .  . \n N  . \n",
+            plain().set_piece_style(PieceStyle::Letter).render(&board)
+        );
+    }
+
+    #[test]
+    fn flipped_orientation_turns_the_board_around() {
+        let board = board_with_piece();
+        let renderer = plain()
+            .set_orientation(Orientation::Flipped)
+            .set_show_labels(true);
+        assert_eq!("1  .  ♞ \n2  .  . \n   b  a \n", renderer.render(&board));
+    }
+
+    #[test]
+    fn labels_are_padded_to_the_widest_rank() {
+        let board = Board::<MockPiece>::build(1, 10).unwrap();
+        let rendered = plain().set_show_labels(true).render(&board);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!("10  . ", lines[0]);
+        assert_eq!(" 1  . ", lines[9]);
+        assert_eq!("    a ", lines[10]);
+    }
+
+    #[test]
+    fn highlights_are_drawn_over_the_last_move() {
+        let board = board_with_piece();
+        let renderer = plain()
+            .set_last_move(Some((Coord::new(1, 1), Coord::new(0, 0))))
+            .set_highlighted_squares(vec![Coord::new(1, 1)]);
+        assert_eq!(" . [.]\n(♞) . \n", renderer.render(&board));
+    }
+
+    #[test]
+    fn color_themes_use_their_escape_codes() {
+        let board = board_with_piece();
+
+        let rendered = BoardRenderer::new().render(&board);
+        assert!(rendered.contains("\x1b[48;5;180m"));
+        assert!(rendered.contains("\x1b[48;5;137m ♞ \x1b[0m"));
+
+        let rendered = BoardRenderer::new()
+            .set_theme(Theme::TrueColor)
+            .set_last_move(Some((Coord::new(0, 0), Coord::new(1, 1))))
+            .render(&board);
+        assert!(rendered.contains("\x1b[48;2;205;210;106m ♞ \x1b[0m"));
+        assert!(rendered.contains("\x1b[48;2;240;217;181m"));
+    }
+}
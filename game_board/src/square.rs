@@ -49,28 +49,30 @@ pub fn get_square_name_from_row_and_col(column: usize, row: usize) -> String {
 /// # Examples
 ///
 /// ```
+/// use game_board::get_rank_name;
+///
 /// let rank_name = get_rank_name(0); // For row 0 (0-based), rank name is 1 (1-based)
-/// assert_eq!(rank_name, 1);
+/// assert_eq!(rank_name, "1");
 ///
 /// let rank_name = get_rank_name(4); // For row 4 (0-based), rank name is 5 (1-based)
-/// assert_eq!(rank_name, 5);
+/// assert_eq!(rank_name, "5");
 /// ```
 pub fn get_rank_name(row: usize) -> String {
     format!("{}", row + 1)
 }
 
-/// Converts a column number (1-based) into a corresponding column name represented as a string.
+/// Converts a column number (0-based) into a corresponding column name represented as a string.
 ///
 /// The column name follows a pattern inspired by spreadsheet software like Excel,
 /// where columns are labeled in a sequential alphabetic order. For example:
-/// - Column 1 corresponds to "a"
-/// - Column 2 corresponds to "b"
-/// - Column 27 corresponds to "aa"
-/// - Column 28 corresponds to "ab"
+/// - Column 0 corresponds to "a"
+/// - Column 1 corresponds to "b"
+/// - Column 26 corresponds to "aa"
+/// - Column 27 corresponds to "ab"
 ///
 /// # Arguments
 ///
-/// * `column` - A 1-based column number to be converted into a string.
+/// * `column` - A 0-based column number to be converted into a string.
 ///
 /// # Returns
 ///
@@ -79,20 +81,17 @@ pub fn get_rank_name(row: usize) -> String {
 /// # Example
 ///
 /// ```
-/// let column_name = get_file_name(1);
+/// use game_board::get_file_name;
+///
+/// let column_name = get_file_name(0);
 /// assert_eq!(column_name, "a");
 ///
-/// let column_name = get_file_name(28);
+/// let column_name = get_file_name(27);
 /// assert_eq!(column_name, "ab");
 ///
-/// let column_name = get_file_name(703);
+/// let column_name = get_file_name(702);
 /// assert_eq!(column_name, "aaa");
 /// ```
-///
-/// # Note
-///
-/// - The input `column` should be a positive integer greater than or equal to 1.
-///   If a value less than 1 is passed, the function behavior is undefined.
 pub fn get_file_name(column: usize) -> String {
     let mut col_id = String::new();
     let mut remainder = column;
@@ -0,0 +1,435 @@
+use crate::checkers_game::DrawReason::{FortyMoveRule, Repetition};
+use crate::checkers_move::CheckersMoveType;
+use crate::checkers_move_analyzer::get_all_moves_for_color;
+use crate::piece::{CheckersPiece, PieceType};
+use crate::Color;
+use crate::Color::{Black, White};
+use game_board::{Board, Outcome, TurnBasedGame};
+
+#[derive(Debug, Clone)]
+pub struct CheckersGame {
+    board: Board<CheckersPiece>,
+    current_players_turn: Color,
+    moves: Vec<CheckersMoveType>,
+    forty_move_rule_counters: Vec<usize>,
+    previous_board_states: Vec<Vec<Option<CheckersPiece>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameState {
+    InProgress {
+        legal_moves: Vec<CheckersMoveType>,
+        turn: Color,
+    },
+    Win {
+        winner: Color,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawReason {
+    Repetition,
+    FortyMoveRule,
+}
+
+fn build_board_with_starting_position() -> Board<CheckersPiece> {
+    let mut board = Board::<CheckersPiece>::build(8, 8).unwrap();
+
+    for row in 0..3 {
+        for col in (row % 2..8).step_by(2) {
+            board.place_piece(CheckersPiece::new(PieceType::Man, White), col, row);
+            board.place_piece(CheckersPiece::new(PieceType::Man, Black), 7 - col, 7 - row);
+        }
+    }
+
+    board
+}
+
+fn encode_board_state(board: &Board<CheckersPiece>) -> Vec<Option<CheckersPiece>> {
+    board
+        .iter()
+        .map(|square| square.get_piece().copied())
+        .collect()
+}
+
+impl CheckersGame {
+    /// Creates a new game of checkers in the starting position.
+    ///
+    /// Black's twelve men fill the dark squares of the top three rows and
+    /// White's fill the bottom three. Black moves first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use simple_checkers::{CheckersGame, Color};
+    ///
+    /// let game = CheckersGame::new();
+    /// assert_eq!(Color::Black, game.get_current_players_turn());
+    /// assert_eq!(24, game.get_board().iter_pieces().count());
+    /// ```
+    pub fn new() -> CheckersGame {
+        Self::build(build_board_with_starting_position(), Black)
+    }
+
+    /// Creates a game from an arbitrary position.
+    ///
+    /// # Arguments
+    ///
+    /// * `board`: The board with the pieces already placed.
+    /// * `current_players_turn`: The player to move.
+    pub fn build(board: Board<CheckersPiece>, current_players_turn: Color) -> CheckersGame {
+        let previous_board_states = vec![encode_board_state(&board)];
+        CheckersGame {
+            board,
+            current_players_turn,
+            moves: vec![],
+            forty_move_rule_counters: vec![0],
+            previous_board_states,
+        }
+    }
+
+    pub fn get_board(&self) -> &Board<CheckersPiece> {
+        &self.board
+    }
+
+    pub fn get_current_players_turn(&self) -> Color {
+        self.current_players_turn
+    }
+
+    pub fn get_moves(&self) -> &Vec<CheckersMoveType> {
+        &self.moves
+    }
+
+    pub fn get_last_move(&self) -> Option<&CheckersMoveType> {
+        self.moves.last()
+    }
+
+    /// The number of plies played since the last capture or man move.
+    pub fn get_forty_move_rule_counter(&self) -> usize {
+        *self.forty_move_rule_counters.last().unwrap()
+    }
+
+    /// Plays a move and passes the turn to the other player.
+    ///
+    /// The move is not validated, it is expected to be one of the legal moves
+    /// returned by `get_game_state`.
+    pub fn make_move(&mut self, checkers_move: CheckersMoveType) {
+        checkers_move.make_move(&mut self.board);
+
+        let counter = if checkers_move.is_capture()
+            || checkers_move.get_piece().get_piece_type() == PieceType::Man
+        {
+            0
+        } else {
+            self.get_forty_move_rule_counter() + 1
+        };
+
+        self.forty_move_rule_counters.push(counter);
+        self.moves.push(checkers_move);
+        self.previous_board_states
+            .push(encode_board_state(&self.board));
+        self.current_players_turn = self.current_players_turn.opposite();
+    }
+
+    /// Undoes the last move, restoring the board, the turn and the draw
+    /// counters. Does nothing if no moves have been made.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use simple_checkers::CheckersGame;
+    /// use simple_checkers::GameState;
+    ///
+    /// let mut game = CheckersGame::new();
+    /// if let GameState::InProgress { legal_moves, .. } = game.get_game_state() {
+    ///     game.make_move(legal_moves[0].clone());
+    /// }
+    /// assert!(game.get_last_move().is_some());
+    ///
+    /// game.undo_last_move();
+    /// assert_eq!(None, game.get_last_move());
+    /// ```
+    pub fn undo_last_move(&mut self) {
+        let Some(last_move) = self.moves.pop() else {
+            return;
+        };
+        last_move.undo_move(&mut self.board);
+        self.forty_move_rule_counters.pop();
+        self.previous_board_states.pop();
+        self.current_players_turn = self.current_players_turn.opposite();
+    }
+
+    /// Get the current state of the game.
+    ///
+    /// # Returns
+    ///
+    /// `GameState::Win` for the opponent if the player to move has no pieces
+    /// or no legal moves left, otherwise `GameState::InProgress` with the
+    /// legal moves.
+    pub fn get_game_state(&self) -> GameState {
+        let legal_moves = get_all_moves_for_color(&self.board, self.current_players_turn);
+        if legal_moves.is_empty() {
+            return GameState::Win {
+                winner: self.current_players_turn.opposite(),
+            };
+        }
+        GameState::InProgress {
+            legal_moves,
+            turn: self.current_players_turn,
+        }
+    }
+
+    /// Determines if a draw can be claimed in the game.
+    ///
+    /// # Returns
+    ///
+    /// `Option<DrawReason>`: The reason a draw can be claimed, or `None`.
+    ///
+    /// A draw can be claimed when:
+    ///
+    /// - Forty moves by each player have been made without a capture or a man
+    ///   being moved.
+    /// - The same position has occurred three times with the same player to
+    ///   move.
+    pub fn can_claim_draw(&self) -> Option<DrawReason> {
+        if self.get_forty_move_rule_counter() >= 80 {
+            return Some(FortyMoveRule);
+        }
+        if self.can_claim_draw_by_repetition() {
+            return Some(Repetition);
+        }
+        None
+    }
+
+    fn can_claim_draw_by_repetition(&self) -> bool {
+        let current = self.previous_board_states.last().unwrap();
+        let repetitions = self
+            .previous_board_states
+            .iter()
+            .rev()
+            .take(self.get_forty_move_rule_counter() + 1)
+            .step_by(2)
+            .filter(|state| *state == current)
+            .count();
+        repetitions >= 3
+    }
+}
+
+impl Default for CheckersGame {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TurnBasedGame for CheckersGame {
+    type Move = CheckersMoveType;
+    type Player = Color;
+
+    fn current_player(&self) -> Color {
+        self.current_players_turn
+    }
+
+    fn legal_moves(&mut self) -> Vec<CheckersMoveType> {
+        if self.can_claim_draw().is_some() {
+            return Vec::new();
+        }
+        match self.get_game_state() {
+            GameState::InProgress { legal_moves, .. } => legal_moves,
+            GameState::Win { .. } => Vec::new(),
+        }
+    }
+
+    fn apply(&mut self, game_move: CheckersMoveType) {
+        self.make_move(game_move);
+    }
+
+    fn undo(&mut self) {
+        self.undo_last_move();
+    }
+
+    /// Draws under the forty-move rule and by repetition end the game, as there
+    /// is no way to claim them through the trait and searches and playouts
+    /// would otherwise go on forever.
+    fn outcome(&mut self) -> Option<Outcome<Color>> {
+        match self.get_game_state() {
+            GameState::InProgress { .. } if self.can_claim_draw().is_some() => Some(Outcome::Draw),
+            GameState::InProgress { .. } => None,
+            GameState::Win { winner } => Some(Outcome::Win(winner)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::piece::PieceType::{King, Man};
+
+    fn play_first_legal_move(game: &mut CheckersGame, from: (usize, usize), to: (usize, usize)) {
+        let GameState::InProgress { legal_moves, .. } = game.get_game_state() else {
+            panic!("game should be in progress");
+        };
+        let checkers_move = legal_moves
+            .into_iter()
+            .find(|m| m.get_original_position() == from && m.get_new_position() == to)
+            .unwrap();
+        game.make_move(checkers_move);
+    }
+
+    #[test]
+    fn starting_position_places_men_on_dark_squares() {
+        let game = CheckersGame::new();
+        let board = game.get_board();
+
+        assert_eq!(
+            12,
+            board
+                .iter_pieces()
+                .filter(|(_, _, p)| p.get_color() == White)
+                .count()
+        );
+        assert_eq!(
+            12,
+            board
+                .iter_pieces()
+                .filter(|(_, _, p)| p.get_color() == Black)
+                .count()
+        );
+        board
+            .iter_pieces()
+            .for_each(|(col, row, _)| assert_eq!(0, (col + row) % 2));
+    }
+
+    #[test]
+    fn player_without_pieces_loses() {
+        let mut board = Board::build(8, 8).unwrap();
+        board.place_piece(CheckersPiece::new(Man, White), 2, 2);
+        let game = CheckersGame::build(board, Black);
+
+        assert_eq!(GameState::Win { winner: White }, game.get_game_state());
+    }
+
+    #[test]
+    fn player_without_moves_loses() {
+        let mut board = Board::build(8, 8).unwrap();
+        board.place_piece(CheckersPiece::new(Man, White), 0, 0);
+        board.place_piece(CheckersPiece::new(Man, Black), 1, 1);
+        board.place_piece(CheckersPiece::new(Man, Black), 2, 2);
+        let game = CheckersGame::build(board, White);
+
+        assert_eq!(GameState::Win { winner: Black }, game.get_game_state());
+    }
+
+    #[test]
+    fn kings_shuffling_can_claim_repetition() {
+        let mut board = Board::build(8, 8).unwrap();
+        board.place_piece(CheckersPiece::new(King, White), 0, 0);
+        board.place_piece(CheckersPiece::new(King, Black), 7, 7);
+        let mut game = CheckersGame::build(board, White);
+
+        for _ in 0..2 {
+            assert_eq!(None, game.can_claim_draw());
+            play_first_legal_move(&mut game, (0, 0), (1, 1));
+            play_first_legal_move(&mut game, (7, 7), (6, 6));
+            play_first_legal_move(&mut game, (1, 1), (0, 0));
+            play_first_legal_move(&mut game, (6, 6), (7, 7));
+        }
+        assert_eq!(Some(Repetition), game.can_claim_draw());
+        assert_eq!(Some(Outcome::Draw), game.outcome());
+        assert!(game.legal_moves().is_empty());
+
+        game.undo_last_move();
+        assert_eq!(None, game.can_claim_draw());
+    }
+
+    #[test]
+    fn man_move_resets_the_forty_move_rule_counter() {
+        let mut board = Board::build(8, 8).unwrap();
+        board.place_piece(CheckersPiece::new(King, White), 0, 0);
+        board.place_piece(CheckersPiece::new(Man, White), 6, 0);
+        board.place_piece(CheckersPiece::new(King, Black), 7, 7);
+        let mut game = CheckersGame::build(board, White);
+
+        play_first_legal_move(&mut game, (0, 0), (1, 1));
+        play_first_legal_move(&mut game, (7, 7), (6, 6));
+        assert_eq!(2, game.get_forty_move_rule_counter());
+
+        play_first_legal_move(&mut game, (6, 0), (5, 1));
+        assert_eq!(0, game.get_forty_move_rule_counter());
+
+        game.undo_last_move();
+        assert_eq!(2, game.get_forty_move_rule_counter());
+    }
+
+    #[test]
+    fn forty_moves_without_progress_can_claim_draw() {
+        let mut board = Board::build(8, 8).unwrap();
+        board.place_piece(CheckersPiece::new(King, White), 0, 0);
+        board.place_piece(CheckersPiece::new(King, Black), 7, 7);
+        let mut game = CheckersGame::build(board, White);
+
+        let white_path = [
+            (0, 0),
+            (1, 1),
+            (2, 0),
+            (3, 1),
+            (4, 0),
+            (5, 1),
+            (6, 0),
+            (7, 1),
+        ];
+        let black_path = [
+            (7, 7),
+            (6, 6),
+            (5, 7),
+            (4, 6),
+            (3, 7),
+            (2, 6),
+            (1, 7),
+            (0, 6),
+        ];
+        for ply in 0..80 {
+            let (path, index) = if ply % 2 == 0 {
+                (&white_path, ply / 2)
+            } else {
+                (&black_path, ply / 2)
+            };
+            let step = index % 14;
+            let (from, to) = if step < 7 {
+                (path[step], path[step + 1])
+            } else {
+                (path[14 - step], path[13 - step])
+            };
+            play_first_legal_move(&mut game, from, to);
+        }
+
+        assert_eq!(80, game.get_forty_move_rule_counter());
+        assert_eq!(Some(FortyMoveRule), game.can_claim_draw());
+    }
+
+    #[test]
+    fn undo_restores_captured_pieces_and_turn() {
+        let mut game = CheckersGame::new();
+        play_first_legal_move(&mut game, (3, 5), (4, 4));
+        play_first_legal_move(&mut game, (2, 2), (3, 3));
+        play_first_legal_move(&mut game, (4, 4), (2, 2));
+
+        assert_eq!(23, game.get_board().iter_pieces().count());
+        assert_eq!(White, game.get_current_players_turn());
+
+        game.undo_last_move();
+        assert_eq!(24, game.get_board().iter_pieces().count());
+        assert_eq!(Black, game.get_current_players_turn());
+    }
+
+    #[test]
+    fn turn_based_perft_matches_known_totals() {
+        let mut game = CheckersGame::new();
+        assert_eq!(1469, game_board::perft(&mut game, 4));
+        assert_eq!(None, game.outcome());
+
+        let mut board = Board::build(8, 8).unwrap();
+        board.place_piece(CheckersPiece::new(Man, White), 2, 2);
+        let mut game = CheckersGame::build(board, Black);
+        assert_eq!(Some(Outcome::Win(White)), game.outcome());
+    }
+}
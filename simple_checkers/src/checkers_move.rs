@@ -0,0 +1,193 @@
+use crate::piece::CheckersPiece;
+use game_board::{Board, Coord};
+use std::fmt::{Display, Formatter};
+
+/// A single move in a game of checkers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckersMoveType {
+    /// A piece stepping one square diagonally without capturing.
+    Move {
+        original_position: (usize, usize),
+        new_position: (usize, usize),
+        piece: CheckersPiece,
+        promotion: bool,
+    },
+    /// A capture of one or more pieces.
+    ///
+    /// `path` holds every square the piece lands on, starting with the square
+    /// it moved from, and `taken_pieces` holds each captured piece in the
+    /// order it was jumped.
+    Jump {
+        path: Vec<(usize, usize)>,
+        piece: CheckersPiece,
+        taken_pieces: Vec<((usize, usize), CheckersPiece)>,
+        promotion: bool,
+    },
+}
+
+impl CheckersMoveType {
+    /// The square the moving piece started on.
+    pub fn get_original_position(&self) -> (usize, usize) {
+        match self {
+            CheckersMoveType::Move {
+                original_position, ..
+            } => *original_position,
+            CheckersMoveType::Jump { path, .. } => path[0],
+        }
+    }
+
+    /// The square the moving piece ends on.
+    pub fn get_new_position(&self) -> (usize, usize) {
+        match self {
+            CheckersMoveType::Move { new_position, .. } => *new_position,
+            CheckersMoveType::Jump { path, .. } => path[path.len() - 1],
+        }
+    }
+
+    /// The piece being moved, as it was before the move.
+    pub fn get_piece(&self) -> CheckersPiece {
+        match self {
+            CheckersMoveType::Move { piece, .. } | CheckersMoveType::Jump { piece, .. } => *piece,
+        }
+    }
+
+    /// Returns true if the moving piece is crowned at the end of the move.
+    pub fn is_promotion(&self) -> bool {
+        match self {
+            CheckersMoveType::Move { promotion, .. } | CheckersMoveType::Jump { promotion, .. } => {
+                *promotion
+            }
+        }
+    }
+
+    /// Returns true if the move captures at least one piece.
+    pub fn is_capture(&self) -> bool {
+        matches!(self, CheckersMoveType::Jump { .. })
+    }
+
+    /// Plays the move on the board.
+    ///
+    /// The move is not validated, it is expected to come from the move
+    /// analyzer.
+    pub fn make_move(&self, board: &mut Board<CheckersPiece>) {
+        let (from_col, from_row) = self.get_original_position();
+        let (to_col, to_row) = self.get_new_position();
+
+        board.remove_piece(from_col, from_row);
+        if let CheckersMoveType::Jump { taken_pieces, .. } = self {
+            taken_pieces.iter().for_each(|((col, row), _)| {
+                board.remove_piece(*col, *row);
+            });
+        }
+
+        let piece = if self.is_promotion() {
+            self.get_piece().crowned()
+        } else {
+            self.get_piece()
+        };
+        board.place_piece(piece, to_col, to_row);
+    }
+
+    /// Reverses the move on the board, restoring any captured pieces.
+    pub fn undo_move(&self, board: &mut Board<CheckersPiece>) {
+        let (from_col, from_row) = self.get_original_position();
+        let (to_col, to_row) = self.get_new_position();
+
+        board.remove_piece(to_col, to_row);
+        if let CheckersMoveType::Jump { taken_pieces, .. } = self {
+            taken_pieces.iter().for_each(|((col, row), piece)| {
+                board.place_piece(*piece, *col, *row);
+            });
+        }
+        board.place_piece(self.get_piece(), from_col, from_row);
+    }
+}
+
+impl Display for CheckersMoveType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckersMoveType::Move {
+                original_position,
+                new_position,
+                piece,
+                ..
+            } => write!(
+                f,
+                "{} {} to {}",
+                piece,
+                Coord::from(*original_position),
+                Coord::from(*new_position)
+            ),
+            CheckersMoveType::Jump {
+                path,
+                piece,
+                taken_pieces,
+                ..
+            } => {
+                let squares = path
+                    .iter()
+                    .map(|square| Coord::from(*square).to_string())
+                    .collect::<Vec<String>>()
+                    .join(" to ");
+                write!(f, "{} {} taking {}", piece, squares, taken_pieces.len())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::piece::PieceType::{King, Man};
+    use crate::Color::{Black, White};
+
+    #[test]
+    fn jump_removes_taken_pieces_and_undo_restores_them() {
+        let mut board = Board::<CheckersPiece>::build(8, 8).unwrap();
+        let white_man = CheckersPiece::new(Man, White);
+        let black_man = CheckersPiece::new(Man, Black);
+        board.place_piece(white_man, 0, 4);
+        board.place_piece(black_man, 1, 5);
+
+        let jump = CheckersMoveType::Jump {
+            path: vec![(0, 4), (2, 6)],
+            piece: white_man,
+            taken_pieces: vec![((1, 5), black_man)],
+            promotion: false,
+        };
+
+        jump.make_move(&mut board);
+        assert_eq!(None, board.get_piece_at_space(0, 4));
+        assert_eq!(None, board.get_piece_at_space(1, 5));
+        assert_eq!(Some(&white_man), board.get_piece_at_space(2, 6));
+
+        jump.undo_move(&mut board);
+        assert_eq!(Some(&white_man), board.get_piece_at_space(0, 4));
+        assert_eq!(Some(&black_man), board.get_piece_at_space(1, 5));
+        assert_eq!(None, board.get_piece_at_space(2, 6));
+    }
+
+    #[test]
+    fn promotion_crowns_the_piece_and_undo_uncrowns_it() {
+        let mut board = Board::<CheckersPiece>::build(8, 8).unwrap();
+        let black_man = CheckersPiece::new(Man, Black);
+        board.place_piece(black_man, 1, 1);
+
+        let step = CheckersMoveType::Move {
+            original_position: (1, 1),
+            new_position: (0, 0),
+            piece: black_man,
+            promotion: true,
+        };
+
+        step.make_move(&mut board);
+        assert_eq!(
+            King,
+            board.get_piece_at_space(0, 0).unwrap().get_piece_type()
+        );
+
+        step.undo_move(&mut board);
+        assert_eq!(Some(&black_man), board.get_piece_at_space(1, 1));
+        assert_eq!(None, board.get_piece_at_space(0, 0));
+    }
+}
@@ -0,0 +1,297 @@
+use crate::checkers_move::CheckersMoveType;
+use crate::piece::{CheckersPiece, PieceType};
+use crate::Color;
+use game_board::{Board, Coord};
+
+/// Finds every legal move for a player.
+///
+/// Captures are compulsory: if any piece of the player can jump, only jumps
+/// are returned. A jump must be continued for as long as the jumping piece
+/// can take another piece, except when a man reaches the far row, where it is
+/// crowned and its move ends.
+///
+/// # Arguments
+///
+/// * `board`: The board to look for moves on.
+/// * `color`: The player to find moves for.
+///
+/// # Returns
+///
+/// All legal moves for the player, an empty vector if the player can not move.
+///
+/// # Example
+///
+/// ```
+/// use simple_checkers::checkers_move_analyzer::get_all_moves_for_color;
+/// use simple_checkers::{CheckersGame, Color};
+///
+/// let game = CheckersGame::new();
+/// assert_eq!(7, get_all_moves_for_color(game.get_board(), Color::Black).len());
+/// ```
+pub fn get_all_moves_for_color(
+    board: &Board<CheckersPiece>,
+    color: Color,
+) -> Vec<CheckersMoveType> {
+    let jumps: Vec<CheckersMoveType> = board
+        .iter_pieces()
+        .filter(|(_, _, piece)| piece.get_color() == color)
+        .flat_map(|(col, row, piece)| get_jumps_for_piece(board, *piece, (col, row)))
+        .collect();
+
+    if !jumps.is_empty() {
+        return jumps;
+    }
+
+    board
+        .iter_pieces()
+        .filter(|(_, _, piece)| piece.get_color() == color)
+        .flat_map(|(col, row, piece)| get_steps_for_piece(board, *piece, (col, row)))
+        .collect()
+}
+
+/// Returns true if the player has at least one capture available.
+pub fn can_color_capture(board: &Board<CheckersPiece>, color: Color) -> bool {
+    board
+        .iter_pieces()
+        .filter(|(_, _, piece)| piece.get_color() == color)
+        .any(|(col, row, piece)| !get_jumps_for_piece(board, *piece, (col, row)).is_empty())
+}
+
+fn is_promotion_row(board: &Board<CheckersPiece>, piece: CheckersPiece, row: usize) -> bool {
+    if piece.get_piece_type() == PieceType::King {
+        return false;
+    }
+    match piece.get_color() {
+        Color::White => row == board.get_height() - 1,
+        Color::Black => row == 0,
+    }
+}
+
+fn get_steps_for_piece(
+    board: &Board<CheckersPiece>,
+    piece: CheckersPiece,
+    position: (usize, usize),
+) -> Vec<CheckersMoveType> {
+    let from = Coord::from(position);
+    piece
+        .get_directions()
+        .into_iter()
+        .filter_map(|(col_offset, row_offset)| from.offset_on(board, col_offset, row_offset))
+        .filter(|to| {
+            board
+                .get_piece_at_space(to.get_col(), to.get_row())
+                .is_none()
+        })
+        .map(|to| CheckersMoveType::Move {
+            original_position: position,
+            new_position: to.into(),
+            piece,
+            promotion: is_promotion_row(board, piece, to.get_row()),
+        })
+        .collect()
+}
+
+fn get_jumps_for_piece(
+    board: &Board<CheckersPiece>,
+    piece: CheckersPiece,
+    position: (usize, usize),
+) -> Vec<CheckersMoveType> {
+    let mut jumps = vec![];
+    let mut path = vec![position];
+    let mut taken_pieces = vec![];
+    continue_jump(board, piece, &mut path, &mut taken_pieces, &mut jumps);
+    jumps
+}
+
+/// Extends a jump in progress from the last square of `path`, adding every
+/// finished jump to `jumps`.
+///
+/// The jumping piece's starting square is treated as empty, and a piece that
+/// has already been jumped can not be jumped again, it stays on the board
+/// until the whole move is complete.
+fn continue_jump(
+    board: &Board<CheckersPiece>,
+    piece: CheckersPiece,
+    path: &mut Vec<(usize, usize)>,
+    taken_pieces: &mut Vec<((usize, usize), CheckersPiece)>,
+    jumps: &mut Vec<CheckersMoveType>,
+) {
+    let from = Coord::from(path[path.len() - 1]);
+    let start = path[0];
+    let mut extended = false;
+
+    for (col_offset, row_offset) in piece.get_directions() {
+        let Some(over) = from.offset_on(board, col_offset, row_offset) else {
+            continue;
+        };
+        let Some(to) = from.offset_on(board, col_offset * 2, row_offset * 2) else {
+            continue;
+        };
+
+        let taken = match board.get_piece_at_space(over.get_col(), over.get_row()) {
+            Some(taken) if taken.get_color() != piece.get_color() => *taken,
+            _ => continue,
+        };
+        if taken_pieces
+            .iter()
+            .any(|(square, _)| *square == over.into())
+        {
+            continue;
+        }
+        let to_position: (usize, usize) = to.into();
+        if to_position != start
+            && board
+                .get_piece_at_space(to.get_col(), to.get_row())
+                .is_some()
+        {
+            continue;
+        }
+
+        extended = true;
+        path.push(to_position);
+        taken_pieces.push((over.into(), taken));
+
+        if is_promotion_row(board, piece, to.get_row()) {
+            jumps.push(CheckersMoveType::Jump {
+                path: path.clone(),
+                piece,
+                taken_pieces: taken_pieces.clone(),
+                promotion: true,
+            });
+        } else {
+            continue_jump(board, piece, path, taken_pieces, jumps);
+        }
+
+        path.pop();
+        taken_pieces.pop();
+    }
+
+    if !extended && !taken_pieces.is_empty() {
+        jumps.push(CheckersMoveType::Jump {
+            path: path.clone(),
+            piece,
+            taken_pieces: taken_pieces.clone(),
+            promotion: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::piece::PieceType::{King, Man};
+    use crate::Color::{Black, White};
+
+    fn empty_board() -> Board<CheckersPiece> {
+        Board::build(8, 8).unwrap()
+    }
+
+    #[test]
+    fn starting_position_has_seven_moves_for_each_player() {
+        let game = crate::CheckersGame::new();
+        assert_eq!(7, get_all_moves_for_color(game.get_board(), Black).len());
+        assert_eq!(7, get_all_moves_for_color(game.get_board(), White).len());
+    }
+
+    #[test]
+    fn perft_from_the_starting_position() {
+        let mut game = crate::CheckersGame::new();
+        [7, 49, 302, 1469, 7361, 36768]
+            .into_iter()
+            .enumerate()
+            .for_each(|(depth, expected)| {
+                assert_eq!(expected, game_board::perft(&mut game, depth + 1))
+            });
+    }
+
+    #[test]
+    fn men_only_step_forward() {
+        let mut board = empty_board();
+        board.place_piece(CheckersPiece::new(Man, White), 2, 2);
+
+        let moves = get_all_moves_for_color(&board, White);
+        let targets: Vec<(usize, usize)> = moves.iter().map(|m| m.get_new_position()).collect();
+        assert_eq!(vec![(1, 3), (3, 3)], targets);
+    }
+
+    #[test]
+    fn kings_step_in_all_directions() {
+        let mut board = empty_board();
+        board.place_piece(CheckersPiece::new(King, White), 2, 2);
+
+        assert_eq!(4, get_all_moves_for_color(&board, White).len());
+    }
+
+    #[test]
+    fn captures_are_compulsory() {
+        let mut board = empty_board();
+        board.place_piece(CheckersPiece::new(Man, White), 2, 2);
+        board.place_piece(CheckersPiece::new(Man, White), 6, 0);
+        board.place_piece(CheckersPiece::new(Man, Black), 3, 3);
+
+        let moves = get_all_moves_for_color(&board, White);
+        assert_eq!(1, moves.len());
+        assert!(moves[0].is_capture());
+        assert_eq!((4, 4), moves[0].get_new_position());
+        assert!(can_color_capture(&board, White));
+    }
+
+    #[test]
+    fn multi_jumps_must_be_completed() {
+        let mut board = empty_board();
+        board.place_piece(CheckersPiece::new(Man, White), 0, 0);
+        board.place_piece(CheckersPiece::new(Man, Black), 1, 1);
+        board.place_piece(CheckersPiece::new(Man, Black), 3, 3);
+        board.place_piece(CheckersPiece::new(Man, Black), 1, 3);
+
+        let moves = get_all_moves_for_color(&board, White);
+        assert_eq!(2, moves.len());
+        moves.into_iter().for_each(|m| match m {
+            CheckersMoveType::Jump {
+                path, taken_pieces, ..
+            } => {
+                assert_eq!(3, path.len());
+                assert_eq!(2, taken_pieces.len());
+            }
+            _ => panic!("expected a jump"),
+        });
+    }
+
+    #[test]
+    fn man_reaching_the_far_row_ends_its_jump() {
+        let mut board = empty_board();
+        board.place_piece(CheckersPiece::new(Man, White), 2, 5);
+        board.place_piece(CheckersPiece::new(Man, Black), 3, 6);
+        board.place_piece(CheckersPiece::new(Man, Black), 5, 6);
+
+        let moves = get_all_moves_for_color(&board, White);
+        assert_eq!(1, moves.len());
+        assert!(moves[0].is_promotion());
+        assert_eq!(
+            vec![(2, 5), (4, 7)],
+            match &moves[0] {
+                CheckersMoveType::Jump { path, .. } => path.clone(),
+                _ => panic!("expected a jump"),
+            }
+        );
+    }
+
+    #[test]
+    fn king_can_jump_back_through_its_starting_square() {
+        let mut board = empty_board();
+        board.place_piece(CheckersPiece::new(King, White), 2, 2);
+        board.place_piece(CheckersPiece::new(Man, Black), 3, 3);
+        board.place_piece(CheckersPiece::new(Man, Black), 5, 3);
+        board.place_piece(CheckersPiece::new(Man, Black), 5, 1);
+        board.place_piece(CheckersPiece::new(Man, Black), 3, 1);
+
+        let moves = get_all_moves_for_color(&board, White);
+        assert!(moves.iter().any(|m| match m {
+            CheckersMoveType::Jump { taken_pieces, .. } => taken_pieces.len() == 4,
+            _ => false,
+        }));
+        moves
+            .into_iter()
+            .for_each(|m| assert_eq!((2, 2), m.get_new_position()));
+    }
+}
@@ -0,0 +1,303 @@
+use crate::checkers_game::{CheckersGame, GameState};
+use crate::checkers_move::CheckersMoveType;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+const SQUARE_COUNT: usize = 32;
+
+/// Converts a board position to its square number in standard draughts
+/// notation.
+///
+/// Only the 32 dark squares are numbered. Square 1 is on Black's back row at
+/// b8, counting left to right and down the board, so White's back row holds
+/// squares 29 to 32.
+///
+/// # Arguments
+///
+/// * `position`: The column and row of a dark square.
+///
+/// # Returns
+///
+/// The square number, or `None` if the position is a light square or off
+/// the board.
+///
+/// # Example
+///
+/// ```
+/// use simple_checkers::codec::notation::get_square_number;
+///
+/// assert_eq!(Some(1), get_square_number((1, 7)));
+/// assert_eq!(Some(29), get_square_number((0, 0)));
+/// assert_eq!(None, get_square_number((0, 7)));
+/// ```
+pub fn get_square_number(position: (usize, usize)) -> Option<usize> {
+    let (col, row) = position;
+    if col > 7 || row > 7 || (col + row) % 2 != 0 {
+        return None;
+    }
+    Some((7 - row) * 4 + col / 2 + 1)
+}
+
+/// Converts a square number in standard draughts notation to a board
+/// position.
+///
+/// # Arguments
+///
+/// * `square_number`: A square number from 1 to 32.
+///
+/// # Returns
+///
+/// The column and row of the square, or `None` if the number is out of range.
+///
+/// # Example
+///
+/// ```
+/// use simple_checkers::codec::notation::get_position_from_square_number;
+///
+/// assert_eq!(Some((1, 7)), get_position_from_square_number(1));
+/// assert_eq!(Some((6, 0)), get_position_from_square_number(32));
+/// assert_eq!(None, get_position_from_square_number(33));
+/// ```
+pub fn get_position_from_square_number(square_number: usize) -> Option<(usize, usize)> {
+    if !(1..=SQUARE_COUNT).contains(&square_number) {
+        return None;
+    }
+    let index = square_number - 1;
+    let row = 7 - index / 4;
+    let col = 2 * (index % 4) + row % 2;
+    Some((col, row))
+}
+
+/// Encodes a move in standard draughts notation.
+///
+/// Steps are written as the start and end square separated by a `-`, as in
+/// `11-15`. Jumps list every square the piece lands on separated by an `x`,
+/// as in `15x24` or `1x10x19`.
+///
+/// # Example
+///
+/// ```
+/// use simple_checkers::codec::notation::encode_move_as_notation;
+/// use simple_checkers::{CheckersGame, GameState};
+///
+/// let game = CheckersGame::new();
+/// if let GameState::InProgress { legal_moves, .. } = game.get_game_state() {
+///     assert_eq!("9-13", encode_move_as_notation(&legal_moves[0]));
+/// }
+/// ```
+pub fn encode_move_as_notation(checkers_move: &CheckersMoveType) -> String {
+    match checkers_move {
+        CheckersMoveType::Move {
+            original_position,
+            new_position,
+            ..
+        } => format!(
+            "{}-{}",
+            get_square_number(*original_position).unwrap(),
+            get_square_number(*new_position).unwrap()
+        ),
+        CheckersMoveType::Jump { path, .. } => path
+            .iter()
+            .map(|position| get_square_number(*position).unwrap().to_string())
+            .collect::<Vec<String>>()
+            .join("x"),
+    }
+}
+
+/// Encodes every move of a game in standard draughts notation, separated by
+/// spaces.
+pub fn encode_game(game: &CheckersGame) -> String {
+    game.get_moves()
+        .iter()
+        .map(encode_move_as_notation)
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Finds the legal move described by a move in standard draughts notation.
+///
+/// A jump may be written with only its start and end squares, such as
+/// `1x19` for `1x10x19`, as long as only one legal jump matches.
+///
+/// # Arguments
+///
+/// * `game`: The game the move is to be played in.
+/// * `notation`: The move, such as `11-15` or `15x24`.
+///
+/// # Returns
+///
+/// The matching legal move, or a `NotationError` if the notation can not be
+/// read, the move is not legal or it matches more than one legal move.
+pub fn decode_move(game: &CheckersGame, notation: &str) -> Result<CheckersMoveType, NotationError> {
+    let notation = notation.trim();
+    let is_jump = notation.contains('x');
+    let separator = if is_jump { 'x' } else { '-' };
+
+    let squares = notation
+        .split(separator)
+        .map(|square| {
+            square
+                .parse::<usize>()
+                .ok()
+                .and_then(get_position_from_square_number)
+                .ok_or_else(|| NotationError::new(format!("'{notation}' is not a valid move")))
+        })
+        .collect::<Result<Vec<(usize, usize)>, NotationError>>()?;
+
+    if squares.len() < 2 || (!is_jump && squares.len() != 2) {
+        return Err(NotationError::new(format!(
+            "'{notation}' is not a valid move"
+        )));
+    }
+
+    let legal_moves = match game.get_game_state() {
+        GameState::InProgress { legal_moves, .. } => legal_moves,
+        GameState::Win { .. } => vec![],
+    };
+
+    let mut matching_moves = legal_moves.into_iter().filter(|m| match m {
+        CheckersMoveType::Move {
+            original_position,
+            new_position,
+            ..
+        } => !is_jump && squares == [*original_position, *new_position],
+        CheckersMoveType::Jump { path, .. } => {
+            *path == squares
+                || (squares.len() == 2
+                    && path[0] == squares[0]
+                    && path[path.len() - 1] == squares[1])
+        }
+    });
+
+    match (matching_moves.next(), matching_moves.next()) {
+        (Some(checkers_move), None) => Ok(checkers_move),
+        (Some(_), Some(_)) => Err(NotationError::new(format!(
+            "'{notation}' matches more than one legal move"
+        ))),
+        (None, _) => Err(NotationError::new(format!(
+            "Unable to make move {notation}"
+        ))),
+    }
+}
+
+/// Builds a `CheckersGame` from moves in standard draughts notation,
+/// separated by spaces or newlines, played from the starting position.
+///
+/// # Example
+///
+/// ```
+/// use simple_checkers::codec::notation::build_game_from_notation;
+/// use simple_checkers::Color;
+///
+/// let game = build_game_from_notation("11-15 22-18 15x22").unwrap();
+/// assert_eq!(Color::White, game.get_current_players_turn());
+/// assert_eq!(23, game.get_board().iter_pieces().count());
+/// ```
+pub fn build_game_from_notation(notation_string: &str) -> Result<CheckersGame, NotationError> {
+    let mut game = CheckersGame::new();
+
+    for notation in notation_string.split_whitespace() {
+        let checkers_move = decode_move(&game, notation)?;
+        game.make_move(checkers_move);
+    }
+
+    Ok(game)
+}
+
+pub struct NotationError {
+    reason: String,
+}
+
+impl NotationError {
+    fn new(reason: String) -> Self {
+        Self { reason }
+    }
+}
+
+impl Display for NotationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Notation Error: {}", self.reason)
+    }
+}
+
+impl Debug for NotationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NotationError: {}", self.reason)
+    }
+}
+
+impl Error for NotationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::piece::{CheckersPiece, PieceType};
+    use crate::Color::{Black, White};
+
+    #[test]
+    fn square_numbers_round_trip() {
+        (1..=32).for_each(|square_number| {
+            let position = get_position_from_square_number(square_number).unwrap();
+            assert_eq!(Some(square_number), get_square_number(position));
+        });
+        assert_eq!(None, get_position_from_square_number(0));
+    }
+
+    #[test]
+    fn black_starts_on_the_low_square_numbers() {
+        let game = CheckersGame::new();
+        game.get_board()
+            .iter_pieces()
+            .for_each(|(col, row, piece)| {
+                let square_number = get_square_number((col, row)).unwrap();
+                match piece.get_color() {
+                    Black => assert!(square_number <= 12),
+                    White => assert!(square_number >= 21),
+                }
+            });
+    }
+
+    #[test]
+    fn game_round_trips_through_notation() {
+        let notation = "11-15 23-19 8-11 22-17 9-13 17-14 10x17 21x14";
+        let game = build_game_from_notation(notation).unwrap();
+        assert_eq!(notation, encode_game(&game));
+    }
+
+    #[test]
+    fn multi_jump_can_be_written_with_only_its_ends() {
+        let mut board = game_board::Board::build(8, 8).unwrap();
+        let place = |board: &mut game_board::Board<CheckersPiece>, square, color| {
+            let (col, row) = get_position_from_square_number(square).unwrap();
+            board.place_piece(CheckersPiece::new(PieceType::Man, color), col, row);
+        };
+        place(&mut board, 1, Black);
+        place(&mut board, 6, White);
+        place(&mut board, 15, White);
+        place(&mut board, 32, White);
+        let game = CheckersGame::build(board, Black);
+
+        let full = decode_move(&game, "1x10x19").unwrap();
+        let shorthand = decode_move(&game, "1x19").unwrap();
+        assert_eq!(full, shorthand);
+        assert_eq!("1x10x19", encode_move_as_notation(&shorthand));
+    }
+
+    #[test]
+    fn jump_must_be_taken_when_available() {
+        let error = build_game_from_notation("11-15 22-18 9-13").unwrap_err();
+        assert_eq!(
+            "Notation Error: Unable to make move 9-13",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn invalid_square_is_an_error() {
+        let error = build_game_from_notation("11-40").unwrap_err();
+        assert_eq!(
+            "Notation Error: '11-40' is not a valid move",
+            error.to_string()
+        );
+    }
+}
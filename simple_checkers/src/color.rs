@@ -0,0 +1,48 @@
+use crate::Color::{Black, White};
+use std::fmt::Display;
+
+/// The two sides of a game of checkers. Black moves first.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub enum Color {
+    Black,
+    White,
+}
+
+impl Color {
+    /// Returns the opposite color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_checkers::Color::{Black, White};
+    ///
+    /// assert_eq!(White, Black.opposite());
+    /// assert_eq!(Black, White.opposite());
+    /// ```
+    pub fn opposite(&self) -> Color {
+        match self {
+            White => Black,
+            Black => White,
+        }
+    }
+
+    /// The row direction the men of this color move in.
+    ///
+    /// White starts on the low rows and moves up the board, Black starts on
+    /// the high rows and moves down.
+    pub fn forward(&self) -> i32 {
+        match self {
+            White => 1,
+            Black => -1,
+        }
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            White => write!(f, "White"),
+            Black => write!(f, "Black"),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod checkers_game;
+mod checkers_move;
+pub mod checkers_move_analyzer;
+pub mod codec;
+pub mod color;
+pub mod piece;
+
+pub use checkers_game::{CheckersGame, DrawReason, GameState};
+pub use checkers_move::CheckersMoveType;
+pub use color::Color;
+pub use game_board;
@@ -0,0 +1,136 @@
+use crate::Color;
+use game_board::RenderPiece;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum PieceType {
+    /// Moves one square diagonally forward.
+    Man,
+    /// A crowned man, moving one square diagonally in any direction.
+    King,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub struct CheckersPiece {
+    piece_type: PieceType,
+    color: Color,
+}
+
+impl CheckersPiece {
+    /// Creates a new `CheckersPiece` with the specified type and color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_checkers::piece::{CheckersPiece, PieceType};
+    /// use simple_checkers::Color;
+    ///
+    /// let black_man = CheckersPiece::new(PieceType::Man, Color::Black);
+    /// assert_eq!(Color::Black, black_man.get_color());
+    /// ```
+    pub fn new(piece_type: PieceType, color: Color) -> Self {
+        Self { piece_type, color }
+    }
+
+    pub fn get_color(&self) -> Color {
+        self.color
+    }
+
+    pub fn get_piece_type(&self) -> PieceType {
+        self.piece_type
+    }
+
+    /// Returns the piece after being crowned on the far row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_checkers::piece::{CheckersPiece, PieceType};
+    /// use simple_checkers::Color;
+    ///
+    /// let white_man = CheckersPiece::new(PieceType::Man, Color::White);
+    /// assert_eq!(PieceType::King, white_man.crowned().get_piece_type());
+    /// ```
+    pub fn crowned(&self) -> Self {
+        Self::new(PieceType::King, self.color)
+    }
+
+    /// The diagonal directions the piece can move and jump in.
+    ///
+    /// # Returns
+    ///
+    /// The column and row offsets of a single step. Men only move forward,
+    /// kings move in all four directions.
+    pub fn get_directions(&self) -> Vec<(i32, i32)> {
+        let forward = self.color.forward();
+        match self.piece_type {
+            PieceType::Man => vec![(-1, forward), (1, forward)],
+            PieceType::King => vec![(-1, forward), (1, forward), (-1, -forward), (1, -forward)],
+        }
+    }
+
+    /// Returns the UTF-8 symbol of the piece.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_checkers::piece::{CheckersPiece, PieceType};
+    /// use simple_checkers::Color;
+    ///
+    /// assert_eq!("⛂", CheckersPiece::new(PieceType::Man, Color::Black).as_utf_str());
+    /// assert_eq!("⛁", CheckersPiece::new(PieceType::King, Color::White).as_utf_str());
+    /// ```
+    pub fn as_utf_str(&self) -> &'static str {
+        match (self.piece_type, self.color) {
+            (PieceType::Man, Color::White) => "⛀",
+            (PieceType::King, Color::White) => "⛁",
+            (PieceType::Man, Color::Black) => "⛂",
+            (PieceType::King, Color::Black) => "⛃",
+        }
+    }
+}
+
+impl Display for CheckersPiece {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_utf_str())
+    }
+}
+
+impl RenderPiece for CheckersPiece {
+    /// `b` and `w` for men, `B` and `W` for kings.
+    fn get_letter(&self) -> char {
+        let letter = match self.color {
+            Color::Black => 'b',
+            Color::White => 'w',
+        };
+        match self.piece_type {
+            PieceType::Man => letter,
+            PieceType::King => letter.to_ascii_uppercase(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color::{Black, White};
+
+    #[test]
+    fn men_move_forward_and_kings_move_both_ways() {
+        let white_man = CheckersPiece::new(PieceType::Man, White);
+        assert_eq!(vec![(-1, 1), (1, 1)], white_man.get_directions());
+
+        let black_man = CheckersPiece::new(PieceType::Man, Black);
+        assert_eq!(vec![(-1, -1), (1, -1)], black_man.get_directions());
+
+        let black_king = black_man.crowned();
+        assert_eq!(4, black_king.get_directions().len());
+        assert_eq!(Black, black_king.get_color());
+    }
+
+    #[test]
+    fn letters_are_upper_case_for_kings() {
+        assert_eq!('w', CheckersPiece::new(PieceType::Man, White).get_letter());
+        assert_eq!('B', CheckersPiece::new(PieceType::King, Black).get_letter());
+    }
+}
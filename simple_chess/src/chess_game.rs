@@ -1,16 +1,24 @@
 use crate::chess_game::DrawReason::{FiftyMoveRule, InsufficientPieces, Repetition};
-use crate::chess_game_state_analyzer::{get_game_state, is_insufficient_material, GameState};
+use crate::chess_game_state_analyzer::{
+    get_game_state, has_insufficient_mating_material, GameState,
+};
 use crate::chess_move::ChessMoveType;
 use crate::codec::binary::encode_board_as_binary;
-use crate::piece::ChessPiece;
+use crate::codec::forsyth_edwards_notation::{
+    build_variant_game_from_string, encode_game_as_string,
+};
 use crate::piece::PieceType::{Bishop, King, Knight, Pawn, Queen, Rook};
+use crate::piece::{ChessPiece, PieceType};
+use crate::variant::{Standard, Variant};
 use crate::Color;
 use crate::Color::{Black, White};
-use game_board::Board;
+use game_board::{Board, Outcome, TurnBasedGame};
 use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 #[derive(Debug, Clone)]
-pub struct ChessGame {
+pub struct ChessGame<V = Standard> {
     board: Board<ChessPiece>,
     current_players_turn: Color,
     turn_number: usize,
@@ -19,24 +27,135 @@ pub struct ChessGame {
     can_white_castle_long: bool,
     can_black_castle_short: bool,
     can_black_castle_long: bool,
+    castling_rook_columns: (usize, usize, usize, usize),
     moves: Vec<ChessMoveType>,
     previous_board_states: Vec<Vec<u8>>,
+    repetition_start: usize,
+    undo_history: Vec<UndoState>,
+    white_pocket: Vec<PieceType>,
+    black_pocket: Vec<PieceType>,
+    promoted_pieces: Vec<(usize, usize)>,
+    result: Option<(GameResult, Termination)>,
+    draw_offer: Option<Color>,
+    starting_fen: String,
+    starting_ply: usize,
+    variant: V,
 }
 
-#[derive(Debug)]
+/// The parts of the game that a move can change but can not be worked out
+/// again from the move alone, kept so `undo_last_move` can restore them.
+#[derive(Debug, Clone, Copy)]
+struct UndoState {
+    turn_number: usize,
+    fifty_move_rule_counter: usize,
+    castling_rights: (bool, bool, bool, bool),
+    repetition_start: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DrawReason {
     InsufficientPieces,
     Repetition,
     FiftyMoveRule,
 }
 
+/// The result of a finished game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameResult {
+    WhiteWins,
+    BlackWins,
+    Draw,
+}
+
+impl GameResult {
+    /// The result of a game won by `winner`.
+    pub fn win_for(winner: Color) -> Self {
+        match winner {
+            White => GameResult::WhiteWins,
+            Black => GameResult::BlackWins,
+        }
+    }
+
+    /// The winner of the game, `None` for a draw.
+    pub fn get_winner(&self) -> Option<Color> {
+        match self {
+            GameResult::WhiteWins => Some(White),
+            GameResult::BlackWins => Some(Black),
+            GameResult::Draw => None,
+        }
+    }
+}
+
+/// Writes the result as in PGN, `1-0`, `0-1` or `1/2-1/2`.
+impl Display for GameResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let result = match self {
+            GameResult::WhiteWins => "1-0",
+            GameResult::BlackWins => "0-1",
+            GameResult::Draw => "1/2-1/2",
+        };
+        write!(f, "{}", result)
+    }
+}
+
+impl FromStr for GameResult {
+    type Err = GameResultError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1-0" => Ok(GameResult::WhiteWins),
+            "0-1" => Ok(GameResult::BlackWins),
+            "1/2-1/2" => Ok(GameResult::Draw),
+            _ => Err(GameResultError {
+                reason: format!("Invalid game result {}", s),
+            }),
+        }
+    }
+}
+
+/// Why a game finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Termination {
+    Checkmate,
+    Stalemate,
+    /// Won by a rule of the variant being played.
+    VariantWin,
+    /// Drawn by a rule of the variant being played.
+    VariantDraw,
+    Resignation,
+    DrawAgreement,
+    DrawClaim(DrawReason),
+    /// A player ran out of time, which is a draw if the other player could
+    /// not checkmate.
+    TimeForfeit,
+}
+
+#[derive(Debug)]
+pub struct GameResultError {
+    pub reason: String,
+}
+
+const CHESS_960_KNIGHT_PLACEMENTS: [(usize, usize); 10] = [
+    (0, 1),
+    (0, 2),
+    (0, 3),
+    (0, 4),
+    (1, 2),
+    (1, 3),
+    (1, 4),
+    (2, 3),
+    (2, 4),
+    (3, 4),
+];
+
 fn build_board_with_starting_position() -> Board<ChessPiece> {
+    build_board_with_back_rank([Rook, Knight, Bishop, Queen, King, Bishop, Knight, Rook])
+}
+
+fn build_board_with_back_rank(back_rank: [PieceType; 8]) -> Board<ChessPiece> {
     let mut board = Board::<ChessPiece>::build(8, 8).unwrap();
 
-    for (col, piece_type) in [Rook, Knight, Bishop, Queen, King, Bishop, Knight, Rook]
-        .iter()
-        .enumerate()
-    {
+    for (col, piece_type) in back_rank.iter().enumerate() {
         board.place_piece(ChessPiece::new(*piece_type, Black), col, 7);
         board.place_piece(ChessPiece::new(Pawn, Black), col, 6);
         board.place_piece(ChessPiece::new(Pawn, White), col, 1);
@@ -46,6 +165,42 @@ fn build_board_with_starting_position() -> Board<ChessPiece> {
     board
 }
 
+/// Generates the back rank of a Chess960 starting position.
+///
+/// Positions are numbered from 0 to 959 using Scharnagl's numbering scheme,
+/// where position 518 is the standard starting position.
+fn chess_960_back_rank(index: usize) -> [PieceType; 8] {
+    let mut back_rank: [Option<PieceType>; 8] = [None; 8];
+
+    let (remainder, light_bishop) = (index / 4, index % 4);
+    back_rank[light_bishop * 2 + 1] = Some(Bishop);
+
+    let (remainder, dark_bishop) = (remainder / 4, remainder % 4);
+    back_rank[dark_bishop * 2] = Some(Bishop);
+
+    let (knights, queen) = (remainder / 6, remainder % 6);
+    let queen_col = empty_columns(&back_rank)[queen];
+    back_rank[queen_col] = Some(Queen);
+
+    let (first_knight, second_knight) = CHESS_960_KNIGHT_PLACEMENTS[knights];
+    let empty = empty_columns(&back_rank);
+    back_rank[empty[first_knight]] = Some(Knight);
+    back_rank[empty[second_knight]] = Some(Knight);
+
+    for (col, piece_type) in empty_columns(&back_rank)
+        .into_iter()
+        .zip([Rook, King, Rook])
+    {
+        back_rank[col] = Some(piece_type);
+    }
+
+    back_rank.map(|piece_type| piece_type.unwrap())
+}
+
+fn empty_columns(back_rank: &[Option<PieceType>; 8]) -> Vec<usize> {
+    (0..8).filter(|col| back_rank[*col].is_none()).collect()
+}
+
 impl ChessGame {
     /// Initialize a new simple_chess game.
     ///
@@ -64,7 +219,7 @@ impl ChessGame {
     /// let game = ChessGame::new();
     /// ```
     pub fn new() -> ChessGame {
-        ChessGame {
+        let mut game = ChessGame {
             board: build_board_with_starting_position(),
             current_players_turn: White,
             turn_number: 1,
@@ -73,9 +228,69 @@ impl ChessGame {
             can_white_castle_long: true,
             can_black_castle_short: true,
             can_black_castle_long: true,
+            castling_rook_columns: (0, 7, 0, 7),
             moves: Vec::new(),
             previous_board_states: Vec::new(),
+            repetition_start: 0,
+            undo_history: Vec::new(),
+            white_pocket: Vec::new(),
+            black_pocket: Vec::new(),
+            promoted_pieces: Vec::new(),
+            result: None,
+            draw_offer: None,
+            starting_fen: String::new(),
+            starting_ply: 0,
+            variant: Standard,
+        };
+        game.set_starting_position();
+        game
+    }
+
+    /// Initialize a new Chess960 (Fischer Random) game.
+    ///
+    /// The back ranks are shuffled according to the Chess960 rules, with the
+    /// bishops on opposite colored squares and the king somewhere between the
+    /// rooks. Black mirrors White's setup and both sides may castle with
+    /// either rook.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The number of the starting position, from 0 to 959, using
+    ///   Scharnagl's numbering. Position 518 is the standard starting position.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ChessGame)` - A new game in the requested starting position.
+    /// * `Err(String)` - If the index is not a valid Chess960 position number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::ChessGame;
+    /// use simple_chess::codec::forsyth_edwards_notation::encode_game_as_string;
+    ///
+    /// let game = ChessGame::new_chess960(0).unwrap();
+    /// assert_eq!(
+    ///     "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w KQkq - 0 1",
+    ///     encode_game_as_string(&game)
+    /// );
+    /// ```
+    pub fn new_chess960(index: usize) -> Result<ChessGame, String> {
+        if index >= 960 {
+            return Err(format!(
+                "Chess960 starting position must be between 0 and 959, received {index}"
+            ));
         }
+
+        let back_rank = chess_960_back_rank(index);
+        let long_rook = back_rank.iter().position(|p| *p == Rook).unwrap();
+        let short_rook = back_rank.iter().rposition(|p| *p == Rook).unwrap();
+
+        let mut game = ChessGame::new();
+        game.board = build_board_with_back_rank(back_rank);
+        game.castling_rook_columns = (long_rook, short_rook, long_rook, short_rook);
+        game.set_starting_position();
+        Ok(game)
     }
 
     pub fn build(
@@ -89,7 +304,8 @@ impl ChessGame {
         can_black_castle_long: bool,
         moves: Vec<ChessMoveType>,
     ) -> ChessGame {
-        ChessGame {
+        let last_column = board.get_width() - 1;
+        let mut game = ChessGame {
             board,
             current_players_turn,
             turn_number,
@@ -98,11 +314,211 @@ impl ChessGame {
             can_white_castle_long,
             can_black_castle_short,
             can_black_castle_long,
+            castling_rook_columns: (0, last_column, 0, last_column),
             moves,
             previous_board_states: vec![], // TODO generate previous board states from moves
+            repetition_start: 0,
+            undo_history: Vec::new(),
+            white_pocket: Vec::new(),
+            black_pocket: Vec::new(),
+            promoted_pieces: Vec::new(),
+            result: None,
+            draw_offer: None,
+            starting_fen: String::new(),
+            starting_ply: 0,
+            variant: Standard,
+        };
+        game.set_starting_position();
+        game
+    }
+}
+
+impl<V: Variant> ChessGame<V> {
+    /// Initialize a new game of a chess variant from its starting position.
+    ///
+    /// Most variants start from the standard starting position, others such as
+    /// Antichess provide their own through `Variant::starting_fen`.
+    ///
+    /// # Arguments
+    ///
+    /// * `variant` - The rules of the variant to play.
+    ///
+    /// # Returns
+    ///
+    /// `ChessGame<V>`: A new game in the starting position of the variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::ChessGame;
+    /// use simple_chess::variant::{KingOfTheHill, Variant};
+    ///
+    /// let game = ChessGame::new_variant(KingOfTheHill);
+    /// assert_eq!("King of the Hill", game.get_variant().name());
+    /// ```
+    pub fn new_variant(variant: V) -> ChessGame<V> {
+        match variant.starting_fen() {
+            Some(fen) => build_variant_game_from_string(fen, variant)
+                .expect("the starting position of a variant must be valid FEN"),
+            None => {
+                let mut game = ChessGame::new().into_variant(variant);
+                game.set_starting_position();
+                game
+            }
         }
     }
 
+    /// Continue the game in its current position with the rules of another variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `variant` - The rules of the variant to play from now on.
+    ///
+    /// # Returns
+    ///
+    /// `ChessGame<W>`: The game with the same position and move history.
+    pub fn into_variant<W: Variant>(self, variant: W) -> ChessGame<W> {
+        ChessGame {
+            board: self.board,
+            current_players_turn: self.current_players_turn,
+            turn_number: self.turn_number,
+            fifty_move_rule_counter: self.fifty_move_rule_counter,
+            can_white_castle_short: self.can_white_castle_short,
+            can_white_castle_long: self.can_white_castle_long,
+            can_black_castle_short: self.can_black_castle_short,
+            can_black_castle_long: self.can_black_castle_long,
+            castling_rook_columns: self.castling_rook_columns,
+            moves: self.moves,
+            previous_board_states: self.previous_board_states,
+            repetition_start: self.repetition_start,
+            undo_history: self.undo_history,
+            white_pocket: self.white_pocket,
+            black_pocket: self.black_pocket,
+            promoted_pieces: self.promoted_pieces,
+            result: self.result,
+            draw_offer: self.draw_offer,
+            starting_fen: self.starting_fen,
+            starting_ply: self.starting_ply,
+            variant,
+        }
+    }
+
+    /// Get the same game as seen from the other side of the board.
+    ///
+    /// The board is flipped top to bottom and every piece changes colour. The
+    /// side to move, castling rights, pockets and move history are swapped to
+    /// match, so en passant and undoing moves keep working.
+    ///
+    /// # Returns
+    ///
+    /// `ChessGame<V>`: The color flipped game.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::codec::forsyth_edwards_notation::{build_game_from_string, encode_game_as_string};
+    ///
+    /// let game = build_game_from_string("4k3/8/8/8/4P3/8/8/R3K3 b Q - 0 1").unwrap();
+    /// assert_eq!(
+    ///     "r3k3/8/8/4p3/8/8/8/4K3 w q - 0 1",
+    ///     encode_game_as_string(&game.color_flipped())
+    /// );
+    /// ```
+    pub fn color_flipped(&self) -> ChessGame<V> {
+        let starting_fen = build_variant_game_from_string(&self.starting_fen, self.variant.clone())
+            .map(|start| encode_game_as_string(&start.flipped(String::new())))
+            .expect("the starting position of a game must be valid FEN");
+        self.flipped(starting_fen)
+    }
+
+    fn flipped(&self, starting_fen: String) -> ChessGame<V> {
+        let height = self.board.get_height();
+        let flip_row = |(col, row): (usize, usize)| (col, height - 1 - row);
+
+        let mut board = self.board.flipped_vertical();
+        board.iter_mut().for_each(|square| {
+            if let Some(piece) = square.clear_piece() {
+                square.place_piece(piece.color_flipped());
+            }
+        });
+
+        let moves = self
+            .moves
+            .iter()
+            .map(|chess_move| chess_move.color_flipped(height))
+            .collect::<Vec<ChessMoveType>>();
+
+        // The stored positions are the boards after each of the last moves, so
+        // they can be found again by taking the flipped moves back one by one.
+        let mut previous_board = board.clone();
+        let mut previous_board_states = Vec::with_capacity(self.previous_board_states.len());
+        for chess_move in moves.iter().rev().take(self.previous_board_states.len()) {
+            previous_board_states.push(encode_board_as_binary(&previous_board));
+            chess_move.undo_move(&mut previous_board);
+        }
+        previous_board_states.reverse();
+
+        let (white_long, white_short, black_long, black_short) = self.castling_rook_columns;
+        let undo_history = self
+            .undo_history
+            .iter()
+            .map(|state| {
+                let (white_long, white_short, black_long, black_short) = state.castling_rights;
+                UndoState {
+                    castling_rights: (black_long, black_short, white_long, white_short),
+                    ..*state
+                }
+            })
+            .collect();
+
+        ChessGame {
+            board,
+            current_players_turn: self.current_players_turn.opposite(),
+            turn_number: self.turn_number,
+            fifty_move_rule_counter: self.fifty_move_rule_counter,
+            can_white_castle_short: self.can_black_castle_short,
+            can_white_castle_long: self.can_black_castle_long,
+            can_black_castle_short: self.can_white_castle_short,
+            can_black_castle_long: self.can_white_castle_long,
+            castling_rook_columns: (black_long, black_short, white_long, white_short),
+            moves,
+            previous_board_states,
+            repetition_start: self.repetition_start,
+            undo_history,
+            white_pocket: self.black_pocket.clone(),
+            black_pocket: self.white_pocket.clone(),
+            promoted_pieces: self.promoted_pieces.iter().copied().map(flip_row).collect(),
+            result: self.result.map(|(result, termination)| {
+                let result = match result {
+                    GameResult::WhiteWins => GameResult::BlackWins,
+                    GameResult::BlackWins => GameResult::WhiteWins,
+                    GameResult::Draw => GameResult::Draw,
+                };
+                (result, termination)
+            }),
+            draw_offer: self.draw_offer.map(|color| color.opposite()),
+            starting_fen,
+            starting_ply: self.starting_ply,
+            variant: self.variant.color_flipped(height),
+        }
+    }
+
+    /// Get the variant the game is played with
+    ///
+    /// # Returns
+    /// `&V`: The rules of the variant and any state it keeps.
+    pub fn get_variant(&self) -> &V {
+        &self.variant
+    }
+
+    /// Get a mutable reference to the variant the game is played with
+    ///
+    /// # Returns
+    /// `&mut V`: The rules of the variant and any state it keeps.
+    pub fn get_variant_mut(&mut self) -> &mut V {
+        &mut self.variant
+    }
+
     /// Get board
     ///
     /// # Returns
@@ -169,6 +585,145 @@ impl ChessGame {
         )
     }
 
+    /// Get the columns of the rooks each side castles with
+    ///
+    /// In standard chess these are the corner columns, in Chess960 they depend
+    /// on the starting position. The columns are only meaningful for the sides
+    /// that still have the matching castling right.
+    ///
+    /// # Returns
+    ///
+    /// (usize, usize, usize, usize): The rook columns, in the same order as
+    /// `get_castling_rights`:
+    /// - (white_long_rook, white_short_rook, black_long_rook, black_short_rook)
+    pub fn get_castling_rook_columns(&self) -> (usize, usize, usize, usize) {
+        self.castling_rook_columns
+    }
+
+    /// Set the columns of the rooks each side castles with
+    ///
+    /// The columns are given in the same order as `get_castling_rook_columns`
+    /// returns them.
+    ///
+    /// # Arguments
+    ///
+    /// * `white_long` - The column of the rook White castles long with.
+    /// * `white_short` - The column of the rook White castles short with.
+    /// * `black_long` - The column of the rook Black castles long with.
+    /// * `black_short` - The column of the rook Black castles short with.
+    pub fn set_castling_rook_columns(
+        &mut self,
+        white_long: usize,
+        white_short: usize,
+        black_long: usize,
+        black_short: usize,
+    ) {
+        self.castling_rook_columns = (white_long, white_short, black_long, black_short);
+    }
+
+    /// Get the pieces a player holds in their pocket
+    ///
+    /// Pieces in a pocket can be dropped onto any empty square instead of making a
+    /// regular move, as in Crazyhouse. The pockets are empty in standard chess.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player whose pocket to get.
+    ///
+    /// # Returns
+    ///
+    /// `&[PieceType]`: The pieces in the pocket, in the order they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::ChessGame;
+    /// use simple_chess::Color::White;
+    /// use simple_chess::piece::PieceType::Knight;
+    ///
+    /// let mut game = ChessGame::new();
+    /// assert!(game.get_pocket(White).is_empty());
+    ///
+    /// game.add_to_pocket(White, Knight);
+    /// assert_eq!(&[Knight], game.get_pocket(White));
+    /// ```
+    pub fn get_pocket(&self, color: Color) -> &[PieceType] {
+        match color {
+            White => &self.white_pocket,
+            Black => &self.black_pocket,
+        }
+    }
+
+    /// Add a piece to a player's pocket
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player receiving the piece.
+    /// * `piece_type` - The type of the piece.
+    pub fn add_to_pocket(&mut self, color: Color, piece_type: PieceType) {
+        self.pocket_mut(color).push(piece_type);
+    }
+
+    /// Remove a piece from a player's pocket
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player giving up the piece.
+    /// * `piece_type` - The type of the piece.
+    ///
+    /// # Returns
+    ///
+    /// `bool`: `true` if the pocket held a piece of the type, `false` if it did not.
+    pub fn remove_from_pocket(&mut self, color: Color, piece_type: PieceType) -> bool {
+        let pocket = self.pocket_mut(color);
+        match pocket
+            .iter()
+            .position(|pocket_piece| *pocket_piece == piece_type)
+        {
+            Some(index) => {
+                pocket.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn pocket_mut(&mut self, color: Color) -> &mut Vec<PieceType> {
+        match color {
+            White => &mut self.white_pocket,
+            Black => &mut self.black_pocket,
+        }
+    }
+
+    /// Check if the piece on a square was promoted from a pawn
+    ///
+    /// Promoted pieces are only tracked by variants that need them, such as
+    /// Crazyhouse, where a promoted piece turns back into a pawn when taken.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The (column, row) of the square.
+    ///
+    /// # Returns
+    ///
+    /// `bool`: `true` if the square holds a promoted piece.
+    pub fn is_promoted(&self, position: (usize, usize)) -> bool {
+        self.promoted_pieces.contains(&position)
+    }
+
+    /// Mark or unmark the piece on a square as promoted from a pawn
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The (column, row) of the square.
+    /// * `promoted` - If the piece on the square is a promoted piece.
+    pub fn set_promoted(&mut self, position: (usize, usize), promoted: bool) {
+        self.promoted_pieces.retain(|square| *square != position);
+        if promoted {
+            self.promoted_pieces.push(position);
+        }
+    }
+
     /// Returns the current turn number.
     ///
     /// # Returns
@@ -205,6 +760,45 @@ impl ChessGame {
         &self.moves
     }
 
+    /// Get the position the game was started from.
+    ///
+    /// This is the position the game was created in, so a game built from FEN
+    /// keeps that FEN even after moves are made or taken back.
+    ///
+    /// # Returns
+    ///
+    /// `&str`: The starting position in Forsyth-Edwards Notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::codec::forsyth_edwards_notation::build_game_from_string;
+    /// use simple_chess::codec::universal_chess_interface::decode_move_from_uci;
+    ///
+    /// let fen = "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1";
+    /// let mut game = build_game_from_string(fen).unwrap();
+    /// let pawn_move = decode_move_from_uci(&game, "e2e4").unwrap();
+    /// game.make_move(pawn_move);
+    ///
+    /// assert_eq!(fen, game.get_starting_fen());
+    /// ```
+    pub fn get_starting_fen(&self) -> &str {
+        &self.starting_fen
+    }
+
+    /// Get the moves made since the starting position, leaving out any move
+    /// the game was set up with, such as the pawn move behind an en passant
+    /// square in FEN.
+    pub(crate) fn get_moves_since_start(&self) -> &[ChessMoveType] {
+        self.moves.get(self.starting_ply..).unwrap_or_default()
+    }
+
+    /// Take the current position as the one the game starts from.
+    pub(crate) fn set_starting_position(&mut self) {
+        self.starting_fen = encode_game_as_string(self);
+        self.starting_ply = self.moves.len();
+    }
+
     /// Get the last move made in the game.
     ///
     /// # Returns
@@ -257,7 +851,23 @@ impl ChessGame {
     /// - Updates internal state for castling rights and the fifty-move rule counter.
     /// - Alternates the current player's turn.
     /// - Adds the move to the move history and updates previous board states.
+    /// - Withdraws a draw offer made by the other player, as moving declines it.
+    ///
+    /// Nothing happens once a result has been recorded for the game, use
+    /// `try_make_move` to find out whether the move was made.
     pub fn make_move(&mut self, chess_move: ChessMoveType) {
+        if self.result.is_some() {
+            return;
+        }
+        if self.draw_offer == Some(self.current_players_turn.opposite()) {
+            self.draw_offer = None;
+        }
+        self.undo_history.push(UndoState {
+            turn_number: self.turn_number,
+            fifty_move_rule_counter: self.fifty_move_rule_counter,
+            castling_rights: self.get_castling_rights(),
+            repetition_start: self.repetition_start,
+        });
         chess_move.make_move(&mut self.board);
         if self.current_players_turn == Black {
             self.turn_number += 1;
@@ -273,13 +883,22 @@ impl ChessGame {
             } => {
                 if taken_piece.is_some() || piece.get_piece_type() == Pawn {
                     self.fifty_move_rule_counter = 0;
-                    self.previous_board_states = vec![];
+                    self.repetition_start = self.previous_board_states.len();
                 } else {
                     self.fifty_move_rule_counter += 1;
                 }
 
                 self.update_castling_rights(taken_piece, piece, original_position, new_position);
             }
+            ChessMoveType::Drop { piece, .. } => {
+                self.remove_from_pocket(piece.get_color(), piece.get_piece_type());
+                if piece.get_piece_type() == Pawn {
+                    self.fifty_move_rule_counter = 0;
+                    self.repetition_start = self.previous_board_states.len();
+                } else {
+                    self.fifty_move_rule_counter += 1;
+                }
+            }
             ChessMoveType::Castle { .. } => {
                 match self.current_players_turn {
                     White => {
@@ -295,7 +914,7 @@ impl ChessGame {
             }
             _ => {
                 self.fifty_move_rule_counter = 0;
-                self.previous_board_states = vec![];
+                self.repetition_start = self.previous_board_states.len();
             }
         }
 
@@ -303,6 +922,7 @@ impl ChessGame {
         self.previous_board_states
             .push(encode_board_as_binary(self.get_board()));
         self.current_players_turn = self.current_players_turn.opposite();
+        V::after_move(self, &chess_move);
     }
 
     /// Undoes the last move made in the game.
@@ -313,6 +933,12 @@ impl ChessGame {
     /// - If there is a move to undo:
     ///   - The move is removed from the move history.
     ///   - The effects of the last move are reverted on the board.
+    ///   - The turn number, castling rights, fifty-move rule counter and
+    ///     repeated positions are restored, for moves made with `make_move`.
+    ///   - Any draw offer is withdrawn, and a result that came from the
+    ///     position, such as checkmate or a claimed draw, is taken back.
+    /// - Nothing happens while the game has been resigned, agreed drawn or
+    ///   lost on time, as those results stand whatever the position.
     ///
     /// This method can be used to revert a move in case of user mistakes or for implementing
     /// a "takeback" feature in the game.
@@ -345,8 +971,34 @@ impl ChessGame {
         if self.moves.is_empty() {
             return;
         }
-        let last_move = self.moves.pop().unwrap();
+        match self.result {
+            Some((
+                _,
+                Termination::Resignation | Termination::DrawAgreement | Termination::TimeForfeit,
+            )) => return,
+            Some(_) => self.result = None,
+            None => {}
+        }
+        self.draw_offer = None;
+        let last_move = *self.moves.last().unwrap();
+        V::before_undo(self, &last_move);
+        self.moves.pop();
         last_move.undo_move(&mut self.board);
+        if let ChessMoveType::Drop { piece, .. } = last_move {
+            self.add_to_pocket(piece.get_color(), piece.get_piece_type());
+        }
+        if let Some(state) = self.undo_history.pop() {
+            self.turn_number = state.turn_number;
+            self.fifty_move_rule_counter = state.fifty_move_rule_counter;
+            (
+                self.can_white_castle_long,
+                self.can_white_castle_short,
+                self.can_black_castle_long,
+                self.can_black_castle_short,
+            ) = state.castling_rights;
+            self.repetition_start = state.repetition_start;
+            self.previous_board_states.pop();
+        }
         self.current_players_turn = self.current_players_turn.opposite();
     }
 
@@ -357,45 +1009,14 @@ impl ChessGame {
         original_position: (usize, usize),
         new_position: (usize, usize),
     ) {
-        if let Some(piece) = taken_piece {
-            if piece.get_piece_type() == Rook {
-                match piece.get_color() {
-                    White => {
-                        if new_position.1 == 0 {
-                            if new_position.0 == 0 {
-                                self.can_white_castle_long = false;
-                            }
-                            if new_position.0 == self.board.get_width() - 1 {
-                                self.can_white_castle_short = false;
-                            }
-                        }
-                    }
-                    Black => {
-                        if new_position.1 == self.board.get_height() - 1 {
-                            if new_position.0 == 0 {
-                                self.can_black_castle_long = false;
-                            }
-                            if new_position.0 == self.board.get_width() - 1 {
-                                self.can_black_castle_short = false;
-                            }
-                        }
-                    }
-                }
+        if let Some(taken_piece) = taken_piece {
+            if taken_piece.get_piece_type() == Rook {
+                self.revoke_castling_right_for_rook_at(taken_piece.get_color(), new_position);
             }
         }
 
         if piece.get_piece_type() == Rook {
-            if original_position.0 < self.board.get_width() / 2 {
-                match self.current_players_turn {
-                    White => self.can_white_castle_long = false,
-                    Black => self.can_black_castle_long = false,
-                }
-            } else {
-                match self.current_players_turn {
-                    White => self.can_white_castle_short = false,
-                    Black => self.can_black_castle_short = false,
-                }
-            }
+            self.revoke_castling_right_for_rook_at(self.current_players_turn, original_position);
         }
 
         if piece.get_piece_type() == King {
@@ -412,6 +1033,36 @@ impl ChessGame {
         }
     }
 
+    pub(crate) fn revoke_castling_right_for_rook_at(
+        &mut self,
+        color: Color,
+        position: (usize, usize),
+    ) {
+        let (white_long, white_short, black_long, black_short) = self.castling_rook_columns;
+        match color {
+            White => {
+                if position.1 == 0 {
+                    if position.0 == white_long {
+                        self.can_white_castle_long = false;
+                    }
+                    if position.0 == white_short {
+                        self.can_white_castle_short = false;
+                    }
+                }
+            }
+            Black => {
+                if position.1 == self.board.get_height() - 1 {
+                    if position.0 == black_long {
+                        self.can_black_castle_long = false;
+                    }
+                    if position.0 == black_short {
+                        self.can_black_castle_short = false;
+                    }
+                }
+            }
+        }
+    }
+
     /// Get the current state of the game.
     ///
     /// # Returns
@@ -437,7 +1088,8 @@ impl ChessGame {
     /// A draw can be claimed based on:
     ///
     /// - The fifty-move rule: If fifty moves have been made without a pawn move or piece capture.
-    /// - Insufficient material: If the material left on the board is not enough for a checkmate.
+    /// - Insufficient material: If the material left on the board is not enough for either
+    ///   player to win under the rules of the variant.
     /// - Repetition: If the same board state has been repeated three times.
     ///
     ///
@@ -445,7 +1097,7 @@ impl ChessGame {
         if self.fifty_move_rule_counter >= 100 {
             return Some(FiftyMoveRule);
         }
-        if is_insufficient_material(self.get_board()) {
+        if V::is_insufficient_material(self) {
             return Some(InsufficientPieces);
         }
         if self.can_claim_draw_by_repetition() {
@@ -454,9 +1106,229 @@ impl ChessGame {
         None
     }
 
+    /// Makes a move, unless the game has finished.
+    ///
+    /// Only recorded results are checked, a position that is checkmate or
+    /// stalemate has no legal moves to make.
+    ///
+    /// # Arguments
+    ///
+    /// * `chess_move` - The move to make.
+    ///
+    /// # Returns
+    ///
+    /// `Err(GameResultError)` if a result has been recorded for the game.
+    pub fn try_make_move(&mut self, chess_move: ChessMoveType) -> Result<(), GameResultError> {
+        if let Some((result, _)) = self.result {
+            return Err(GameResultError {
+                reason: format!(
+                    "Unable to make move {}, the game has finished {}",
+                    chess_move, result
+                ),
+            });
+        }
+        self.make_move(chess_move);
+        Ok(())
+    }
+
+    /// The result of the game, `None` while it is in progress.
+    ///
+    /// Results are recorded by `resign`, `accept_draw`, `claim_draw`,
+    /// `lose_on_time` and `update_result`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::codec::forsyth_edwards_notation::build_game_from_string;
+    /// use simple_chess::{GameResult, Termination};
+    ///
+    /// let mut game = build_game_from_string("k6R/pp6/8/8/8/8/8/8 b - - 0 1").unwrap();
+    /// assert_eq!(None, game.get_result());
+    ///
+    /// game.update_result();
+    /// assert_eq!(Some(GameResult::WhiteWins), game.get_result());
+    /// assert_eq!(Some(Termination::Checkmate), game.get_termination());
+    /// ```
+    pub fn get_result(&self) -> Option<GameResult> {
+        self.result.map(|(result, _)| result)
+    }
+
+    /// Why the game finished, `None` while it is in progress.
+    pub fn get_termination(&self) -> Option<Termination> {
+        self.result.map(|(_, termination)| termination)
+    }
+
+    /// Records the result of a game that has ended on the board, by checkmate,
+    /// stalemate or the rules of the variant.
+    ///
+    /// # Returns
+    ///
+    /// `Option<GameResult>`: The result of the game, `None` while it is in
+    /// progress.
+    pub fn update_result(&mut self) -> Option<GameResult> {
+        if self.result.is_none() {
+            self.result = match self.get_game_state() {
+                GameState::Checkmate { winner } => {
+                    Some((GameResult::win_for(winner), Termination::Checkmate))
+                }
+                GameState::Stalemate => Some((GameResult::Draw, Termination::Stalemate)),
+                GameState::VariantWin { winner } => {
+                    Some((GameResult::win_for(winner), Termination::VariantWin))
+                }
+                GameState::VariantDraw => Some((GameResult::Draw, Termination::VariantDraw)),
+                GameState::InProgress { .. } | GameState::Check { .. } => None,
+            };
+        }
+        self.get_result()
+    }
+
+    fn finish(
+        &mut self,
+        result: GameResult,
+        termination: Termination,
+    ) -> Result<(), GameResultError> {
+        if let Some(finished) = self.update_result() {
+            return Err(GameResultError {
+                reason: format!("The game has already finished {}", finished),
+            });
+        }
+        self.result = Some((result, termination));
+        self.draw_offer = None;
+        Ok(())
+    }
+
+    /// Records that a player has resigned.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player who resigns.
+    ///
+    /// # Returns
+    ///
+    /// `Err(GameResultError)` if the game has already finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::Color::White;
+    /// use simple_chess::{ChessGame, GameResult, Termination};
+    ///
+    /// let mut game = ChessGame::new();
+    /// game.resign(White).unwrap();
+    ///
+    /// assert_eq!(Some(GameResult::BlackWins), game.get_result());
+    /// assert_eq!(Some(Termination::Resignation), game.get_termination());
+    /// assert!(game.resign(White).is_err());
+    /// ```
+    pub fn resign(&mut self, color: Color) -> Result<(), GameResultError> {
+        self.finish(
+            GameResult::win_for(color.opposite()),
+            Termination::Resignation,
+        )
+    }
+
+    /// Offers a draw to the other player.
+    ///
+    /// The offer stands until the other player accepts it or makes a move.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player offering the draw.
+    ///
+    /// # Returns
+    ///
+    /// `Err(GameResultError)` if the game has already finished.
+    pub fn offer_draw(&mut self, color: Color) -> Result<(), GameResultError> {
+        if let Some(finished) = self.update_result() {
+            return Err(GameResultError {
+                reason: format!("The game has already finished {}", finished),
+            });
+        }
+        self.draw_offer = Some(color);
+        Ok(())
+    }
+
+    /// The player who has offered a draw that has not been answered yet.
+    pub fn get_draw_offer(&self) -> Option<Color> {
+        self.draw_offer
+    }
+
+    /// Accepts the draw offered by the other player, ending the game in a draw
+    /// by agreement.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player accepting the draw.
+    ///
+    /// # Returns
+    ///
+    /// `Err(GameResultError)` if the other player has not offered a draw or the
+    /// game has already finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::Color::{Black, White};
+    /// use simple_chess::{ChessGame, GameResult, Termination};
+    ///
+    /// let mut game = ChessGame::new();
+    /// assert!(game.accept_draw(Black).is_err());
+    ///
+    /// game.offer_draw(White).unwrap();
+    /// game.accept_draw(Black).unwrap();
+    ///
+    /// assert_eq!(Some(GameResult::Draw), game.get_result());
+    /// assert_eq!(Some(Termination::DrawAgreement), game.get_termination());
+    /// ```
+    pub fn accept_draw(&mut self, color: Color) -> Result<(), GameResultError> {
+        if self.draw_offer != Some(color.opposite()) {
+            return Err(GameResultError {
+                reason: format!("{} has not offered a draw", color.opposite()),
+            });
+        }
+        self.finish(GameResult::Draw, Termination::DrawAgreement)
+    }
+
+    /// Claims a draw by the fifty-move rule, repetition or insufficient
+    /// material, see `can_claim_draw`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(DrawReason)` with the rule the draw was claimed by.
+    /// - `Err(GameResultError)` if no draw can be claimed or the game has
+    ///   already finished.
+    pub fn claim_draw(&mut self) -> Result<DrawReason, GameResultError> {
+        let reason = self.can_claim_draw().ok_or_else(|| GameResultError {
+            reason: String::from("A draw can not be claimed"),
+        })?;
+        self.finish(GameResult::Draw, Termination::DrawClaim(reason))?;
+        Ok(reason)
+    }
+
+    /// Records that a player has run out of time.
+    ///
+    /// Following the FIDE rules the player loses, unless the other player does
+    /// not have the material to checkmate in which case the game is drawn.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player who ran out of time.
+    ///
+    /// # Returns
+    ///
+    /// `Err(GameResultError)` if the game has already finished.
+    pub fn lose_on_time(&mut self, color: Color) -> Result<(), GameResultError> {
+        let result = if has_insufficient_mating_material(&self.board, color.opposite()) {
+            GameResult::Draw
+        } else {
+            GameResult::win_for(color.opposite())
+        };
+        self.finish(result, Termination::TimeForfeit)
+    }
+
     fn can_claim_draw_by_repetition(&self) -> bool {
         let mut previous_board_states: HashMap<Vec<u8>, usize> = HashMap::new();
-        for previous_state in &self.previous_board_states {
+        for previous_state in &self.previous_board_states[self.repetition_start..] {
             match previous_board_states.get(previous_state) {
                 None => {
                     previous_board_states.insert(previous_state.clone(), 1);
@@ -480,10 +1352,58 @@ impl Default for ChessGame {
     }
 }
 
+impl<V: Variant> TurnBasedGame for ChessGame<V> {
+    type Move = ChessMoveType;
+    type Player = Color;
+
+    fn current_player(&self) -> Color {
+        self.current_players_turn
+    }
+
+    fn legal_moves(&mut self) -> Vec<ChessMoveType> {
+        if self.result.is_some() {
+            return Vec::new();
+        }
+        match self.get_game_state() {
+            GameState::InProgress { legal_moves, .. } => legal_moves,
+            GameState::Check { legal_moves, .. } => legal_moves,
+            _ => Vec::new(),
+        }
+    }
+
+    fn apply(&mut self, game_move: ChessMoveType) {
+        self.make_move(game_move);
+    }
+
+    fn undo(&mut self) {
+        self.undo_last_move();
+    }
+
+    fn outcome(&mut self) -> Option<Outcome<Color>> {
+        if let Some((result, _)) = self.result {
+            return Some(match result.get_winner() {
+                Some(winner) => Outcome::Win(winner),
+                None => Outcome::Draw,
+            });
+        }
+        match self.get_game_state() {
+            GameState::InProgress { .. } | GameState::Check { .. } => None,
+            GameState::Checkmate { winner } | GameState::VariantWin { winner } => {
+                Some(Outcome::Win(winner))
+            }
+            GameState::Stalemate | GameState::VariantDraw => Some(Outcome::Draw),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::codec::forsyth_edwards_notation::encode_game_as_string;
+    use crate::codec::forsyth_edwards_notation::{
+        build_game_from_string, build_variant_game_from_string, encode_game_as_string,
+    };
+    use crate::variant::{Crazyhouse, ThreeCheck};
+    use game_board::perft;
 
     #[test]
     fn new_game_start_correctly() {
@@ -494,4 +1414,421 @@ mod tests {
             fen_string
         );
     }
+
+    #[test]
+    fn chess_960_position_518_is_the_standard_starting_position() {
+        let game = ChessGame::new_chess960(518).unwrap();
+        assert_eq!(
+            encode_game_as_string(&ChessGame::new()),
+            encode_game_as_string(&game)
+        );
+        assert_eq!((0, 7, 0, 7), game.get_castling_rook_columns());
+    }
+
+    #[test]
+    fn all_chess_960_positions_are_valid() {
+        let mut back_ranks = Vec::new();
+        for index in 0..960 {
+            let back_rank = chess_960_back_rank(index);
+            let columns_of = |piece_type: PieceType| -> Vec<usize> {
+                (0..8).filter(|col| back_rank[*col] == piece_type).collect()
+            };
+
+            let bishops = columns_of(Bishop);
+            let rooks = columns_of(Rook);
+            let king = columns_of(King);
+            assert_eq!(2, bishops.len());
+            assert_ne!(bishops[0] % 2, bishops[1] % 2);
+            assert_eq!(2, rooks.len());
+            assert!(rooks[0] < king[0] && king[0] < rooks[1]);
+            assert_eq!(1, columns_of(Queen).len());
+            assert_eq!(2, columns_of(Knight).len());
+
+            assert!(!back_ranks.contains(&back_rank));
+            back_ranks.push(back_rank);
+        }
+    }
+
+    #[test]
+    fn chess_960_index_must_be_below_960() {
+        assert!(ChessGame::new_chess960(959).is_ok());
+        assert!(ChessGame::new_chess960(960).is_err());
+    }
+
+    #[test]
+    fn chess_960_rook_columns_follow_the_starting_position() {
+        let game = ChessGame::new_chess960(0).unwrap();
+        assert_eq!((5, 7, 5, 7), game.get_castling_rook_columns());
+    }
+
+    #[test]
+    fn castling_rook_columns_are_set_in_the_order_they_are_read() {
+        let mut game = ChessGame::new();
+        game.set_castling_rook_columns(1, 6, 2, 5);
+        assert_eq!((1, 6, 2, 5), game.get_castling_rook_columns());
+    }
+
+    #[test]
+    fn moving_a_chess_960_rook_revokes_only_its_castling_right() {
+        let mut game = build_game_from_string("1r4kr/8/8/8/8/8/8/1R4KR w HBhb - 0 1").unwrap();
+        game.make_move(ChessMoveType::Move {
+            original_position: (1, 0),
+            new_position: (1, 7),
+            piece: ChessPiece::new(Rook, White),
+            taken_piece: Some(ChessPiece::new(Rook, Black)),
+            promotion: None,
+        });
+
+        assert_eq!((false, true, false, true), game.get_castling_rights());
+    }
+
+    #[test]
+    fn color_flipping_twice_gives_the_same_game() {
+        [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+            "1r4kr/8/8/8/8/8/8/1R4KR b HBhb - 3 20",
+        ]
+        .into_iter()
+        .for_each(|fen| {
+            let game = build_game_from_string(fen).unwrap();
+            let flipped = game.color_flipped();
+            assert_ne!(
+                encode_game_as_string(&game),
+                encode_game_as_string(&flipped)
+            );
+
+            let flipped_twice = flipped.color_flipped();
+            assert_eq!(
+                encode_game_as_string(&game),
+                encode_game_as_string(&flipped_twice)
+            );
+            assert_eq!(game.get_last_move(), flipped_twice.get_last_move());
+        });
+
+        let fen = "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2+3 2 3";
+        let game = build_variant_game_from_string(fen, ThreeCheck::new()).unwrap();
+        assert_eq!(
+            "rnbqkb1r/pppp1ppp/5n2/4p3/4P3/2N5/PPPP1PPP/R1BQKBNR b KQkq - 3+2 2 3",
+            encode_game_as_string(&game.color_flipped())
+        );
+    }
+
+    #[test]
+    fn color_flipping_swaps_pockets_and_promoted_pieces() {
+        let fen = "4k3/8/8/8/8/8/8/Q~3K3[Nbp] w - - 0 1";
+        let game = build_variant_game_from_string(fen, Crazyhouse::new()).unwrap();
+        assert_eq!(
+            "q~3k3/8/8/8/8/8/8/4K3[BPn] b - - 0 1",
+            encode_game_as_string(&game.color_flipped())
+        );
+    }
+
+    #[test]
+    fn perft_is_symmetric_under_color_flip() {
+        [
+            (
+                "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+                2,
+            ),
+            ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 3),
+            (
+                "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+                2,
+            ),
+        ]
+        .into_iter()
+        .for_each(|(fen, depth)| {
+            let mut game = build_game_from_string(fen).unwrap();
+            assert_eq!(
+                perft(&mut game, depth),
+                perft(&mut game.color_flipped(), depth)
+            );
+        });
+
+        let fen = "r1bqk2r/ppp2ppp/2n2n2/2bpp3/4P3/3P1N2/PPP1BPPP/RNBQK2R[Pp] w KQkq - 0 1";
+        let mut game = build_variant_game_from_string(fen, Crazyhouse::new()).unwrap();
+        assert_eq!(perft(&mut game, 2), perft(&mut game.color_flipped(), 2));
+    }
+
+    #[test]
+    fn game_state_is_symmetric_under_color_flip() {
+        let height = 8;
+        let flip_moves = |legal_moves: Vec<ChessMoveType>| {
+            let mut flipped = legal_moves
+                .iter()
+                .map(|legal_move| format!("{:?}", legal_move.color_flipped(height)))
+                .collect::<Vec<String>>();
+            flipped.sort();
+            flipped
+        };
+        let sorted_moves = |legal_moves: Vec<ChessMoveType>| {
+            let mut sorted = legal_moves
+                .iter()
+                .map(|legal_move| format!("{legal_move:?}"))
+                .collect::<Vec<String>>();
+            sorted.sort();
+            sorted
+        };
+
+        let mut game =
+            build_game_from_string("rnbqkbnr/ppppp2p/5p2/6pQ/4P3/8/PPPP1PPP/RNB1KBNR b KQkq - 1 3")
+                .unwrap();
+        assert_eq!(
+            GameState::Checkmate { winner: White },
+            game.get_game_state()
+        );
+        assert_eq!(
+            GameState::Checkmate { winner: Black },
+            game.color_flipped().get_game_state()
+        );
+
+        let mut game = build_game_from_string(
+            "rnbqkbnr/pppp1ppp/8/4p3/4P3/5Q2/PPPP1PPP/RNB1KBNR b KQkq - 1 2",
+        )
+        .unwrap();
+        let mut flipped = game.color_flipped();
+        match (game.get_game_state(), flipped.get_game_state()) {
+            (
+                GameState::InProgress { legal_moves, turn },
+                GameState::InProgress {
+                    legal_moves: flipped_moves,
+                    turn: flipped_turn,
+                },
+            ) => {
+                assert_eq!(turn.opposite(), flipped_turn);
+                assert_eq!(flip_moves(legal_moves), sorted_moves(flipped_moves));
+            }
+            states => panic!("expected both games to be in progress, got {states:?}"),
+        }
+    }
+
+    #[test]
+    fn flipped_game_can_undo_moves_and_claim_repetition() {
+        let mut game = ChessGame::new();
+        let knight_move = |from: (usize, usize), to: (usize, usize), color| ChessMoveType::Move {
+            original_position: from,
+            new_position: to,
+            piece: ChessPiece::new(Knight, color),
+            taken_piece: None,
+            promotion: None,
+        };
+        for _ in 0..4 {
+            game.make_move(knight_move((6, 0), (5, 2), White));
+            game.make_move(knight_move((6, 7), (5, 5), Black));
+            game.make_move(knight_move((5, 2), (6, 0), White));
+            game.make_move(knight_move((5, 5), (6, 7), Black));
+        }
+        assert_eq!(Some(Repetition), game.can_claim_draw());
+
+        let mut flipped = game.color_flipped();
+        assert_eq!(Some(Repetition), flipped.can_claim_draw());
+
+        for _ in 0..16 {
+            flipped.undo_last_move();
+        }
+        assert_eq!(
+            encode_game_as_string(&ChessGame::new()).split(' ').next(),
+            encode_game_as_string(&flipped).split(' ').next()
+        );
+        assert_eq!(Black, flipped.get_current_players_turn());
+    }
+
+    #[test]
+    fn undo_restores_castling_rights_and_counters() {
+        let mut game = build_game_from_string("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 7 12").unwrap();
+        let rook_move = ChessMoveType::Move {
+            original_position: (7, 0),
+            new_position: (6, 0),
+            piece: ChessPiece::new(Rook, White),
+            taken_piece: None,
+            promotion: None,
+        };
+
+        game.make_move(rook_move);
+        assert_eq!((true, false, true, true), game.get_castling_rights());
+        assert_eq!(8, game.get_50_move_rule_counter());
+
+        game.undo_last_move();
+        assert_eq!(
+            "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 7 12",
+            encode_game_as_string(&game)
+        );
+    }
+
+    #[test]
+    fn undo_restores_positions_for_repetition() {
+        let mut game = ChessGame::new();
+        let knight_move = |from: (usize, usize), to: (usize, usize), color| ChessMoveType::Move {
+            original_position: from,
+            new_position: to,
+            piece: ChessPiece::new(Knight, color),
+            taken_piece: None,
+            promotion: None,
+        };
+        for _ in 0..4 {
+            game.make_move(knight_move((6, 0), (5, 2), White));
+            game.make_move(knight_move((6, 7), (5, 5), Black));
+            game.make_move(knight_move((5, 2), (6, 0), White));
+            game.make_move(knight_move((5, 5), (6, 7), Black));
+        }
+        assert_eq!(Some(Repetition), game.can_claim_draw());
+
+        for _ in 0..4 {
+            game.undo_last_move();
+        }
+        assert_eq!(None, game.can_claim_draw());
+
+        game.make_move(knight_move((6, 0), (5, 2), White));
+        game.make_move(knight_move((6, 7), (5, 5), Black));
+        game.make_move(knight_move((5, 2), (6, 0), White));
+        game.make_move(knight_move((5, 5), (6, 7), Black));
+        assert_eq!(Some(Repetition), game.can_claim_draw());
+    }
+
+    #[test]
+    fn turn_based_perft_matches_known_totals() {
+        let mut game = build_game_from_string(
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        )
+        .unwrap();
+
+        assert_eq!(2039, perft(&mut game, 2));
+        assert_eq!(Some(Outcome::Draw), {
+            let mut game = build_game_from_string("k7/2Q5/1K6/8/8/8/8/8 b - - 0 1").unwrap();
+            game.outcome()
+        });
+
+        let mut game = build_game_from_string("k7/8/8/8/8/8/8/K7 w - - 0 1").unwrap();
+        assert_eq!(Some(InsufficientPieces), game.can_claim_draw());
+        assert_eq!(None, game.outcome());
+        game.claim_draw().unwrap();
+        assert_eq!(Some(Outcome::Draw), game.outcome());
+        assert!(game.legal_moves().is_empty());
+        assert_eq!(
+            Some(Outcome::Win(White)),
+            build_game_from_string("k6R/pp6/8/8/8/8/8/8 b - - 0 1")
+                .unwrap()
+                .outcome()
+        );
+    }
+
+    #[test]
+    fn finished_games_reject_moves() {
+        let mut game = ChessGame::new();
+        let pawn_move = ChessMoveType::Move {
+            original_position: (4, 1),
+            new_position: (4, 3),
+            piece: ChessPiece::new(Pawn, White),
+            taken_piece: None,
+            promotion: None,
+        };
+        game.make_move(pawn_move);
+        game.resign(White).unwrap();
+
+        assert_eq!(Some(GameResult::BlackWins), game.get_result());
+        assert!(game.legal_moves().is_empty());
+        assert_eq!(Some(Outcome::Win(Black)), game.outcome());
+        assert!(game.offer_draw(Black).is_err());
+
+        let reply = ChessMoveType::Move {
+            original_position: (4, 6),
+            new_position: (4, 4),
+            piece: ChessPiece::new(Pawn, Black),
+            taken_piece: None,
+            promotion: None,
+        };
+        assert!(game.try_make_move(reply).is_err());
+        game.make_move(reply);
+        game.apply(reply);
+        assert_eq!(&vec![pawn_move], game.get_moves());
+
+        game.undo_last_move();
+        assert_eq!(&vec![pawn_move], game.get_moves());
+        assert_eq!(Some(Termination::Resignation), game.get_termination());
+    }
+
+    #[test]
+    fn results_from_the_position_are_taken_back_with_the_last_move() {
+        let mut game = build_game_from_string("k7/8/1K6/8/8/8/8/7R w - - 99 1").unwrap();
+        let rook_move = |original_position, new_position, color| ChessMoveType::Move {
+            original_position,
+            new_position,
+            piece: ChessPiece::new(Rook, color),
+            taken_piece: None,
+            promotion: None,
+        };
+        game.make_move(rook_move((7, 0), (7, 1), White));
+        assert_eq!(Ok(FiftyMoveRule), game.claim_draw().map_err(|e| e.reason));
+
+        game.undo_last_move();
+        assert_eq!(None, game.get_result());
+        assert!(game.get_moves().is_empty());
+
+        game.make_move(rook_move((7, 0), (7, 7), White));
+        assert_eq!(None, game.get_result());
+        assert_eq!(Some(GameResult::WhiteWins), game.update_result());
+        assert_eq!(Some(Termination::Checkmate), game.get_termination());
+
+        game.undo_last_move();
+        assert_eq!(None, game.get_result());
+        assert!(game.try_make_move(rook_move((7, 0), (7, 2), White)).is_ok());
+    }
+
+    #[test]
+    fn draw_offers_are_withdrawn_by_moving() {
+        let mut game = ChessGame::new();
+        game.offer_draw(White).unwrap();
+        assert!(game.accept_draw(White).is_err());
+
+        game.make_move(ChessMoveType::Move {
+            original_position: (6, 0),
+            new_position: (5, 2),
+            piece: ChessPiece::new(Knight, White),
+            taken_piece: None,
+            promotion: None,
+        });
+        assert_eq!(Some(White), game.get_draw_offer());
+
+        game.make_move(ChessMoveType::Move {
+            original_position: (6, 7),
+            new_position: (5, 5),
+            piece: ChessPiece::new(Knight, Black),
+            taken_piece: None,
+            promotion: None,
+        });
+        assert_eq!(None, game.get_draw_offer());
+        assert!(game.accept_draw(Black).is_err());
+        assert_eq!(None, game.get_result());
+    }
+
+    #[test]
+    fn draws_can_be_claimed_and_time_forfeits_need_mating_material() {
+        let mut game = ChessGame::new();
+        assert!(game.claim_draw().is_err());
+
+        let mut game_with_kings = build_game_from_string("k7/8/8/8/8/8/8/K7 w - - 0 1").unwrap();
+        assert_eq!(InsufficientPieces, game_with_kings.claim_draw().unwrap());
+        assert_eq!(
+            Some(Termination::DrawClaim(InsufficientPieces)),
+            game_with_kings.get_termination()
+        );
+
+        game = build_game_from_string("k7/8/8/8/8/8/8/K6Q w - - 0 1").unwrap();
+        game.lose_on_time(Black).unwrap();
+        assert_eq!(Some(GameResult::WhiteWins), game.get_result());
+
+        game = build_game_from_string("k7/8/8/8/8/8/8/K6Q w - - 0 1").unwrap();
+        game.lose_on_time(White).unwrap();
+        assert_eq!(Some(GameResult::Draw), game.get_result());
+        assert_eq!(Some(Termination::TimeForfeit), game.get_termination());
+    }
+
+    #[test]
+    fn game_results_are_written_as_in_pgn() {
+        for result in ["1-0", "0-1", "1/2-1/2"] {
+            assert_eq!(result, result.parse::<GameResult>().unwrap().to_string());
+        }
+        assert!("*".parse::<GameResult>().is_err());
+    }
 }
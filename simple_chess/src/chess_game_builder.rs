@@ -1,5 +1,6 @@
 use crate::chess_move::ChessMoveType;
-use crate::piece::ChessPiece;
+use crate::piece::{ChessPiece, PieceType};
+use crate::variant::{Standard, Variant};
 use crate::{ChessGame, Color};
 use game_board::Board;
 
@@ -20,7 +21,11 @@ pub struct ChessGameBuilder {
     can_white_castle_long: Option<bool>,
     can_black_castle_short: Option<bool>,
     can_black_castle_long: Option<bool>,
+    castle_rook_columns: Option<(usize, usize, usize, usize)>,
     moves: Option<Vec<ChessMoveType>>,
+    white_pocket: Option<Vec<PieceType>>,
+    black_pocket: Option<Vec<PieceType>>,
+    promoted_pieces: Option<Vec<(usize, usize)>>,
 }
 
 impl ChessGameBuilder {
@@ -45,7 +50,11 @@ impl ChessGameBuilder {
             can_white_castle_long: None,
             can_black_castle_short: None,
             can_black_castle_long: None,
+            castle_rook_columns: None,
             moves: None,
+            white_pocket: None,
+            black_pocket: None,
+            promoted_pieces: None,
         }
     }
 
@@ -91,8 +100,24 @@ impl ChessGameBuilder {
     /// assert!(game_result.is_ok());
     /// ```
     pub fn build<'a>(self) -> Result<ChessGame, &'a str> {
+        self.build_with_variant(Standard)
+    }
+
+    /// Finalizes the construction of a `ChessGame` of a chess variant.
+    ///
+    /// Works like `build`, except the game is played with the rules of `variant`.
+    ///
+    /// # Arguments
+    ///
+    /// * `variant` - The rules of the variant the game is played with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ChessGame<V>)` - If the necessary components (board and current player's turn) are set
+    /// * `Err(&str)` - If any of the necessary components is missing
+    pub fn build_with_variant<'a, V: Variant>(self, variant: V) -> Result<ChessGame<V>, &'a str> {
         if let (Some(board), Some(current_players_turn)) = (self.board, self.current_players_turn) {
-            let game = ChessGame::build(
+            let mut game = ChessGame::build(
                 board,
                 current_players_turn,
                 self.turn_number.unwrap_or(0),
@@ -103,6 +128,20 @@ impl ChessGameBuilder {
                 self.can_black_castle_long.unwrap_or(true),
                 self.moves.unwrap_or_default(),
             );
+            if let Some((wl, ws, bl, bs)) = self.castle_rook_columns {
+                game.set_castling_rook_columns(wl, ws, bl, bs);
+            }
+            for piece_type in self.white_pocket.unwrap_or_default() {
+                game.add_to_pocket(Color::White, piece_type);
+            }
+            for piece_type in self.black_pocket.unwrap_or_default() {
+                game.add_to_pocket(Color::Black, piece_type);
+            }
+            for position in self.promoted_pieces.unwrap_or_default() {
+                game.set_promoted(position, true);
+            }
+            let mut game = game.into_variant(variant);
+            game.set_starting_position();
             Ok(game)
         } else {
             Err("Not all necessary elements are set")
@@ -199,6 +238,27 @@ impl ChessGameBuilder {
         self
     }
 
+    /// Sets the columns of the rooks each player castles with in the `ChessGame`.
+    ///
+    /// This is only needed for Chess960 positions, by default the rooks in the
+    /// corners of the board are used.
+    ///
+    /// # Arguments
+    ///
+    /// * `wl` - The column of the rook White castles long with.
+    /// * `ws` - The column of the rook White castles short with.
+    /// * `bl` - The column of the rook Black castles long with.
+    /// * `bs` - The column of the rook Black castles short with.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - Returns the `ChessGameBuilder` instance with the castling rook columns set,
+    ///   in the same order as `ChessGame::get_castling_rook_columns` returns them.
+    pub fn set_castle_rook_columns(mut self, wl: usize, ws: usize, bl: usize, bs: usize) -> Self {
+        self.castle_rook_columns = Some((wl, ws, bl, bs));
+        self
+    }
+
     /// Sets the moves made so far in the `ChessGame`.
     ///
     /// This method allows you to specify the sequence of moves that have been made
@@ -215,6 +275,45 @@ impl ChessGameBuilder {
         self.moves = Some(moves);
         self
     }
+
+    /// Sets the pieces a player holds in their pocket in the `ChessGame`.
+    ///
+    /// Pieces in a pocket can be dropped onto the board, as in Crazyhouse.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player holding the pieces.
+    /// * `pieces` - A `Vec<PieceType>` containing the pieces in the pocket.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - Returns the `ChessGameBuilder` instance with the pocket set.
+    pub fn set_pocket(mut self, color: Color, pieces: Vec<PieceType>) -> Self {
+        match color {
+            Color::White => self.white_pocket = Some(pieces),
+            Color::Black => self.black_pocket = Some(pieces),
+        }
+        self
+    }
+
+    /// Sets the squares holding pieces that were promoted from pawns in the `ChessGame`.
+    ///
+    /// # Arguments
+    ///
+    /// * `promoted_pieces` - A `Vec<(usize, usize)>` containing the (column, row) of
+    ///   every promoted piece.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - Returns the `ChessGameBuilder` instance with the promoted pieces set.
+    pub fn set_promoted_pieces(mut self, promoted_pieces: Vec<(usize, usize)>) -> Self {
+        self.promoted_pieces = Some(promoted_pieces);
+        self
+    }
+
+    pub(crate) fn get_board(&self) -> Option<&Board<ChessPiece>> {
+        self.board.as_ref()
+    }
 }
 
 impl Default for ChessGameBuilder {
@@ -226,7 +325,7 @@ impl Default for ChessGameBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::piece::PieceType::Pawn;
+    use crate::piece::PieceType::{Knight, Pawn, Queen};
     use crate::Color::{Black, White};
 
     #[test]
@@ -300,4 +399,44 @@ mod tests {
 
         assert_eq!(2, game_result.get_moves().len());
     }
+
+    #[test]
+    fn build_game_with_castle_rook_columns() {
+        let game = ChessGameBuilder::new()
+            .set_board(Board::<ChessPiece>::build(8, 8).unwrap())
+            .set_current_turn(White)
+            .set_castle_rook_columns(1, 6, 2, 5)
+            .build()
+            .unwrap();
+
+        assert_eq!((1, 6, 2, 5), game.get_castling_rook_columns());
+    }
+
+    #[test]
+    fn castle_rook_columns_default_to_the_corners() {
+        let game = ChessGameBuilder::new()
+            .set_board(Board::<ChessPiece>::build(10, 8).unwrap())
+            .set_current_turn(White)
+            .build()
+            .unwrap();
+
+        assert_eq!((0, 9, 0, 9), game.get_castling_rook_columns());
+    }
+
+    #[test]
+    fn build_game_with_pockets_and_promoted_pieces() {
+        let game = ChessGameBuilder::new()
+            .set_board(Board::<ChessPiece>::build(8, 8).unwrap())
+            .set_current_turn(White)
+            .set_pocket(White, vec![Queen, Knight])
+            .set_pocket(Black, vec![Pawn])
+            .set_promoted_pieces(vec![(1, 6)])
+            .build()
+            .unwrap();
+
+        assert_eq!(&[Queen, Knight], game.get_pocket(White));
+        assert_eq!(&[Pawn], game.get_pocket(Black));
+        assert!(game.is_promoted((1, 6)));
+        assert!(!game.is_promoted((1, 5)));
+    }
 }
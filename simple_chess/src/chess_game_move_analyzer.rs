@@ -1,8 +1,9 @@
-use crate::chess_game_state_analyzer::is_in_check;
 use crate::piece::ChessPiece;
-use crate::piece::PieceType::King;
-use crate::ChessMoveType::{Castle, Move};
+use crate::piece::PieceType::{King, Pawn, Rook};
+use crate::variant::Variant;
+use crate::ChessMoveType::Castle;
 use crate::{ChessGame, ChessMoveType, Color};
+use game_board::Board;
 
 ///
 /// Returns a vector of legal moves for the current player's turn in the given simple_chess game.
@@ -15,123 +16,224 @@ use crate::{ChessGame, ChessMoveType, Color};
 ///
 /// A vector of `ChessMoveType` that represents all possible legal moves that the current
 /// player can make without putting their king in check.
-pub fn get_legal_moves(game: &mut ChessGame) -> Vec<ChessMoveType> {
+pub fn get_legal_moves<V: Variant>(game: &mut ChessGame<V>) -> Vec<ChessMoveType> {
     let current_turn = game.get_current_players_turn();
 
-    get_all_moves_for_color(current_turn, game)
+    get_pseudo_legal_moves(game)
         .into_iter()
         .filter(|possible_move| {
             let board = game.get_board_mut();
             possible_move.make_move(board);
-            let in_check = is_in_check(current_turn, board);
+            let in_check = V::is_in_check(current_turn, board);
             possible_move.undo_move(board);
             !in_check
         })
         .collect::<Vec<ChessMoveType>>()
 }
 
-fn get_all_moves_for_color(color: Color, game: &mut ChessGame) -> Vec<ChessMoveType> {
+/// Returns all moves the current player's pieces can make, including castling and
+/// drops from the player's pocket, without checking if they leave the player's own
+/// king in check.
+pub fn get_pseudo_legal_moves<V: Variant>(game: &mut ChessGame<V>) -> Vec<ChessMoveType> {
+    get_all_moves_for_color(game.get_current_players_turn(), game)
+}
+
+fn get_all_moves_for_color<V: Variant>(
+    color: Color,
+    game: &mut ChessGame<V>,
+) -> Vec<ChessMoveType> {
     let mut moves: Vec<ChessMoveType> = Vec::new();
+    let pawn_rules = game.get_variant().pawn_rules();
     let board = game.get_board();
 
-    for row in 0..board.get_height() {
-        for col in 0..board.get_width() {
-            if let Some(piece) = board.get_piece_at_space(col, row) {
-                if piece.get_color() == color {
-                    moves.append(&mut piece.possible_moves(
-                        (col, row),
-                        board,
-                        game.get_last_move(),
-                    ));
-                }
-            }
-        }
+    for (col, row, piece) in board
+        .iter_pieces()
+        .filter(|(_, _, piece)| piece.get_color() == color)
+    {
+        moves.append(&mut piece.possible_moves_with_pawn_rules(
+            (col, row),
+            board,
+            game.get_last_move(),
+            &pawn_rules,
+        ));
     }
 
     let castling_moves = generate_possible_castling_moves(color, game);
     moves.extend(castling_moves);
 
+    let drop_moves = generate_possible_drop_moves(color, game);
+    moves.extend(drop_moves);
+
+    moves
+}
+
+/// Generates a drop for every piece type in the pocket of `color` onto every
+/// empty square, except for pawns on the first and last rank.
+fn generate_possible_drop_moves<V: Variant>(
+    color: Color,
+    game: &ChessGame<V>,
+) -> Vec<ChessMoveType> {
+    let mut unique_piece_types = Vec::new();
+    for piece_type in game.get_pocket(color) {
+        if !unique_piece_types.contains(piece_type) {
+            unique_piece_types.push(*piece_type);
+        }
+    }
+
+    let board = game.get_board();
+    let mut moves = Vec::new();
+    for piece_type in unique_piece_types {
+        let rows = match piece_type {
+            Pawn => 1..board.get_height() - 1,
+            _ => 0..board.get_height(),
+        };
+        for square in rows.filter_map(|row| board.iter_rank(row).ok()).flatten() {
+            if square.get_piece().is_none() {
+                moves.push(ChessMoveType::Drop {
+                    piece: ChessPiece::new(piece_type, color),
+                    new_position: (square.get_column(), square.get_row()),
+                });
+            }
+        }
+    }
     moves
 }
 
-fn generate_possible_castling_moves(color: Color, game: &mut ChessGame) -> Vec<ChessMoveType> {
+fn generate_possible_castling_moves<V: Variant>(
+    color: Color,
+    game: &mut ChessGame<V>,
+) -> Vec<ChessMoveType> {
     let castling_rights = game.get_castling_rights();
-    let (long_castle, short_castle) = match color {
-        Color::White => (castling_rights.0, castling_rights.1),
-        Color::Black => (castling_rights.2, castling_rights.3),
+    let rook_columns = game.get_castling_rook_columns();
+    let (long_castle, short_castle, long_rook_col, short_rook_col) = match color {
+        Color::White => (
+            castling_rights.0,
+            castling_rights.1,
+            rook_columns.0,
+            rook_columns.1,
+        ),
+        Color::Black => (
+            castling_rights.2,
+            castling_rights.3,
+            rook_columns.2,
+            rook_columns.3,
+        ),
     };
 
     let mut moves = Vec::new();
     let board = game.get_board_mut();
+    let width = board.get_width();
+    if width < 4 || !(long_castle || short_castle) {
+        return moves;
+    }
+
     let row = match color {
         Color::White => 0,
         Color::Black => board.get_height() - 1,
     };
 
-    if is_in_check(color, board) {
+    let king_col = match (0..width).find(|col| {
+        board
+            .get_piece_at_space(*col, row)
+            .is_some_and(|piece| piece.get_piece_type() == King && piece.get_color() == color)
+    }) {
+        Some(king_col) => king_col,
+        None => return moves,
+    };
+
+    if V::is_in_check(color, board) {
         return moves;
     }
-    if long_castle {
-        for col in 1..board.get_width() - 1 {
-            if let Some(piece) = board.get_piece_at_space(col, row) {
-                if piece.get_piece_type() != King || piece.get_color() != color {
-                    break;
-                }
-                let tmp_move = Move {
-                    original_position: (col, row),
-                    new_position: (col - 1, row),
-                    piece: ChessPiece::new(King, color),
-                    taken_piece: None,
-                    promotion: None,
-                };
-                tmp_move.make_move(board);
-                if !is_in_check(color, board) {
-                    moves.push(Castle {
-                        rook_original_position: (0, row),
-                        rook_new_position: (col - 1, row),
-                        king_original_position: (col, row),
-                        king_new_position: (col - 2, row),
-                    })
-                }
-                tmp_move.undo_move(board);
-            }
+
+    if long_castle && long_rook_col < king_col {
+        if let Some(castle) =
+            build_castle_move::<V>(board, color, row, (king_col, 2), (long_rook_col, 3))
+        {
+            moves.push(castle);
         }
     }
-    if short_castle {
-        for col in (0..board.get_width() - 1).rev() {
-            if let Some(piece) = board.get_piece_at_space(col, row) {
-                if piece.get_piece_type() != King || piece.get_color() != color {
-                    break;
-                }
-                let tmp_move = Move {
-                    original_position: (col, row),
-                    new_position: (col + 1, row),
-                    piece: ChessPiece::new(King, color),
-                    taken_piece: None,
-                    promotion: None,
-                };
-                tmp_move.make_move(board);
-                if !is_in_check(color, board) {
-                    moves.push(Castle {
-                        rook_original_position: (board.get_width() - 1, row),
-                        rook_new_position: (col + 1, row),
-                        king_original_position: (col, row),
-                        king_new_position: (col + 2, row),
-                    });
-                }
-                tmp_move.undo_move(board);
-            }
+    if short_castle && short_rook_col > king_col {
+        if let Some(castle) = build_castle_move::<V>(
+            board,
+            color,
+            row,
+            (king_col, width - 2),
+            (short_rook_col, width - 3),
+        ) {
+            moves.push(castle);
         }
     }
 
     moves
 }
 
+/// Builds a castle move if the squares the king and rook travel over are free
+/// and the king does not pass through check.
+///
+/// The king and rook may start anywhere on the back rank, as in Chess960, but
+/// always end on the same squares as in standard chess.
+fn build_castle_move<V: Variant>(
+    board: &mut Board<ChessPiece>,
+    color: Color,
+    row: usize,
+    (king_col, king_target): (usize, usize),
+    (rook_col, rook_target): (usize, usize),
+) -> Option<ChessMoveType> {
+    match board.get_piece_at_space(rook_col, row) {
+        Some(piece) if piece.get_piece_type() == Rook && piece.get_color() == color => {}
+        _ => return None,
+    }
+
+    let first_col = king_col.min(king_target).min(rook_col).min(rook_target);
+    let last_col = king_col.max(king_target).max(rook_col).max(rook_target);
+    if (first_col..=last_col).any(|col| {
+        col != king_col && col != rook_col && board.get_piece_at_space(col, row).is_some()
+    }) {
+        return None;
+    }
+
+    // The squares the king passes over are checked with the rook still in place,
+    // the square the king lands on with the rook on its new square.
+    let king = board.remove_piece(king_col, row).unwrap();
+    let rook = board.remove_piece(rook_col, row).unwrap();
+    let passes_through_check = (king_col.min(king_target)..=king_col.max(king_target))
+        .filter(|col| *col != king_col)
+        .any(|col| {
+            let rook_col = match col == king_target {
+                true => Some(rook_target),
+                false => Some(rook_col).filter(|rook_col| *rook_col != col),
+            };
+            if let Some(rook_col) = rook_col {
+                board.place_piece(rook, rook_col, row);
+            }
+            board.place_piece(king, col, row);
+            let in_check = V::is_in_check(color, board);
+            board.remove_piece(col, row);
+            if let Some(rook_col) = rook_col {
+                board.remove_piece(rook_col, row);
+            }
+            in_check
+        });
+    board.place_piece(king, king_col, row);
+    board.place_piece(rook, rook_col, row);
+
+    if passes_through_check {
+        return None;
+    }
+
+    Some(Castle {
+        rook_original_position: (rook_col, row),
+        rook_new_position: (rook_target, row),
+        king_original_position: (king_col, row),
+        king_new_position: (king_target, row),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::codec::forsyth_edwards_notation::build_game_from_string;
-    use crate::piece::PieceType::{Bishop, Knight, Pawn, Queen, Rook};
+    use crate::piece::PieceType::{Bishop, Knight, Pawn, Queen};
     use crate::ChessMoveType::Move;
     use crate::Color::{Black, White};
 
@@ -353,4 +455,65 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn chess_960_perft() {
+        let positions = [
+            (
+                "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9",
+                [21, 528, 12189],
+            ),
+            (
+                "2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9",
+                [21, 807, 18002],
+            ),
+            (
+                "b1q1rrkb/pppppppp/3nn3/8/P7/1PPP4/4PPPP/BQNNRKRB w GE - 1 9",
+                [20, 479, 10471],
+            ),
+            (
+                "qbbnnrkr/2pp2pp/p7/1p2pp2/8/P3PP2/1PPP1KPP/QBBNNR1R w hf - 0 9",
+                [22, 593, 13440],
+            ),
+            (
+                "1nbbnrkr/p1p1ppp1/3p4/1p3P1p/3Pq2P/8/PPP1P1P1/QNBBNRKR w HFhf - 0 9",
+                [28, 1120, 31058],
+            ),
+        ];
+
+        for (fen, expected) in positions {
+            let mut game = build_game_from_string(fen).unwrap();
+            for (depth, expected_nodes) in expected.iter().enumerate() {
+                assert_eq!(
+                    *expected_nodes,
+                    game_board::perft(&mut game, depth + 1),
+                    "{fen} at depth {}",
+                    depth + 1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn chess_960_castle_with_king_and_rook_swapping_places() {
+        let mut game = build_game_from_string("4k3/8/8/8/8/8/8/5KR1 w G - 0 1").unwrap();
+        let legal_moves = get_legal_moves(&mut game);
+
+        assert!(legal_moves.contains(&Castle {
+            rook_original_position: (6, 0),
+            rook_new_position: (5, 0),
+            king_original_position: (5, 0),
+            king_new_position: (6, 0),
+        }));
+    }
+
+    #[test]
+    fn chess_960_can_not_castle_when_king_passes_through_check() {
+        let mut game = build_game_from_string("3r2k1/8/8/8/8/8/8/1K5R w H - 0 1").unwrap();
+        let legal_moves = get_legal_moves(&mut game);
+
+        assert!(!legal_moves
+            .iter()
+            .any(|legal_move| matches!(legal_move, Castle { .. })));
+    }
 }
@@ -1,6 +1,6 @@
-use crate::chess_game_move_analyzer;
 use crate::piece::PieceType::King;
 use crate::piece::{ChessPiece, PieceType};
+use crate::variant::Variant;
 use crate::ChessMoveType::Move;
 use crate::{ChessGame, ChessMoveType, Color};
 use game_board::Board;
@@ -8,12 +8,14 @@ use game_board::Board;
 /// Represents the current state of a simple_chess game.
 ///
 /// The `GameState` enum is used to track the status of an ongoing simple_chess game.
-/// It can be one of four possible states:
+/// It can be one of five possible states:
 ///
 /// - `InProgress`: The game is actively being played, with available legal moves for the current turn.
 /// - `Check`: The current player is in check, meaning their king is under threat but has legal moves to counter.
 /// - `Checkmate`: The current player's king is in check and there are no legal moves to escape, resulting in a victory for the opponent.
 /// - `Stalemate`: The game is in a state where the current player has no legal moves, but their king is not in check, resulting in a draw.
+/// - `VariantWin`: A player has won by a rule of the variant being played, such as giving a third check in Three-check.
+/// - `VariantDraw`: The game has ended in a draw by a rule of the variant being played.
 ///
 /// # Enum Variants
 ///
@@ -21,6 +23,8 @@ use game_board::Board;
 /// - `Check`: Holds a vector of legal moves and indicates whose turn it is.
 /// - `Checkmate`: Indicates the winning player's color.
 /// - `Stalemate`: Indicates the game has ended in a draw.
+/// - `VariantWin`: Indicates the winning player's color.
+/// - `VariantDraw`: Indicates the game has ended in a draw.
 #[derive(Debug, PartialEq)]
 pub enum GameState {
     InProgress {
@@ -35,6 +39,10 @@ pub enum GameState {
         winner: Color,
     },
     Stalemate,
+    VariantWin {
+        winner: Color,
+    },
+    VariantDraw,
 }
 
 /// Determines the current state of a simple_chess game.
@@ -48,6 +56,10 @@ pub enum GameState {
 ///   moves left, resulting in the opponent's victory.
 /// - `Stalemate`: If the current player has no legal moves, and their king is not
 ///   in check, resulting in a draw.
+/// - `VariantWin`: If a player has won by a rule of the variant being played.
+/// - `VariantDraw`: If the game is drawn by a rule of the variant being played.
+///
+/// The legal moves and the end of the game are decided by the `Variant` of the game.
 ///
 /// # Parameters
 ///
@@ -57,9 +69,13 @@ pub enum GameState {
 /// # Returns
 ///
 /// - `GameState`: Enum variant representing the current state of the simple_chess game.
-pub fn get_game_state(game: &mut ChessGame) -> GameState {
-    let legal_moves = chess_game_move_analyzer::get_legal_moves(game);
-    if is_in_check(game.get_current_players_turn(), game.get_board()) {
+pub fn get_game_state<V: Variant>(game: &mut ChessGame<V>) -> GameState {
+    let legal_moves = V::legal_moves(game);
+    if let Some(game_state) = V::game_end(game, &legal_moves) {
+        return game_state;
+    }
+
+    if V::is_in_check(game.get_current_players_turn(), game.get_board()) {
         if legal_moves.is_empty() {
             GameState::Checkmate {
                 winner: game.get_current_players_turn().opposite(),
@@ -96,28 +112,18 @@ pub fn get_game_state(game: &mut ChessGame) -> GameState {
 ///
 /// - `bool`: Returns `true` if the player's king is in check, meaning it is under threat. Returns `false` otherwise.
 pub fn is_in_check(color: Color, board: &Board<ChessPiece>) -> bool {
-    for row in 0..board.get_height() {
-        for col in 0..board.get_width() {
-            if let Some(piece) = board.get_piece_at_space(col, row) {
-                if piece.get_color() == color.opposite() {
-                    let moves = piece.possible_moves((col, row), board, None);
-                    for m in moves {
-                        match m {
-                            Move { taken_piece, .. } => {
-                                if let Some(taken_piece) = taken_piece {
-                                    if taken_piece.get_piece_type() == King {
-                                        return true;
-                                    }
-                                }
-                            }
-                            _ => return false,
-                        }
-                    }
-                }
-            }
-        }
-    }
-    false
+    board
+        .iter_pieces()
+        .filter(|(_, _, piece)| piece.get_color() == color.opposite())
+        .any(|(col, row, piece)| {
+            piece
+                .possible_moves((col, row), board, None)
+                .iter()
+                .any(|m| {
+                    matches!(m, Move { taken_piece: Some(taken_piece), .. }
+                        if taken_piece.get_piece_type().is_royal())
+                })
+        })
 }
 
 /// Determines if there is insufficient material on the board to continue the game.
@@ -136,37 +142,45 @@ pub fn is_in_check(color: Color, board: &Board<ChessPiece>) -> bool {
 /// - `bool`: Returns `true` if both players have insufficient material to reach checkmate.
 ///   Otherwise, it returns `false`.
 pub fn is_insufficient_material(board: &Board<ChessPiece>) -> bool {
-    let mut white_pieces = vec![];
-    let mut black_pieces = vec![];
-    for col in 0..board.get_width() {
-        for row in 0..board.get_height() {
-            if let Some(piece) = board.get_piece_at_space(col, row) {
-                match piece.get_color() {
-                    Color::White => white_pieces.push(piece),
-                    Color::Black => black_pieces.push(piece),
-                }
-            }
-        }
-    }
+    has_insufficient_mating_material(board, Color::White)
+        && has_insufficient_mating_material(board, Color::Black)
+}
 
-    let check = |pieces: &Vec<&ChessPiece>| -> bool {
-        if pieces.len() < 2 {
-            return true;
-        } else if pieces.len() == 2 {
-            let piece_type_a = pieces[0].get_piece_type();
-            let piece_type_b = pieces[1].get_piece_type();
-
-            let other = if piece_type_a == King {
-                piece_type_b
-            } else {
-                piece_type_a
-            };
-            return matches!(other, PieceType::Knight | PieceType::Bishop);
-        }
-        false
-    };
+/// Determines if one player has too little material to ever checkmate the other.
+///
+/// A lone king, or a king with a single knight or bishop, can not checkmate. This decides
+/// the result when the other player runs out of time: under the FIDE rules the game is a
+/// draw rather than a loss if the player who still has time could not checkmate.
+///
+/// # Parameters
+///
+/// - `board`: The board to check.
+/// - `color`: The player whose pieces are checked.
+///
+/// # Returns
+///
+/// - `bool`: Returns `true` if the player's pieces can not reach checkmate.
+pub fn has_insufficient_mating_material(board: &Board<ChessPiece>, color: Color) -> bool {
+    let pieces: Vec<&ChessPiece> = board
+        .iter_pieces()
+        .map(|(_, _, piece)| piece)
+        .filter(|piece| piece.get_color() == color)
+        .collect();
+
+    if pieces.len() < 2 {
+        return true;
+    } else if pieces.len() == 2 {
+        let piece_type_a = pieces[0].get_piece_type();
+        let piece_type_b = pieces[1].get_piece_type();
 
-    check(&white_pieces) && check(&black_pieces)
+        let other = if piece_type_a == King {
+            piece_type_b
+        } else {
+            piece_type_a
+        };
+        return matches!(other, PieceType::Knight | PieceType::Bishop);
+    }
+    false
 }
 
 #[cfg(test)]
@@ -238,6 +252,13 @@ mod tests {
         assert!(is_insufficient_material(game.get_board()));
     }
 
+    #[test]
+    fn mating_material_is_checked_per_player() {
+        let game = build_game_from_string("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        assert!(!has_insufficient_mating_material(game.get_board(), White));
+        assert!(has_insufficient_mating_material(game.get_board(), Black));
+    }
+
     #[test]
     fn king_and_bishop_or_knight_is_insufficient_material() {
         let game = build_game_from_string("k7/8/bN6/8/8/8/8/K7 b - - 0 1").unwrap();
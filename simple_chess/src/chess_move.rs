@@ -1,5 +1,5 @@
 use crate::piece::ChessPiece;
-use game_board::{get_square_name_from_row_and_col, Board};
+use game_board::{Board, Coord};
 use std::fmt::{Display, Formatter};
 
 /// Represents different types of simple_chess moves.
@@ -56,6 +56,17 @@ pub enum ChessMoveType {
         king_original_position: (usize, usize),
         king_new_position: (usize, usize),
     },
+
+    /// A piece taken earlier in the game is placed from the player's pocket onto an
+    /// empty square, as in Crazyhouse.
+    ///
+    /// Fields:
+    /// - `piece`: ChessPiece - The piece being dropped.
+    /// - `new_position`: (usize, usize) - The empty square the piece is dropped on.
+    Drop {
+        piece: ChessPiece,
+        new_position: (usize, usize),
+    },
 }
 
 impl ChessMoveType {
@@ -137,6 +148,12 @@ impl ChessMoveType {
                 board.place_piece(rook, rook_new_position.0, rook_new_position.1);
                 board.place_piece(king, king_new_position.0, king_new_position.1);
             }
+            ChessMoveType::Drop {
+                piece,
+                new_position,
+            } => {
+                board.place_piece(*piece, new_position.0, new_position.1);
+            }
         }
     }
 
@@ -182,6 +199,169 @@ impl ChessMoveType {
                 board.place_piece(rook, rook_original_position.0, rook_original_position.1);
                 board.place_piece(king, king_original_position.0, king_original_position.1);
             }
+            ChessMoveType::Drop { new_position, .. } => {
+                board.remove_piece(new_position.0, new_position.1);
+            }
+        }
+    }
+
+    /// Gets the square the moving piece starts on.
+    ///
+    /// For castling this is the square of the king.
+    ///
+    /// # Returns
+    ///
+    /// The starting square, or `None` for a drop, which does not start on the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use game_board::Coord;
+    /// use simple_chess::ChessMoveType;
+    /// use simple_chess::Color::White;
+    /// use simple_chess::piece::ChessPiece;
+    /// use simple_chess::piece::PieceType::Pawn;
+    ///
+    /// let game_move = ChessMoveType::Move {
+    ///     original_position: (4, 1),
+    ///     new_position: (4, 3),
+    ///     piece: ChessPiece::new(Pawn, White),
+    ///     taken_piece: None,
+    ///     promotion: None,
+    /// };
+    ///
+    /// assert_eq!(Some("e2".parse::<Coord>().unwrap()), game_move.get_original_position());
+    /// ```
+    pub fn get_original_position(&self) -> Option<Coord> {
+        match self {
+            ChessMoveType::Move {
+                original_position, ..
+            }
+            | ChessMoveType::EnPassant {
+                original_position, ..
+            } => Some(Coord::from(*original_position)),
+            ChessMoveType::Castle {
+                king_original_position,
+                ..
+            } => Some(Coord::from(*king_original_position)),
+            ChessMoveType::Drop { .. } => None,
+        }
+    }
+
+    /// Gets the square the moving piece ends on.
+    ///
+    /// For castling this is the square of the king.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use game_board::Coord;
+    /// use simple_chess::ChessMoveType;
+    /// use simple_chess::Color::White;
+    /// use simple_chess::piece::ChessPiece;
+    /// use simple_chess::piece::PieceType::Knight;
+    ///
+    /// let game_move = ChessMoveType::Drop {
+    ///     piece: ChessPiece::new(Knight, White),
+    ///     new_position: (5, 2),
+    /// };
+    ///
+    /// assert_eq!("f3".parse::<Coord>().unwrap(), game_move.get_new_position());
+    /// ```
+    pub fn get_new_position(&self) -> Coord {
+        match self {
+            ChessMoveType::Move { new_position, .. }
+            | ChessMoveType::EnPassant { new_position, .. }
+            | ChessMoveType::Drop { new_position, .. } => Coord::from(*new_position),
+            ChessMoveType::Castle {
+                king_new_position, ..
+            } => Coord::from(*king_new_position),
+        }
+    }
+
+    /// Gets the same move as played on a board flipped top to bottom, with the
+    /// colours of all pieces swapped.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The height of the board the move is played on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::ChessMoveType;
+    /// use simple_chess::Color::{Black, White};
+    /// use simple_chess::piece::ChessPiece;
+    /// use simple_chess::piece::PieceType::Pawn;
+    ///
+    /// let game_move = ChessMoveType::Move {
+    ///     original_position: (4, 1),
+    ///     new_position: (4, 3),
+    ///     piece: ChessPiece::new(Pawn, White),
+    ///     taken_piece: None,
+    ///     promotion: None,
+    /// };
+    ///
+    /// assert_eq!(
+    ///     ChessMoveType::Move {
+    ///         original_position: (4, 6),
+    ///         new_position: (4, 4),
+    ///         piece: ChessPiece::new(Pawn, Black),
+    ///         taken_piece: None,
+    ///         promotion: None,
+    ///     },
+    ///     game_move.color_flipped(8)
+    /// );
+    /// ```
+    pub fn color_flipped(&self, height: usize) -> ChessMoveType {
+        let flip = |(col, row): (usize, usize)| (col, height - 1 - row);
+        match *self {
+            ChessMoveType::Move {
+                original_position,
+                new_position,
+                piece,
+                taken_piece,
+                promotion,
+            } => ChessMoveType::Move {
+                original_position: flip(original_position),
+                new_position: flip(new_position),
+                piece: piece.color_flipped(),
+                taken_piece: taken_piece.map(|piece| piece.color_flipped()),
+                promotion: promotion.map(|piece| piece.color_flipped()),
+            },
+            ChessMoveType::EnPassant {
+                original_position,
+                new_position,
+                piece,
+                taken_piece,
+                taken_piece_position,
+                promotion,
+            } => ChessMoveType::EnPassant {
+                original_position: flip(original_position),
+                new_position: flip(new_position),
+                piece: piece.color_flipped(),
+                taken_piece: taken_piece.color_flipped(),
+                taken_piece_position: flip(taken_piece_position),
+                promotion: promotion.map(|piece| piece.color_flipped()),
+            },
+            ChessMoveType::Castle {
+                rook_original_position,
+                rook_new_position,
+                king_original_position,
+                king_new_position,
+            } => ChessMoveType::Castle {
+                rook_original_position: flip(rook_original_position),
+                rook_new_position: flip(rook_new_position),
+                king_original_position: flip(king_original_position),
+                king_new_position: flip(king_new_position),
+            },
+            ChessMoveType::Drop {
+                piece,
+                new_position,
+            } => ChessMoveType::Drop {
+                piece: piece.color_flipped(),
+                new_position: flip(new_position),
+            },
         }
     }
 
@@ -224,9 +404,9 @@ impl Display for ChessMoveType {
                     f,
                     "{:?} at {} {} at {} {}",
                     piece.get_piece_type(),
-                    get_square_name_from_row_and_col(original_position.0, original_position.1),
+                    Coord::from(*original_position),
                     take_string,
-                    get_square_name_from_row_and_col(new_position.0, new_position.1),
+                    Coord::from(*new_position),
                     promotion_string
                 )
             }
@@ -238,6 +418,17 @@ impl Display for ChessMoveType {
             ChessMoveType::Castle { .. } => {
                 write!(f, "Castle")
             }
+            ChessMoveType::Drop {
+                piece,
+                new_position,
+            } => {
+                write!(
+                    f,
+                    "{:?} dropped at {}",
+                    piece.get_piece_type(),
+                    Coord::from(*new_position)
+                )
+            }
         }
     }
 }
@@ -0,0 +1,565 @@
+use crate::Color;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Where a `GameClock` gets the current time from.
+///
+/// Only differences between two readings are used, so the time can be
+/// measured from any starting point.
+pub trait TimeSource {
+    /// The time passed since the starting point of the source.
+    fn now(&self) -> Duration;
+}
+
+/// Reads the time from the monotonic system clock.
+#[derive(Debug, Clone, Copy)]
+pub struct SystemTimeSource {
+    start: Instant,
+}
+
+impl SystemTimeSource {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Default for SystemTimeSource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeSource for SystemTimeSource {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+/// A time source that only moves when told to, for testing.
+///
+/// Clones share the same time, so a test can keep one to move the time of a
+/// clock it has given the other to.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::clock::{ManualTimeSource, TimeSource};
+/// use std::time::Duration;
+///
+/// let time_source = ManualTimeSource::new();
+/// let shared = time_source.clone();
+/// shared.advance(Duration::from_secs(3));
+///
+/// assert_eq!(Duration::from_secs(3), time_source.now());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ManualTimeSource {
+    nanos: Arc<AtomicU64>,
+}
+
+impl ManualTimeSource {
+    /// Creates a time source at time 0.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the time forward.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - How much time passes.
+    pub fn advance(&self, duration: Duration) {
+        self.nanos
+            .fetch_add(duration.as_nanos() as u64, Ordering::SeqCst);
+    }
+}
+
+impl TimeSource for ManualTimeSource {
+    fn now(&self) -> Duration {
+        Duration::from_nanos(self.nanos.load(Ordering::SeqCst))
+    }
+}
+
+/// Time given to a player for each move, on top of the time of the period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bonus {
+    None,
+    /// Fischer increment, added to the clock after every move.
+    Increment(Duration),
+    /// Bronstein delay, the time used for a move is given back after it up to
+    /// this much.
+    Bronstein(Duration),
+    /// Simple or US delay, the clock only starts counting down after this much
+    /// time has passed in a move.
+    SimpleDelay(Duration),
+}
+
+/// One part of a time control.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimePeriod {
+    /// The number of moves to make in the period, `None` for the rest of the
+    /// game.
+    pub moves: Option<usize>,
+    /// The time added to the clock at the start of the period.
+    pub time: Duration,
+    pub bonus: Bonus,
+}
+
+/// The time each player has for the game, as one or more periods.
+///
+/// A period with a number of moves is followed by the next period once the
+/// player has made that many moves in it, and the time left over carries on
+/// into it. If the last period has a number of moves it is repeated.
+///
+/// Time controls can be read from and written as the `TimeControl` tag of PGN,
+/// where periods are separated by `:` and each is `moves/seconds` or just
+/// `seconds` for the rest of the game, optionally followed by `+seconds` for
+/// an increment. `d` and `b` are accepted in place of `+` for simple and
+/// Bronstein delays.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::clock::{Bonus, TimeControl};
+/// use std::time::Duration;
+///
+/// // 90 minutes for 40 moves, then 30 minutes, with 30 seconds per move.
+/// let classical: TimeControl = "40/5400+30:1800+30".parse().unwrap();
+/// let periods = classical.get_periods();
+///
+/// assert_eq!(Some(40), periods[0].moves);
+/// assert_eq!(Duration::from_secs(1800), periods[1].time);
+/// assert_eq!(Bonus::Increment(Duration::from_secs(30)), periods[1].bonus);
+/// assert_eq!("40/5400+30:1800+30", classical.to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeControl {
+    periods: Vec<TimePeriod>,
+}
+
+impl TimeControl {
+    /// Creates a time control from its periods.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no periods.
+    pub fn new(periods: Vec<TimePeriod>) -> Self {
+        assert!(!periods.is_empty(), "A time control needs a period");
+        Self { periods }
+    }
+
+    /// The whole game in `time`.
+    pub fn sudden_death(time: Duration) -> Self {
+        Self::with_bonus(time, Bonus::None)
+    }
+
+    /// The whole game in `time`, with `increment` added after every move.
+    pub fn fischer(time: Duration, increment: Duration) -> Self {
+        Self::with_bonus(time, Bonus::Increment(increment))
+    }
+
+    /// The whole game in `time`, with up to `delay` given back after every move.
+    pub fn bronstein(time: Duration, delay: Duration) -> Self {
+        Self::with_bonus(time, Bonus::Bronstein(delay))
+    }
+
+    /// The whole game in `time`, with the clock waiting `delay` before counting
+    /// down in every move.
+    pub fn simple_delay(time: Duration, delay: Duration) -> Self {
+        Self::with_bonus(time, Bonus::SimpleDelay(delay))
+    }
+
+    fn with_bonus(time: Duration, bonus: Bonus) -> Self {
+        Self::new(vec![TimePeriod {
+            moves: None,
+            time,
+            bonus,
+        }])
+    }
+
+    pub fn get_periods(&self) -> &[TimePeriod] {
+        &self.periods
+    }
+}
+
+impl FromStr for TimeControl {
+    type Err = TimeControlError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TimeControlError {
+            reason: format!("Invalid time control {}", s),
+        };
+        let seconds = |value: &str| {
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+                .map(Duration::from_secs_f64)
+                .ok_or_else(invalid)
+        };
+
+        let mut periods = Vec::new();
+        for period in s.trim().split(':') {
+            let (moves, rest) = match period.split_once('/') {
+                Some((moves, rest)) => (Some(moves.parse().map_err(|_| invalid())?), rest),
+                None => (None, period),
+            };
+            let (time, bonus) = match rest.find(['+', 'd', 'b']) {
+                Some(index) => {
+                    let bonus_time = seconds(&rest[index + 1..])?;
+                    let bonus = match &rest[index..index + 1] {
+                        "+" => Bonus::Increment(bonus_time),
+                        "d" => Bonus::SimpleDelay(bonus_time),
+                        _ => Bonus::Bronstein(bonus_time),
+                    };
+                    (seconds(&rest[..index])?, bonus)
+                }
+                None => (seconds(rest)?, Bonus::None),
+            };
+            periods.push(TimePeriod { moves, time, bonus });
+        }
+        Ok(Self::new(periods))
+    }
+}
+
+impl Display for TimeControl {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, period) in self.periods.iter().enumerate() {
+            if index > 0 {
+                write!(f, ":")?;
+            }
+            if let Some(moves) = period.moves {
+                write!(f, "{}/", moves)?;
+            }
+            write!(f, "{}", period.time.as_secs_f64())?;
+            match period.bonus {
+                Bonus::None => {}
+                Bonus::Increment(time) => write!(f, "+{}", time.as_secs_f64())?,
+                Bonus::Bronstein(time) => write!(f, "b{}", time.as_secs_f64())?,
+                Bonus::SimpleDelay(time) => write!(f, "d{}", time.as_secs_f64())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct TimeControlError {
+    pub reason: String,
+}
+
+/// A chess clock counting down the time of both players.
+///
+/// The clock of the player to move runs until they press the clock after
+/// their move, which starts the clock of the other player. A player whose time
+/// runs out has lost on time, or drawn if the other player can not checkmate,
+/// see `simulation::play_game`.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::clock::{GameClock, ManualTimeSource, TimeControl};
+/// use simple_chess::Color::{Black, White};
+/// use std::time::Duration;
+///
+/// let time = ManualTimeSource::new();
+/// let control = TimeControl::fischer(Duration::from_secs(60), Duration::from_secs(1));
+/// let mut clock = GameClock::new(control, time.clone());
+///
+/// clock.start(White);
+/// time.advance(Duration::from_secs(10));
+/// assert_eq!(None, clock.press());
+/// assert_eq!(Duration::from_secs(51), clock.get_remaining(White));
+///
+/// time.advance(Duration::from_secs(61));
+/// assert_eq!(Some(Black), clock.get_flagged());
+/// ```
+#[derive(Debug, Clone)]
+pub struct GameClock<T = SystemTimeSource> {
+    time_control: TimeControl,
+    time_source: T,
+    remaining: [Duration; 2],
+    moves: [usize; 2],
+    moves_in_period: [usize; 2],
+    periods: [usize; 2],
+    running: Option<(Color, Duration)>,
+    flagged: Option<Color>,
+}
+
+impl<T: TimeSource> GameClock<T> {
+    /// Creates a stopped clock with the time of the first period for both
+    /// players.
+    ///
+    /// # Arguments
+    ///
+    /// * `time_control` - How much time the players get.
+    /// * `time_source` - Where the time is read from.
+    pub fn new(time_control: TimeControl, time_source: T) -> Self {
+        let time = time_control.periods[0].time;
+        Self {
+            time_control,
+            time_source,
+            remaining: [time, time],
+            moves: [0, 0],
+            moves_in_period: [0, 0],
+            periods: [0, 0],
+            running: None,
+            flagged: None,
+        }
+    }
+
+    pub fn get_time_control(&self) -> &TimeControl {
+        &self.time_control
+    }
+
+    /// Starts the clock of a player, stopping the other clock without giving
+    /// its player a bonus.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player whose clock starts, usually the player to move.
+    pub fn start(&mut self, color: Color) {
+        self.stop();
+        if self.flagged.is_none() {
+            self.running = Some((color, self.time_source.now()));
+        }
+    }
+
+    /// Stops the running clock, for example to pause a game.
+    pub fn stop(&mut self) {
+        if let Some((color, started)) = self.running.take() {
+            self.charge(color, self.time_source.now() - started);
+        }
+    }
+
+    /// The player whose clock is running, `None` if the clock is stopped.
+    pub fn get_running(&self) -> Option<Color> {
+        self.running.map(|(color, _)| color)
+    }
+
+    /// The number of moves a player has made, counted when they press the
+    /// clock.
+    pub fn get_moves(&self, color: Color) -> usize {
+        self.moves[index(color)]
+    }
+
+    /// The time a player has left, counting down while their clock runs.
+    pub fn get_remaining(&self, color: Color) -> Duration {
+        let remaining = self.remaining[index(color)];
+        match self.running {
+            Some((running, started)) if running == color => {
+                remaining.saturating_sub(self.used_time(color, self.time_source.now() - started))
+            }
+            _ => remaining,
+        }
+    }
+
+    /// The player who has run out of time, if any.
+    pub fn get_flagged(&self) -> Option<Color> {
+        self.flagged.or_else(|| {
+            self.get_running()
+                .filter(|color| self.get_remaining(*color).is_zero())
+        })
+    }
+
+    /// Ends the move of the player whose clock is running and starts the clock
+    /// of the other player.
+    ///
+    /// The time of the move is taken off the player's clock, then the bonus is
+    /// given and, if the player has finished a period, the time of the next
+    /// period is added.
+    ///
+    /// # Returns
+    ///
+    /// The player who has run out of time, `None` if both have time left. A
+    /// player who ran out of time before pressing the clock does not get a
+    /// bonus and both clocks stop.
+    pub fn press(&mut self) -> Option<Color> {
+        let Some((color, started)) = self.running.take() else {
+            return self.flagged;
+        };
+        let elapsed = self.time_source.now() - started;
+        self.charge(color, elapsed);
+        if self.flagged.is_some() {
+            return self.flagged;
+        }
+
+        let side = index(color);
+        let period = self.current_period(color);
+        self.remaining[side] += match period.bonus {
+            Bonus::Increment(increment) => increment,
+            Bonus::Bronstein(delay) => elapsed.min(delay),
+            Bonus::None | Bonus::SimpleDelay(_) => Duration::ZERO,
+        };
+
+        self.moves[side] += 1;
+        self.moves_in_period[side] += 1;
+        if period.moves == Some(self.moves_in_period[side]) {
+            self.moves_in_period[side] = 0;
+            self.periods[side] = (self.periods[side] + 1).min(self.time_control.periods.len() - 1);
+            self.remaining[side] += self.current_period(color).time;
+        }
+
+        self.running = Some((color.opposite(), self.time_source.now()));
+        None
+    }
+
+    fn current_period(&self, color: Color) -> TimePeriod {
+        self.time_control.periods[self.periods[index(color)]]
+    }
+
+    /// The part of `elapsed` that counts against the player's time.
+    fn used_time(&self, color: Color, elapsed: Duration) -> Duration {
+        match self.current_period(color).bonus {
+            Bonus::SimpleDelay(delay) => elapsed.saturating_sub(delay),
+            _ => elapsed,
+        }
+    }
+
+    fn charge(&mut self, color: Color, elapsed: Duration) {
+        let used = self.used_time(color, elapsed);
+        let remaining = &mut self.remaining[index(color)];
+        if used >= *remaining {
+            *remaining = Duration::ZERO;
+            self.flagged = Some(color);
+        } else {
+            *remaining -= used;
+        }
+    }
+}
+
+fn index(color: Color) -> usize {
+    match color {
+        Color::White => 0,
+        Color::Black => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color::{Black, White};
+
+    fn seconds(seconds: u64) -> Duration {
+        Duration::from_secs(seconds)
+    }
+
+    fn clock(time_control: &str) -> (GameClock<ManualTimeSource>, ManualTimeSource) {
+        let time = ManualTimeSource::new();
+        let mut clock = GameClock::new(time_control.parse().unwrap(), time.clone());
+        clock.start(White);
+        (clock, time)
+    }
+
+    fn play_move(clock: &mut GameClock<ManualTimeSource>, time: &ManualTimeSource, duration: u64) {
+        time.advance(seconds(duration));
+        assert_eq!(None, clock.press());
+    }
+
+    #[test]
+    fn sudden_death_counts_down_the_player_to_move() {
+        let (mut clock, time) = clock("300");
+        time.advance(seconds(20));
+        assert_eq!(seconds(280), clock.get_remaining(White));
+        assert_eq!(seconds(300), clock.get_remaining(Black));
+
+        assert_eq!(None, clock.press());
+        assert_eq!(Some(Black), clock.get_running());
+        time.advance(seconds(300));
+        assert_eq!(Some(Black), clock.get_flagged());
+        assert_eq!(Some(Black), clock.press());
+        assert_eq!(None, clock.get_running());
+        assert_eq!(Duration::ZERO, clock.get_remaining(Black));
+    }
+
+    #[test]
+    fn fischer_increment_is_added_after_every_move() {
+        let (mut clock, time) = clock("60+5");
+        play_move(&mut clock, &time, 2);
+        assert_eq!(seconds(63), clock.get_remaining(White));
+    }
+
+    #[test]
+    fn bronstein_delay_gives_back_the_time_used() {
+        let (mut clock, time) = clock("60b5");
+        play_move(&mut clock, &time, 2);
+        assert_eq!(seconds(60), clock.get_remaining(White));
+        play_move(&mut clock, &time, 8);
+        assert_eq!(seconds(57), clock.get_remaining(Black));
+    }
+
+    #[test]
+    fn simple_delay_waits_before_counting_down() {
+        let (mut clock, time) = clock("60d5");
+        time.advance(seconds(3));
+        assert_eq!(seconds(60), clock.get_remaining(White));
+        time.advance(seconds(4));
+        assert_eq!(seconds(58), clock.get_remaining(White));
+        assert_eq!(None, clock.press());
+        assert_eq!(seconds(58), clock.get_remaining(White));
+    }
+
+    #[test]
+    fn periods_add_time_after_their_moves() {
+        let (mut clock, time) = clock("2/100:3/50:30");
+        for _ in 0..2 {
+            play_move(&mut clock, &time, 10);
+            play_move(&mut clock, &time, 10);
+        }
+        assert_eq!(seconds(130), clock.get_remaining(White));
+        for _ in 0..3 {
+            play_move(&mut clock, &time, 10);
+            play_move(&mut clock, &time, 10);
+        }
+        assert_eq!(seconds(130), clock.get_remaining(Black));
+        assert_eq!(5, clock.get_moves(Black));
+    }
+
+    #[test]
+    fn the_last_period_repeats() {
+        let (mut clock, time) = clock("1/10");
+        for _ in 0..3 {
+            play_move(&mut clock, &time, 4);
+        }
+        assert_eq!(seconds(22), clock.get_remaining(White));
+    }
+
+    #[test]
+    fn flagged_players_get_no_bonus() {
+        let (mut clock, time) = clock("10+30");
+        time.advance(seconds(11));
+        assert_eq!(Some(White), clock.press());
+        assert_eq!(Duration::ZERO, clock.get_remaining(White));
+        clock.start(Black);
+        assert_eq!(None, clock.get_running());
+    }
+
+    #[test]
+    fn stopping_pauses_the_clock() {
+        let (mut clock, time) = clock("100");
+        time.advance(seconds(10));
+        clock.stop();
+        time.advance(seconds(50));
+        assert_eq!(seconds(90), clock.get_remaining(White));
+        clock.start(White);
+        time.advance(seconds(5));
+        assert_eq!(seconds(85), clock.get_remaining(White));
+    }
+
+    #[test]
+    fn invalid_time_controls_are_rejected() {
+        assert!("".parse::<TimeControl>().is_err());
+        assert!("40/".parse::<TimeControl>().is_err());
+        assert!("x/300".parse::<TimeControl>().is_err());
+        assert!("300+".parse::<TimeControl>().is_err());
+        assert_eq!(
+            TimeControl::simple_delay(seconds(300), Duration::from_millis(2500)),
+            "300d2.5".parse().unwrap()
+        );
+    }
+}
@@ -1,14 +1,52 @@
 use crate::chess_game_state_analyzer::GameState;
-use crate::codec::long_algebraic_notation::encode_move_as_long_algebraic_notation;
+use crate::codec::long_algebraic_notation::{
+    encode_move_as_long_algebraic_notation, get_piece_as_char,
+};
 use crate::piece::{ChessPiece, PieceType};
+use crate::variant::Variant;
 use crate::{ChessGame, ChessMoveType, Color};
-use game_board::{get_file_name, get_rank_name, get_square_name_from_row_and_col, Board};
-
-fn encode_move_as_algebraic_notation(
+use game_board::{get_file_name, get_rank_name, Board, Coord};
+
+/// Encodes a chess move in standard algebraic notation, such as `Nf3`,
+/// `exd5` or `Qh4e1+`.
+///
+/// Pieces are only told apart by their starting square when another piece of
+/// the same type could move to the same square, and checks and checkmates are
+/// marked with `+` and `#`.
+///
+/// # Arguments
+///
+/// * `chess_move_type` - The move to encode.
+/// * `starting_position` - The board before the move is made.
+///
+/// # Returns
+///
+/// The move in standard algebraic notation.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::codec::algebraic_notation::encode_move_as_algebraic_notation;
+/// use simple_chess::piece::{ChessPiece, PieceType};
+/// use simple_chess::Color::White;
+/// use simple_chess::{ChessGame, ChessMoveType};
+///
+/// let game = ChessGame::new();
+/// let knight_move = ChessMoveType::Move {
+///     original_position: (6, 0),
+///     new_position: (5, 2),
+///     piece: ChessPiece::new(PieceType::Knight, White),
+///     taken_piece: None,
+///     promotion: None,
+/// };
+///
+/// assert_eq!("Nf3", encode_move_as_algebraic_notation(&knight_move, game.get_board()));
+/// ```
+pub fn encode_move_as_algebraic_notation(
     chess_move_type: &ChessMoveType,
     starting_position: &Board<ChessPiece>,
 ) -> String {
-    if let ChessMoveType::Castle { .. } = chess_move_type {
+    if let ChessMoveType::Castle { .. } | ChessMoveType::Drop { .. } = chess_move_type {
         return encode_move_as_long_algebraic_notation(chess_move_type);
     }
 
@@ -34,64 +72,51 @@ fn encode_move_as_algebraic_notation(
         &legal_moves,
     );
 
-    let piece_name = match moving_piece_type {
-        PieceType::Pawn => "",
-        PieceType::Rook => "R",
-        PieceType::Knight => "N",
-        PieceType::Bishop => "B",
-        PieceType::Queen => "Q",
-        PieceType::King => "K",
-    };
-
-    let conflicts_with_same_file = conflicts
-        .iter()
-        .filter(|&c| match c {
-            ChessMoveType::Move {
-                original_position, ..
-            } => original_position.0 == moving_piece_original_location.0,
-            _ => false,
-        })
-        .count();
+    let piece_name = get_piece_as_char(moving_piece_type).unwrap_or_default();
 
-    let conflicts_with_same_rank = conflicts
-        .iter()
-        .filter(|&c| match c {
-            ChessMoveType::Move {
-                original_position, ..
-            } => original_position.1 == moving_piece_original_location.1,
-            _ => false,
-        })
-        .count();
+    let shares_file = |c: &&ChessMoveType| match c {
+        ChessMoveType::Move {
+            original_position, ..
+        } => original_position.0 == moving_piece_original_location.0,
+        _ => false,
+    };
+    let shares_rank = |c: &&ChessMoveType| match c {
+        ChessMoveType::Move {
+            original_position, ..
+        } => original_position.1 == moving_piece_original_location.1,
+        _ => false,
+    };
 
-    let conflict_rank_str = if conflicts_with_same_file != 0 {
-        get_rank_name(moving_piece_original_location.1)
-    } else {
+    // Pawns are always told apart by the file they capture from, other pieces
+    // by their file if that is enough, then their rank, then both.
+    let conflict_string = if moving_piece_type == PieceType::Pawn || conflicts.is_empty() {
         String::new()
-    };
-    let conflict_file_str = if conflicts_with_same_rank != 0 {
+    } else if !conflicts.iter().any(shares_file) {
         get_file_name(moving_piece_original_location.0)
+    } else if !conflicts.iter().any(shares_rank) {
+        get_rank_name(moving_piece_original_location.1)
     } else {
-        String::new()
+        Coord::from(*moving_piece_original_location).to_string()
     };
 
-    let conflict_string = format!("{}{}", conflict_file_str, conflict_rank_str);
-
     let take_string = match chess_move_type {
-        ChessMoveType::Move { taken_piece, .. } => {
-            if taken_piece.is_some() {
-                String::from("x")
-            } else {
-                String::new()
-            }
+        ChessMoveType::Move {
+            taken_piece: Some(_),
+            ..
+        } if moving_piece_type == PieceType::Pawn => {
+            format!("{}x", get_file_name(moving_piece_original_location.0))
         }
+        ChessMoveType::Move {
+            taken_piece: Some(_),
+            ..
+        } => String::from("x"),
         ChessMoveType::EnPassant {
             original_position, ..
         } => format!("{}x", get_file_name(original_position.0)),
         _ => String::new(),
     };
 
-    let new_position_name =
-        get_square_name_from_row_and_col(moving_piece_new_position.0, moving_piece_new_position.1);
+    let new_position_name = Coord::from(*moving_piece_new_position);
 
     let game_state_string = match game.get_game_state() {
         GameState::Check { .. } => "+",
@@ -101,14 +126,8 @@ fn encode_move_as_algebraic_notation(
 
     let en_passant_string = if is_move_en_passant { " e.p." } else { "" };
     let promotion_string = if let Some(promotion_piece) = promotion {
-        let promotion_piece_char = match promotion_piece.get_piece_type() {
-            PieceType::Pawn => "",
-            PieceType::Rook => "R",
-            PieceType::Knight => "N",
-            PieceType::Bishop => "B",
-            PieceType::Queen => "Q",
-            PieceType::King => "K",
-        };
+        let promotion_piece_char =
+            get_piece_as_char(promotion_piece.get_piece_type()).unwrap_or_default();
         format!("={}", promotion_piece_char)
     } else {
         String::new()
@@ -126,6 +145,67 @@ fn encode_move_as_algebraic_notation(
     )
 }
 
+/// Finds the legal move written in standard algebraic notation.
+///
+/// The notation is matched against the legal moves of the game, ignoring
+/// check and annotation marks, the `=` of promotions and `e.p.`, and
+/// accepting castling written with zeros as in `0-0`.
+///
+/// # Arguments
+///
+/// * `game` - The game the move is to be made in.
+/// * `notation` - The move in standard algebraic notation, such as `exd5`.
+///
+/// # Returns
+///
+/// - `Ok(ChessMoveType)` with the matching legal move.
+/// - `Err(AlgebraicNotationError)` if no legal move matches the notation.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::codec::algebraic_notation::decode_move_from_algebraic_notation;
+/// use simple_chess::ChessGame;
+///
+/// let game = ChessGame::new();
+/// let chess_move = decode_move_from_algebraic_notation(&game, "Nf3").unwrap();
+/// assert_eq!((6, 0), chess_move.get_original_position().unwrap().into());
+/// ```
+pub fn decode_move_from_algebraic_notation<V: Variant>(
+    game: &ChessGame<V>,
+    notation: &str,
+) -> Result<ChessMoveType, AlgebraicNotationError> {
+    let legal_moves = match game.clone().get_game_state() {
+        GameState::InProgress { legal_moves, .. } => legal_moves,
+        GameState::Check { legal_moves, .. } => legal_moves,
+        _ => Vec::new(),
+    };
+
+    let notation = normalize_algebraic_notation(notation);
+    legal_moves
+        .into_iter()
+        .find(|legal_move| {
+            normalize_algebraic_notation(&encode_move_as_algebraic_notation(
+                legal_move,
+                game.get_board(),
+            )) == notation
+        })
+        .ok_or_else(|| AlgebraicNotationError {
+            reason: format!("Unable to make move {}", notation),
+        })
+}
+
+fn normalize_algebraic_notation(notation: &str) -> String {
+    notation
+        .trim()
+        .replace("e.p.", "")
+        .replace("0-0-0", "O-O-O")
+        .replace("0-0", "O-O")
+        .chars()
+        .filter(|c| !matches!(c, '+' | '#' | '!' | '?' | '=' | ' '))
+        .collect()
+}
+
 fn get_move_data(
     chess_move_type: &ChessMoveType,
 ) -> (
@@ -219,6 +299,11 @@ fn find_conflicts<'a>(
         .collect::<Vec<&ChessMoveType>>()
 }
 
+#[derive(Debug)]
+pub struct AlgebraicNotationError {
+    pub reason: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,6 +335,7 @@ mod tests {
                 }
                 ChessMoveType::EnPassant { .. } => false,
                 ChessMoveType::Castle { .. } => false,
+                ChessMoveType::Drop { .. } => false,
             })
             .unwrap();
 
@@ -284,6 +370,7 @@ mod tests {
                 }
                 ChessMoveType::EnPassant { .. } => false,
                 ChessMoveType::Castle { .. } => false,
+                ChessMoveType::Drop { .. } => false,
             })
             .unwrap();
 
@@ -316,6 +403,7 @@ mod tests {
                 }
                 ChessMoveType::EnPassant { .. } => false,
                 ChessMoveType::Castle { .. } => false,
+                ChessMoveType::Drop { .. } => false,
             })
             .unwrap();
 
@@ -348,6 +436,7 @@ mod tests {
                 }
                 ChessMoveType::EnPassant { .. } => false,
                 ChessMoveType::Castle { .. } => false,
+                ChessMoveType::Drop { .. } => false,
             })
             .unwrap();
 
@@ -380,6 +469,7 @@ mod tests {
                 }
                 ChessMoveType::EnPassant { .. } => false,
                 ChessMoveType::Castle { .. } => false,
+                ChessMoveType::Drop { .. } => false,
             })
             .unwrap();
         game.make_move(m.clone());
@@ -438,6 +528,7 @@ mod tests {
                 }
                 ChessMoveType::EnPassant { .. } => false,
                 ChessMoveType::Castle { .. } => false,
+                ChessMoveType::Drop { .. } => false,
             })
             .unwrap();
 
@@ -470,10 +561,46 @@ mod tests {
                 }
                 ChessMoveType::EnPassant { .. } => false,
                 ChessMoveType::Castle { .. } => false,
+                ChessMoveType::Drop { .. } => false,
             })
             .unwrap();
 
         let algebraic_notation = encode_move_as_algebraic_notation(m, &game.get_board());
         assert_eq!("Rc1+", algebraic_notation);
     }
+
+    #[test]
+    fn pawn_captures_name_their_file() {
+        let game = build_game_from_string("4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1").unwrap();
+        let chess_move = decode_move_from_algebraic_notation(&game, "exd5").unwrap();
+        assert_eq!(
+            "exd5",
+            encode_move_as_algebraic_notation(&chess_move, game.get_board())
+        );
+    }
+
+    #[test]
+    fn pieces_on_different_files_and_ranks_are_told_apart_by_file() {
+        let game = build_game_from_string("4k3/8/8/8/8/2N5/8/4K1N1 w - - 0 1").unwrap();
+        let chess_move = decode_move_from_algebraic_notation(&game, "Nge2").unwrap();
+        assert_eq!((6, 0), chess_move.get_original_position().unwrap().into());
+        assert!(decode_move_from_algebraic_notation(&game, "Ne2").is_err());
+    }
+
+    #[test]
+    fn decoding_ignores_marks_and_accepts_zero_castling() {
+        let game = build_game_from_string("4k3/8/8/8/8/8/8/4K2R w K - 0 1").unwrap();
+        let castle = decode_move_from_algebraic_notation(&game, "0-0").unwrap();
+        assert!(matches!(castle, ChessMoveType::Castle { .. }));
+
+        let game = build_game_from_string("4k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let promotion = decode_move_from_algebraic_notation(&game, "a8Q+!").unwrap();
+        assert_eq!(
+            "a8=Q+",
+            encode_move_as_algebraic_notation(&promotion, game.get_board())
+        );
+
+        let error = decode_move_from_algebraic_notation(&game, "Kd3").unwrap_err();
+        assert_eq!("Unable to make move Kd3", error.reason);
+    }
 }
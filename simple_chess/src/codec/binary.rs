@@ -25,36 +25,49 @@ use game_board::Board;
 ///   |Bishop       |100                |
 ///   |King         |101                |
 ///   |Queen        |110                |
+///   |Other        |111                |
 ///
 ///   |Color |Bit  |
 ///   | ---- | --- |
 ///   |White |0    |
 ///   |Black |1    |
 ///
+/// - Pieces that are not in the table above, such as the Archbishop and Chancellor, are written as
+///   `Other`. One byte per such piece is appended after the board, in the order the pieces were
+///   found, holding `0` for an Archbishop, `1` for a Chancellor and the registration index plus
+///   two for a custom piece.
+///
 pub fn encode_board_as_binary(board: &Board<ChessPiece>) -> Vec<u8> {
     let mut encoded_board = Vec::new();
+    let mut other_pieces = Vec::new();
 
     let mut first = true;
     let mut current: u8 = 0;
-    for row in (0..board.get_height()).rev() {
-        for col in 0..board.get_width() {
-            let binary = if let Some(piece) = board.get_piece_at_space(col, row) {
-                piece_to_binary(piece)
-            } else {
-                0b0000
-            };
-
-            current |= binary;
-            if first {
-                current <<= 4;
-            } else {
-                encoded_board.push(current);
-                current = 0;
+    let squares = (0..board.get_height())
+        .rev()
+        .filter_map(|row| board.iter_rank(row).ok())
+        .flatten();
+    for square in squares {
+        let binary = if let Some(piece) = square.get_piece() {
+            if let Some(id) = other_piece_id(piece.get_piece_type()) {
+                other_pieces.push(id);
             }
-            first = !first;
+            piece_to_binary(piece)
+        } else {
+            0b0000
+        };
+
+        current |= binary;
+        if first {
+            current <<= 4;
+        } else {
+            encoded_board.push(current);
+            current = 0;
         }
+        first = !first;
     }
 
+    encoded_board.append(&mut other_pieces);
     encoded_board
 }
 
@@ -66,6 +79,7 @@ fn piece_to_binary(piece: &ChessPiece) -> u8 {
         PieceType::Bishop => 0b1000,
         PieceType::King => 0b1010,
         PieceType::Queen => 0b1100,
+        PieceType::Archbishop | PieceType::Chancellor | PieceType::Custom(_) => 0b1110,
     };
 
     if piece.get_color() == Black {
@@ -75,14 +89,24 @@ fn piece_to_binary(piece: &ChessPiece) -> u8 {
     binary
 }
 
+fn other_piece_id(piece_type: PieceType) -> Option<u8> {
+    match piece_type {
+        PieceType::Archbishop => Some(0),
+        PieceType::Chancellor => Some(1),
+        // register_custom_piece keeps the index below 254, so this fits.
+        PieceType::Custom(id) => Some(id.get_index() as u8 + 2),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     mod encoding_tests {
         use super::super::*;
-        use crate::piece::PieceType::King;
+        use crate::piece::PieceType::{Archbishop, Chancellor, King};
         use crate::ChessGame;
-        use crate::Color::Black;
+        use crate::Color::{Black, White};
 
         #[test]
         fn encode_starting_position() {
@@ -119,5 +143,16 @@ mod tests {
             assert_eq!(1, encoded.len());
             assert_eq!(0b00001011, encoded[0]);
         }
+
+        #[test]
+        fn encode_fairy_pieces_after_the_board() {
+            let mut board = Board::build(2, 2).unwrap();
+            board.place_piece(ChessPiece::new(Chancellor, White), 0, 1);
+            board.place_piece(ChessPiece::new(Archbishop, Black), 1, 0);
+
+            let encoded = encode_board_as_binary(&board);
+
+            assert_eq!(vec![0b11100000, 0b00001111, 1, 0], encoded);
+        }
     }
 }
@@ -1,11 +1,15 @@
 use crate::chess_game::ChessGame;
 use crate::chess_game_builder::ChessGameBuilder;
-use crate::piece::ChessPiece;
-use crate::piece::PieceType::{Bishop, King, Knight, Pawn, Queen, Rook};
+use crate::piece::PieceType::{
+    Archbishop, Bishop, Chancellor, Custom, King, Knight, Pawn, Queen, Rook,
+};
+use crate::piece::{find_custom_piece, ChessPiece, PieceType};
+use crate::variant::{Standard, Variant};
 use crate::ChessMoveType;
-use crate::ChessMoveType::EnPassant;
+use crate::ChessMoveType::Move;
+use crate::Color;
 use crate::Color::{Black, White};
-use game_board::Board;
+use game_board::{Board, Coord};
 use std::error::Error;
 use std::fmt::{Debug, Display, Formatter};
 
@@ -21,13 +25,21 @@ const WHITE_QUEEN: char = 'Q';
 const BLACK_QUEEN: char = 'q';
 const WHITE_KING: char = 'K';
 const BLACK_KING: char = 'k';
+const WHITE_ARCHBISHOP: char = 'A';
+const BLACK_ARCHBISHOP: char = 'a';
+const WHITE_CHANCELLOR: char = 'C';
+const BLACK_CHANCELLOR: char = 'c';
+const PROMOTED: char = '~';
+const POCKET_ORDER: [PieceType; 7] = [Queen, Chancellor, Archbishop, Rook, Bishop, Knight, Pawn];
 
 /// Encodes the current state of the simple_chess game as a string in FEN (Forsyth-Edwards Notation) format.
 ///
 /// The resulting string consists of the following parts:
 ///
 /// 1. The board layout, represented by rows separated by slashes, where each piece is represented
-///    by a character and empty squares are represented by numbers.
+///    by a character and empty squares are represented by numbers. When a player holds pieces in
+///    their pocket, the pockets follow the board in brackets, such as `[QNp]`, and promoted pieces
+///    are marked with a '~', as in Crazyhouse.
 /// 2. The current turn, indicated by 'w' for White or 'b' for Black.
 /// 3. Castling rights, represented by 'K', 'Q', 'k', and 'q' for White king-side, White queen-side,
 ///    Black king-side, and Black queen-side castling respectively. If no castling rights are available,
@@ -45,16 +57,54 @@ const BLACK_KING: char = 'k';
 /// # Returns
 ///
 /// A `String` representing the current state of the simple_chess game.
-pub fn encode_game_as_string(game: &ChessGame) -> String {
-    format!(
-        "{} {} {} {} {} {}",
+///
+/// Castling rights are written in X-FEN: when a side castles with a rook that is
+/// not the outermost rook on its side of the king, as can happen in Chess960,
+/// the file of the rook is used instead of 'K' or 'Q'.
+pub fn encode_game_as_string<V: Variant>(game: &ChessGame<V>) -> String {
+    encode_game(game, false)
+}
+
+/// Encodes the current state of the simple_chess game as a string in Shredder-FEN format.
+///
+/// Shredder-FEN is identical to FEN except that castling rights are always written as the
+/// files of the castling rooks, uppercase for White and lowercase for Black. It is commonly
+/// used for Chess960 positions.
+///
+/// # Arguments
+///
+/// * `game` - A reference to the `ChessGame` instance representing the current state of the game.
+///
+/// # Returns
+///
+/// A `String` representing the current state of the simple_chess game.
+///
+/// # Example
+/// ```
+/// use simple_chess::ChessGame;
+/// use simple_chess::codec::forsyth_edwards_notation::encode_game_as_shredder_string;
+///
+/// let game = ChessGame::new();
+/// assert_eq!(
+///     "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1",
+///     encode_game_as_shredder_string(&game)
+/// );
+/// ```
+pub fn encode_game_as_shredder_string<V: Variant>(game: &ChessGame<V>) -> String {
+    encode_game(game, true)
+}
+
+fn encode_game<V: Variant>(game: &ChessGame<V>, shredder: bool) -> String {
+    let mut fields = vec![
         get_board_as_fen_string(game),
-        get_current_turn_char(game),
-        get_castling_rights(game),
+        get_current_turn_char(game).to_string(),
+        get_castling_rights(game, shredder),
         get_en_passant(game),
-        game.get_50_move_rule_counter(),
-        game.get_turn_number()
-    )
+        game.get_50_move_rule_counter().to_string(),
+        game.get_turn_number().to_string(),
+    ];
+    game.get_variant().encode_fen_fields(&mut fields);
+    fields.join(" ")
 }
 
 /// Builds a `ChessGame` from a string in Forsyth-Edwards Notation (FEN) format.
@@ -62,6 +112,9 @@ pub fn encode_game_as_string(game: &ChessGame) -> String {
 /// This function parses the FEN string and constructs the game state, including the board layout,
 /// current turn, castling rights, en passant target square, half-move counter, and full move number.
 ///
+/// Castling rights may be given as 'KQkq', as Shredder-FEN rook files ('HAha') or as a mix of
+/// both (X-FEN), so Chess960 positions can be loaded as well.
+///
 /// # Arguments
 ///
 /// * `fen_string` - A string slice representing the state of the simple_chess game in FEN format.
@@ -80,6 +133,41 @@ pub fn encode_game_as_string(game: &ChessGame) -> String {
 /// assert!(game.is_ok());
 /// ```
 pub fn build_game_from_string(fen_string: &str) -> Result<ChessGame, ForsythEdwardsNotationError> {
+    build_variant_game_from_string(fen_string, Standard)
+}
+
+/// Builds a `ChessGame` of a chess variant from a string in Forsyth-Edwards Notation (FEN) format.
+///
+/// The variant reads any fields it adds to the standard FEN fields, such as the check counters
+/// of Three-check, before the rest of the string is parsed like in `build_game_from_string`.
+///
+/// # Arguments
+///
+/// * `fen_string` - A string slice representing the state of the game in FEN format.
+/// * `variant` - The rules of the variant the game is played with.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` if the `ChessGame` was built successfully, or an `Err` containing
+/// a `ForsythEdwardsNotationError` if the FEN string is invalid or cannot be parsed.
+///
+/// # Example
+/// ```
+/// use simple_chess::codec::forsyth_edwards_notation::build_variant_game_from_string;
+/// use simple_chess::variant::ThreeCheck;
+/// use simple_chess::Color::Black;
+///
+/// let game = build_variant_game_from_string(
+///     "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 3+1 0 1",
+///     ThreeCheck::new(),
+/// )
+/// .unwrap();
+/// assert_eq!(2, game.get_variant().get_checks_given(Black));
+/// ```
+pub fn build_variant_game_from_string<V: Variant>(
+    fen_string: &str,
+    mut variant: V,
+) -> Result<ChessGame<V>, ForsythEdwardsNotationError> {
     let fen_string = fen_string.trim();
     if fen_string.is_empty() {
         return Err(ForsythEdwardsNotationError::new(
@@ -96,7 +184,12 @@ pub fn build_game_from_string(fen_string: &str) -> Result<ChessGame, ForsythEdwa
         parse_turn_number_from_string,
     ];
 
-    let mut parts = fen_string.split(" ");
+    let mut fields: Vec<&str> = fen_string.split(" ").collect();
+    variant
+        .decode_fen_fields(&mut fields)
+        .map_err(ForsythEdwardsNotationError::new)?;
+
+    let mut parts = fields.into_iter();
     let mut builder = ChessGameBuilder::new();
 
     for step in steps {
@@ -112,7 +205,7 @@ pub fn build_game_from_string(fen_string: &str) -> Result<ChessGame, ForsythEdwa
         }
     }
 
-    match builder.build() {
+    match builder.build_with_variant(variant) {
         Ok(g) => Ok(g),
         Err(e) => Err(ForsythEdwardsNotationError::new(e.to_string())),
     }
@@ -122,34 +215,48 @@ fn parse_board_from_string(
     builder: ChessGameBuilder,
     board_as_fen_string: &str,
 ) -> Result<ChessGameBuilder, ForsythEdwardsNotationError> {
-    let mut board = Board::build(8, 8).unwrap();
-
-    let mut files = board_as_fen_string.split("/");
+    let (board_as_fen_string, pockets) = split_pockets_from_board(board_as_fen_string);
+    let builder = match pockets {
+        Some(pockets) => parse_pockets_from_string(builder, pockets)?,
+        None => builder,
+    };
 
-    let mut col = 0;
-    for row in (0..8).rev() {
-        let file = files.next().unwrap();
+    let files = board_as_fen_string.split("/").collect::<Vec<&str>>();
+    let width = get_file_width(files[0])?;
+    let height = files.len();
+    let mut board = match Board::build(width, height) {
+        Ok(board) => board,
+        Err(e) => {
+            return Err(ForsythEdwardsNotationError::new(format!(
+                "Unable to build a {width}x{height} board from piece placement data: {e}"
+            )))
+        }
+    };
+    let mut promoted_pieces = Vec::new();
 
+    for (file, row) in files.into_iter().zip((0..height).rev()) {
+        let mut col = 0;
+        let mut empty_spaces = 0;
+        let mut previous = None;
         for c in file.chars() {
             match c {
-                '1'..='8' => {
-                    col += c.to_digit(10).unwrap() as usize;
+                '0'..='9' => {
+                    empty_spaces = empty_spaces * 10 + c.to_digit(10).unwrap() as usize;
+                }
+                PROMOTED if previous.is_some_and(|p: char| p.is_ascii_alphabetic()) => {
+                    promoted_pieces.push((col - 1, row));
                 }
                 _ => {
-                    let piece = match c {
-                        WHITE_PAWN => ChessPiece::new(Pawn, White),
-                        BLACK_PAWN => ChessPiece::new(Pawn, Black),
-                        WHITE_ROOK => ChessPiece::new(Rook, White),
-                        BLACK_ROOK => ChessPiece::new(Rook, Black),
-                        WHITE_KNIGHT => ChessPiece::new(Knight, White),
-                        BLACK_KNIGHT => ChessPiece::new(Knight, Black),
-                        WHITE_BISHOP => ChessPiece::new(Bishop, White),
-                        BLACK_BISHOP => ChessPiece::new(Bishop, Black),
-                        WHITE_QUEEN => ChessPiece::new(Queen, White),
-                        BLACK_QUEEN => ChessPiece::new(Queen, Black),
-                        WHITE_KING => ChessPiece::new(King, White),
-                        BLACK_KING => ChessPiece::new(King, Black),
-                        _ => {
+                    col += empty_spaces;
+                    empty_spaces = 0;
+                    let piece = match decode_piece_from_fen_char(c) {
+                        Some(piece) if col < width => piece,
+                        Some(_) => {
+                            return Err(ForsythEdwardsNotationError::new(format!(
+                            "File '{file}' was longer than {width} spaces in piece placement data"
+                        )))
+                        }
+                        None => {
                             return Err(ForsythEdwardsNotationError::new(format!(
                                 "Unexpected char '{c}' in file '{file}' of piece placement data"
                             )))
@@ -159,18 +266,116 @@ fn parse_board_from_string(
                     col += 1;
                 }
             }
+            previous = Some(c);
         }
+        col += empty_spaces;
 
-        if col != 8 {
+        if col != width {
             return Err(ForsythEdwardsNotationError::new(format!(
-                "File '{file}' was not 8 spaces long in piece placement data"
+                "File '{file}' was not {width} spaces long in piece placement data"
             )));
         }
+    }
+
+    Ok(builder
+        .set_board(board)
+        .set_promoted_pieces(promoted_pieces))
+}
 
-        col = 0;
+/// Counts the spaces of a file of piece placement data, where runs of empty
+/// spaces may take more than one digit, as in `r10r`.
+fn get_file_width(file: &str) -> Result<usize, ForsythEdwardsNotationError> {
+    let mut width = 0;
+    let mut empty_spaces = 0;
+    for c in file.chars() {
+        match c {
+            '0'..='9' => empty_spaces = empty_spaces * 10 + c.to_digit(10).unwrap() as usize,
+            PROMOTED => {}
+            _ => {
+                width += empty_spaces + 1;
+                empty_spaces = 0;
+            }
+        }
     }
+    width += empty_spaces;
 
-    Ok(builder.set_board(board))
+    match width {
+        0 => Err(ForsythEdwardsNotationError::new(format!(
+            "File '{file}' of piece placement data has no spaces"
+        ))),
+        _ => Ok(width),
+    }
+}
+
+/// Splits the pockets from the piece placement data, written either in brackets,
+/// `RNBQKBNR[Qn]`, or as a ninth rank, `RNBQKBNR/Qn`.
+fn split_pockets_from_board(board_as_fen_string: &str) -> (&str, Option<&str>) {
+    if let Some(board_and_pockets) = board_as_fen_string.strip_suffix(']') {
+        if let Some((board, pockets)) = board_and_pockets.split_once('[') {
+            return (board, Some(pockets));
+        }
+    }
+    if board_as_fen_string.matches('/').count() == 8 {
+        if let Some((board, pockets)) = board_as_fen_string.rsplit_once('/') {
+            return (board, Some(pockets));
+        }
+    }
+    (board_as_fen_string, None)
+}
+
+fn parse_pockets_from_string(
+    builder: ChessGameBuilder,
+    pockets_string: &str,
+) -> Result<ChessGameBuilder, ForsythEdwardsNotationError> {
+    let mut white_pocket = Vec::new();
+    let mut black_pocket = Vec::new();
+
+    for c in pockets_string.chars() {
+        match decode_piece_from_fen_char(c) {
+            Some(piece) if piece.get_piece_type() != King => match piece.get_color() {
+                White => white_pocket.push(piece.get_piece_type()),
+                Black => black_pocket.push(piece.get_piece_type()),
+            },
+            _ => {
+                return Err(ForsythEdwardsNotationError::new(format!(
+                    "Unexpected char '{c}' in pockets '{pockets_string}'"
+                )))
+            }
+        }
+    }
+
+    Ok(builder
+        .set_pocket(White, white_pocket)
+        .set_pocket(Black, black_pocket))
+}
+
+fn decode_piece_from_fen_char(c: char) -> Option<ChessPiece> {
+    let piece = match c {
+        WHITE_PAWN => ChessPiece::new(Pawn, White),
+        BLACK_PAWN => ChessPiece::new(Pawn, Black),
+        WHITE_ROOK => ChessPiece::new(Rook, White),
+        BLACK_ROOK => ChessPiece::new(Rook, Black),
+        WHITE_KNIGHT => ChessPiece::new(Knight, White),
+        BLACK_KNIGHT => ChessPiece::new(Knight, Black),
+        WHITE_BISHOP => ChessPiece::new(Bishop, White),
+        BLACK_BISHOP => ChessPiece::new(Bishop, Black),
+        WHITE_QUEEN => ChessPiece::new(Queen, White),
+        BLACK_QUEEN => ChessPiece::new(Queen, Black),
+        WHITE_KING => ChessPiece::new(King, White),
+        BLACK_KING => ChessPiece::new(King, Black),
+        WHITE_ARCHBISHOP => ChessPiece::new(Archbishop, White),
+        BLACK_ARCHBISHOP => ChessPiece::new(Archbishop, Black),
+        WHITE_CHANCELLOR => ChessPiece::new(Chancellor, White),
+        BLACK_CHANCELLOR => ChessPiece::new(Chancellor, Black),
+        _ => {
+            let piece_type = find_custom_piece(c.to_ascii_uppercase())?;
+            match c.is_ascii_uppercase() {
+                true => ChessPiece::new(piece_type, White),
+                false => ChessPiece::new(piece_type, Black),
+            }
+        }
+    };
+    Some(piece)
 }
 
 fn parse_current_turn_from_string(
@@ -189,23 +394,94 @@ fn parse_castling_rights_from_string(
     castling_rights_string: &str,
 ) -> Result<ChessGameBuilder, ForsythEdwardsNotationError> {
     let (mut ws, mut wl, mut bs, mut bl) = (false, false, false, false);
+    let width = builder.get_board().map_or(8, |board| board.get_width());
+    let (mut ws_col, mut wl_col, mut bs_col, mut bl_col) = (width - 1, 0, width - 1, 0);
+
     if castling_rights_string != "-" {
         for c in castling_rights_string.chars() {
-            match c {
-                'K' => ws = true,
-                'Q' => wl = true,
-                'k' => bs = true,
-                'q' => bl = true,
-                _ => {
-                    return Err(ForsythEdwardsNotationError::new(format!(
-                        "Unexpected char '{c}' in castling rights string"
-                    )))
+            let color = if c.is_ascii_uppercase() { White } else { Black };
+            let king_col = builder
+                .get_board()
+                .and_then(|board| find_home_row_piece(board, color, King, 0..width));
+            let (short, col) = match c {
+                'K' | 'k' => (true, find_outermost_castling_rook(&builder, color, true)),
+                'Q' | 'q' => (false, find_outermost_castling_rook(&builder, color, false)),
+                _ => match file_letter_to_column(c) {
+                    Some(col) if col < width => (col > king_col.unwrap_or(width / 2), Some(col)),
+                    _ => {
+                        return Err(ForsythEdwardsNotationError::new(format!(
+                            "Unexpected char '{c}' in castling rights string"
+                        )))
+                    }
+                },
+            };
+
+            match (color, short) {
+                (White, true) => {
+                    ws = true;
+                    ws_col = col.unwrap_or(ws_col);
+                }
+                (White, false) => {
+                    wl = true;
+                    wl_col = col.unwrap_or(wl_col);
+                }
+                (Black, true) => {
+                    bs = true;
+                    bs_col = col.unwrap_or(bs_col);
+                }
+                (Black, false) => {
+                    bl = true;
+                    bl_col = col.unwrap_or(bl_col);
                 }
             }
         }
     }
 
-    Ok(builder.set_castle_rights(ws, wl, bs, bl))
+    let builder = builder.set_castle_rights(ws, wl, bs, bl);
+    if builder.get_board().is_some() {
+        Ok(builder.set_castle_rook_columns(wl_col, ws_col, bl_col, bs_col))
+    } else {
+        Ok(builder)
+    }
+}
+
+fn find_outermost_castling_rook(
+    builder: &ChessGameBuilder,
+    color: Color,
+    short: bool,
+) -> Option<usize> {
+    let board = builder.get_board()?;
+    let width = board.get_width();
+    let king_col = find_home_row_piece(board, color, King, 0..width)?;
+    if short {
+        find_home_row_piece(board, color, Rook, (king_col + 1..width).rev())
+    } else {
+        find_home_row_piece(board, color, Rook, 0..king_col)
+    }
+}
+
+fn find_home_row_piece(
+    board: &Board<ChessPiece>,
+    color: Color,
+    piece_type: PieceType,
+    mut columns: impl Iterator<Item = usize>,
+) -> Option<usize> {
+    let row = match color {
+        White => 0,
+        Black => board.get_height() - 1,
+    };
+    columns.find(|col| {
+        board.get_piece_at_space(*col, row) == Some(&ChessPiece::new(piece_type, color))
+    })
+}
+
+fn file_letter_to_column(c: char) -> Option<usize> {
+    let c = c.to_ascii_lowercase();
+    if c.is_ascii_lowercase() {
+        Some(c as usize - 'a' as usize)
+    } else {
+        None
+    }
 }
 
 fn parse_en_passant_option_from_string(
@@ -215,26 +491,37 @@ fn parse_en_passant_option_from_string(
     if en_passant_option_string == "-" {
         Ok(builder)
     } else {
-        match game_board::get_column_and_row_from_square_name(en_passant_option_string) {
-            Ok((col, row)) => {
-                let pawn_color = if row < 3 { White } else { Black };
-                let (original_row, new_row) = match pawn_color {
-                    White => (row - 1, row + 1),
-                    Black => (row + 1, row - 1),
-                };
-
-                let m = ChessMoveType::Move {
-                    original_position: (col, original_row),
-                    new_position: (col, new_row),
-                    piece: ChessPiece::new(Pawn, pawn_color),
-                    taken_piece: None,
-                    promotion: None,
-                };
-                let moves = vec![m];
-                Ok(builder.set_moves(moves))
-            }
-            Err(e) => Err(ForsythEdwardsNotationError::new(format!("unable to parse en passant square '{en_passant_option_string}' into a board position: {}", e)))
-        }
+        let en_passant_square = en_passant_option_string.parse::<Coord>().map_err(|e| {
+            ForsythEdwardsNotationError::new(format!(
+                "unable to parse en passant square '{en_passant_option_string}' into a board position: {e}"
+            ))
+        })?;
+
+        let height = builder.get_board().map_or(8, |board| board.get_height());
+        let pawn_color = if en_passant_square.get_row() < height / 2 {
+            White
+        } else {
+            Black
+        };
+        let (original_position, new_position) = match pawn_color {
+            White => (en_passant_square + (0, -1), en_passant_square + (0, 1)),
+            Black => (en_passant_square + (0, 1), en_passant_square + (0, -1)),
+        };
+        let (Some(original_position), Some(new_position)) = (original_position, new_position)
+        else {
+            return Err(ForsythEdwardsNotationError::new(format!(
+                "en passant square '{en_passant_option_string}' can not be passed by a pawn"
+            )));
+        };
+
+        let m = ChessMoveType::Move {
+            original_position: original_position.into(),
+            new_position: new_position.into(),
+            piece: ChessPiece::new(Pawn, pawn_color),
+            taken_piece: None,
+            promotion: None,
+        };
+        Ok(builder.set_moves(vec![m]))
     }
 }
 
@@ -262,18 +549,41 @@ fn parse_turn_number_from_string(
     }
 }
 
-fn get_board_as_fen_string(game: &ChessGame) -> String {
+fn get_board_as_fen_string<V: Variant>(game: &ChessGame<V>) -> String {
     let board = game.get_board();
 
-    let board_as_fen_string: String = (0..board.get_height())
+    let mut board_as_fen_string: String = (0..board.get_height())
         .rev()
-        .map(|rank| encode_row(board, rank))
+        .map(|rank| encode_row(game, rank))
         .collect::<Vec<String>>()
         .join("/");
+
+    if !game.get_pocket(White).is_empty() || !game.get_pocket(Black).is_empty() {
+        board_as_fen_string.push_str(&format!("[{}]", encode_pockets(game)));
+    }
     board_as_fen_string
 }
 
-fn encode_row(board: &Board<ChessPiece>, row: usize) -> String {
+fn encode_pockets<V: Variant>(game: &ChessGame<V>) -> String {
+    let mut result = String::new();
+    for color in [White, Black] {
+        let pocket = game.get_pocket(color);
+        for piece_type in POCKET_ORDER {
+            for _ in pocket
+                .iter()
+                .filter(|pocket_piece| **pocket_piece == piece_type)
+            {
+                result.push(encode_piece_as_fen_char(&ChessPiece::new(
+                    piece_type, color,
+                )));
+            }
+        }
+    }
+    result
+}
+
+fn encode_row<V: Variant>(game: &ChessGame<V>, row: usize) -> String {
+    let board = game.get_board();
     let mut result = String::new();
 
     let mut empty_space_counter: usize = 0;
@@ -285,6 +595,9 @@ fn encode_row(board: &Board<ChessPiece>, row: usize) -> String {
                 empty_space_counter = 0;
             }
             result.push(encode_piece_as_fen_char(piece));
+            if game.is_promoted((col, row)) {
+                result.push(PROMOTED);
+            }
         } else {
             empty_space_counter += 1;
         }
@@ -296,7 +609,7 @@ fn encode_row(board: &Board<ChessPiece>, row: usize) -> String {
     result
 }
 
-fn encode_piece_as_fen_char(piece: &ChessPiece) -> char {
+pub(crate) fn encode_piece_as_fen_char(piece: &ChessPiece) -> char {
     match (piece.get_color(), piece.get_piece_type()) {
         (White, Pawn) => WHITE_PAWN,
         (Black, Pawn) => BLACK_PAWN,
@@ -310,32 +623,37 @@ fn encode_piece_as_fen_char(piece: &ChessPiece) -> char {
         (Black, Queen) => BLACK_QUEEN,
         (White, King) => WHITE_KING,
         (Black, King) => BLACK_KING,
+        (White, Archbishop) => WHITE_ARCHBISHOP,
+        (Black, Archbishop) => BLACK_ARCHBISHOP,
+        (White, Chancellor) => WHITE_CHANCELLOR,
+        (Black, Chancellor) => BLACK_CHANCELLOR,
+        (White, Custom(id)) => id.get_movement().fen_char(),
+        (Black, Custom(id)) => id.get_movement().fen_char().to_ascii_lowercase(),
     }
 }
 
-fn get_current_turn_char(game: &ChessGame) -> char {
+fn get_current_turn_char<V: Variant>(game: &ChessGame<V>) -> char {
     match game.get_current_players_turn() {
         White => 'w',
         Black => 'b',
     }
 }
 
-fn get_castling_rights(game: &ChessGame) -> String {
+fn get_castling_rights<V: Variant>(game: &ChessGame<V>, shredder: bool) -> String {
     let mut result = String::new();
 
     let (wq, wk, bq, bk) = game.get_castling_rights();
-
-    if wk {
-        result.push('K');
-    }
-    if wq {
-        result.push('Q');
-    }
-    if bk {
-        result.push('k');
-    }
-    if bq {
-        result.push('q');
+    let (wq_col, wk_col, bq_col, bk_col) = game.get_castling_rook_columns();
+
+    for (has_right, col, color, short) in [
+        (wk, wk_col, White, true),
+        (wq, wq_col, White, false),
+        (bk, bk_col, Black, true),
+        (bq, bq_col, Black, false),
+    ] {
+        if has_right {
+            result.push(encode_castling_right(game, col, color, short, shredder));
+        }
     }
     if result.is_empty() {
         result.push('-')
@@ -344,15 +662,43 @@ fn get_castling_rights(game: &ChessGame) -> String {
     result
 }
 
-fn get_en_passant(game: &ChessGame) -> String {
-    if let Some(EnPassant {
-        new_position: (col, row),
-        ..
-    }) = game.get_last_move()
-    {
-        game_board::get_square_name_from_row_and_col(*col, *row)
+fn encode_castling_right<V: Variant>(
+    game: &ChessGame<V>,
+    rook_col: usize,
+    color: Color,
+    short: bool,
+    shredder: bool,
+) -> char {
+    let board = game.get_board();
+    let outer_columns = if short {
+        rook_col + 1..board.get_width()
     } else {
-        String::from("-")
+        0..rook_col
+    };
+    let is_outermost_rook = find_home_row_piece(board, color, Rook, outer_columns).is_none();
+
+    let c = match (shredder || !is_outermost_rook, short) {
+        (true, _) => (b'a' + rook_col as u8) as char,
+        (false, true) => 'k',
+        (false, false) => 'q',
+    };
+    match color {
+        White => c.to_ascii_uppercase(),
+        Black => c,
+    }
+}
+
+fn get_en_passant<V: Variant>(game: &ChessGame<V>) -> String {
+    match game.get_last_move() {
+        Some(Move {
+            original_position: (col, from_row),
+            new_position: (_, to_row),
+            piece,
+            ..
+        }) if piece.get_piece_type() == Pawn && from_row.abs_diff(*to_row) == 2 => {
+            Coord::from((*col, (from_row + to_row) / 2)).to_string()
+        }
+        _ => String::from("-"),
     }
 }
 
@@ -397,6 +743,106 @@ mod tests {
                 fen_string
             );
         }
+
+        #[test]
+        fn en_passant_square_round_trip() {
+            let fen = "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2";
+            let game = build_game_from_string(fen).unwrap();
+            assert_eq!(fen, encode_game_as_string(&game));
+        }
+
+        #[test]
+        fn chess_960_castling_rights_round_trip() {
+            let fen = "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9";
+            let game = build_game_from_string(fen).unwrap();
+
+            assert_eq!((5, 7, 5, 7), game.get_castling_rook_columns());
+            assert_eq!(fen, encode_game_as_shredder_string(&game));
+            assert_eq!(
+                "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w KQkq - 2 9",
+                encode_game_as_string(&game)
+            );
+        }
+
+        #[test]
+        fn x_fen_uses_files_for_inner_castling_rooks() {
+            let fen = "rr2k2r/8/8/8/8/8/8/1R2K1RR w GBhb - 0 1";
+            let game = build_game_from_string(fen).unwrap();
+
+            assert_eq!((1, 6, 1, 7), game.get_castling_rook_columns());
+            assert_eq!(
+                "rr2k2r/8/8/8/8/8/8/1R2K1RR w GQkb - 0 1",
+                encode_game_as_string(&game)
+            );
+            assert_eq!(
+                game.get_castling_rook_columns(),
+                build_game_from_string(&encode_game_as_string(&game))
+                    .unwrap()
+                    .get_castling_rook_columns()
+            );
+        }
+
+        #[test]
+        fn pockets_and_promoted_pieces_round_trip() {
+            let fen = "r3k3/1Q~6/8/8/8/8/8/4K3[NQpb] w - - 0 1";
+            let game = build_game_from_string(fen).unwrap();
+
+            assert_eq!(&[Knight, Queen], game.get_pocket(White));
+            assert_eq!(&[Pawn, Bishop], game.get_pocket(Black));
+            assert!(game.is_promoted((1, 6)));
+            assert_eq!(
+                "r3k3/1Q~6/8/8/8/8/8/4K3[QNbp] w - - 0 1",
+                encode_game_as_string(&game)
+            );
+        }
+
+        #[test]
+        fn pockets_can_be_given_as_a_ninth_rank() {
+            let game = build_game_from_string("4k3/8/8/8/8/8/8/4K3/Rn w - - 0 1").unwrap();
+
+            assert_eq!(&[Rook], game.get_pocket(White));
+            assert_eq!(&[Knight], game.get_pocket(Black));
+        }
+
+        #[test]
+        fn board_size_is_read_from_piece_placement_data() {
+            let fen = "r10r/12/12/12/12/12/12/12/12/R4K6 w - - 0 1";
+            let game = build_game_from_string(fen).unwrap();
+            let board = game.get_board();
+
+            assert_eq!((12, 10), (board.get_width(), board.get_height()));
+            assert_eq!(
+                Some(&ChessPiece::new(Rook, Black)),
+                board.get_piece_at_space(11, 9)
+            );
+            assert_eq!(fen, encode_game_as_string(&game));
+        }
+
+        #[test]
+        fn fairy_pieces_round_trip() {
+            let fen = "rnabqkbcnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNABQKBCNR w KQkq - 0 1";
+            let game = build_game_from_string(fen).unwrap();
+
+            assert_eq!(
+                Some(&ChessPiece::new(Archbishop, White)),
+                game.get_board().get_piece_at_space(2, 0)
+            );
+            assert_eq!(
+                Some(&ChessPiece::new(Chancellor, Black)),
+                game.get_board().get_piece_at_space(7, 7)
+            );
+            assert_eq!(fen, encode_game_as_string(&game));
+        }
+
+        #[test]
+        fn files_of_different_lengths_are_rejected() {
+            assert!(build_game_from_string("r9/10/10/10/10/10/10/R8 w - - 0 1").is_err());
+        }
+
+        #[test]
+        fn kings_can_not_be_in_a_pocket() {
+            assert!(build_game_from_string("4k3/8/8/8/8/8/8/4K3[K] w - - 0 1").is_err());
+        }
     }
 
     mod encoding_tests {
@@ -645,7 +1091,19 @@ mod tests {
             match parse_en_passant_option_from_string(game_builder, "_") {
                 Ok(_) => panic!("expected error"),
                 Err(e) => assert_eq!(
-                    "unable to parse en passant square '_' into a board position: Invalid input",
+                    "unable to parse en passant square '_' into a board position: '_' is not a valid square name",
+                    e.reason
+                ),
+            }
+        }
+
+        #[test]
+        fn parse_fen_en_passant_square_on_the_edge_of_the_board() {
+            let game_builder = ChessGameBuilder::new();
+            match parse_en_passant_option_from_string(game_builder, "e1") {
+                Ok(_) => panic!("expected error"),
+                Err(e) => assert_eq!(
+                    "en passant square 'e1' can not be passed by a pawn",
                     e.reason
                 ),
             }
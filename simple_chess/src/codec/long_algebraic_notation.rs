@@ -1,7 +1,8 @@
 use crate::chess_game_state_analyzer::GameState;
 use crate::piece::{ChessPiece, PieceType};
+use crate::variant::{Standard, Variant};
 use crate::{ChessGame, ChessMoveType};
-use game_board::get_square_name_from_row_and_col;
+use game_board::Coord;
 
 /// Encodes a chess move into long algebraic notation based on its type.
 ///
@@ -55,8 +56,13 @@ pub fn encode_move_as_long_algebraic_notation(chess_move_type: &ChessMoveType) -
         } => encode_en_passant(original_position, new_position, promotion),
         ChessMoveType::Castle {
             rook_original_position,
+            king_original_position,
             ..
-        } => encode_castle(rook_original_position),
+        } => encode_castle(rook_original_position, king_original_position),
+        ChessMoveType::Drop {
+            piece,
+            new_position,
+        } => encode_drop(piece, new_position),
     }
 }
 
@@ -96,7 +102,7 @@ pub fn encode_move_as_long_algebraic_notation(chess_move_type: &ChessMoveType) -
 /// let notation = encode_game(&game);
 /// assert_eq!(notation, "b2b4");
 /// ```
-pub fn encode_game(game: &ChessGame) -> String {
+pub fn encode_game<V: Variant>(game: &ChessGame<V>) -> String {
     game.get_moves()
         .iter()
         .map(|m| encode_move_as_long_algebraic_notation(&m))
@@ -136,7 +142,28 @@ pub fn encode_game(game: &ChessGame) -> String {
 pub fn build_game_from_long_algebraic_notation(
     long_algebraic_notation_string: &str,
 ) -> Result<ChessGame, LongAlgebraicNotationError> {
-    let mut game = ChessGame::new();
+    build_variant_game_from_long_algebraic_notation(long_algebraic_notation_string, Standard)
+}
+
+/// Builds a `ChessGame` of a chess variant from a string containing moves encoded
+/// in long algebraic notation separated by spaces or newlines.
+///
+/// The moves are played from the standard starting position following the rules of `variant`.
+///
+/// # Arguments
+///
+/// * `long_algebraic_notation_string` - A string slice that holds the moves in long algebraic notation.
+/// * `variant` - The rules of the variant the game is played with.
+///
+/// # Returns
+///
+/// - `Ok(ChessGame<V>)` if the moves are successfully parsed and applied to the game.
+/// - `Err(LongAlgebraicNotationError)` if an invalid move is encountered or the game cannot be built.
+pub fn build_variant_game_from_long_algebraic_notation<V: Variant>(
+    long_algebraic_notation_string: &str,
+    variant: V,
+) -> Result<ChessGame<V>, LongAlgebraicNotationError> {
+    let mut game = ChessGame::new_variant(variant);
 
     let normalized_string = long_algebraic_notation_string
         .trim()
@@ -150,6 +177,8 @@ pub fn build_game_from_long_algebraic_notation(
             GameState::Check { legal_moves, .. } => legal_moves,
             GameState::Checkmate { .. } => Vec::new(),
             GameState::Stalemate => Vec::new(),
+            GameState::VariantWin { .. } => Vec::new(),
+            GameState::VariantDraw => Vec::new(),
         };
 
         let next_move = available_moves
@@ -178,13 +207,12 @@ fn encode_move(
     promotion: &Option<ChessPiece>,
 ) -> String {
     let moving_piece_str = get_piece_as_char(piece.get_piece_type()).unwrap_or(String::new());
-    let original_square_str =
-        get_square_name_from_row_and_col(original_position.0, original_position.1);
+    let original_square_str = Coord::from(*original_position);
     let taken_str = match taken_piece {
         Some(..) => "x",
         None => "",
     };
-    let new_square_str = get_square_name_from_row_and_col(new_position.0, new_position.1);
+    let new_square_str = Coord::from(*new_position);
     let promotion_str = match promotion {
         Some(piece) => {
             format!("={}", get_piece_as_char(piece.get_piece_type()).unwrap())
@@ -202,9 +230,8 @@ fn encode_en_passant(
     new_position: &(usize, usize),
     promotion: &Option<ChessPiece>,
 ) -> String {
-    let original_square_str =
-        get_square_name_from_row_and_col(original_position.0, original_position.1);
-    let new_square_str = get_square_name_from_row_and_col(new_position.0, new_position.1);
+    let original_square_str = Coord::from(*original_position);
+    let new_square_str = Coord::from(*new_position);
     let promotion_str = match promotion {
         None => String::new(),
         Some(piece) => format!("={}", get_piece_as_char(piece.get_piece_type()).unwrap()),
@@ -215,15 +242,24 @@ fn encode_en_passant(
     )
 }
 
-fn encode_castle(rook_original_position: &(usize, usize)) -> String {
-    if rook_original_position.0 == 0 {
+fn encode_castle(
+    rook_original_position: &(usize, usize),
+    king_original_position: &(usize, usize),
+) -> String {
+    if rook_original_position.0 < king_original_position.0 {
         String::from("O-O-O")
     } else {
         String::from("O-O")
     }
 }
 
-fn get_piece_as_char(piece_type: PieceType) -> Option<String> {
+fn encode_drop(piece: &ChessPiece, new_position: &(usize, usize)) -> String {
+    let piece_str = get_piece_as_char(piece.get_piece_type()).unwrap_or(String::from("P"));
+    let new_square_str = Coord::from(*new_position);
+    format!("{}@{}", piece_str, new_square_str)
+}
+
+pub(crate) fn get_piece_as_char(piece_type: PieceType) -> Option<String> {
     match piece_type {
         PieceType::Pawn => None,
         PieceType::Rook => Some(String::from("R")),
@@ -231,6 +267,9 @@ fn get_piece_as_char(piece_type: PieceType) -> Option<String> {
         PieceType::Bishop => Some(String::from("B")),
         PieceType::Queen => Some(String::from("Q")),
         PieceType::King => Some(String::from("K")),
+        PieceType::Archbishop => Some(String::from("A")),
+        PieceType::Chancellor => Some(String::from("C")),
+        PieceType::Custom(id) => Some(id.get_movement().fen_char().to_string()),
     }
 }
 
@@ -291,4 +330,19 @@ mod tests {
 
         assert_eq!(expected_encoded_string, encode_game(&game))
     }
+
+    #[test]
+    fn encode_drops() {
+        let knight_drop = ChessMoveType::Drop {
+            piece: ChessPiece::new(PieceType::Knight, crate::Color::White),
+            new_position: (3, 5),
+        };
+        let pawn_drop = ChessMoveType::Drop {
+            piece: ChessPiece::new(PieceType::Pawn, crate::Color::Black),
+            new_position: (4, 3),
+        };
+
+        assert_eq!("N@d6", encode_move_as_long_algebraic_notation(&knight_drop));
+        assert_eq!("P@e4", encode_move_as_long_algebraic_notation(&pawn_drop));
+    }
 }
@@ -1,4 +1,7 @@
-mod algebraic_notation;
+pub mod algebraic_notation;
 pub mod binary;
 pub mod forsyth_edwards_notation;
 pub mod long_algebraic_notation;
+pub mod portable_game_notation;
+pub mod svg;
+pub mod universal_chess_interface;
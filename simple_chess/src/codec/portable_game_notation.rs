@@ -0,0 +1,374 @@
+use crate::codec::algebraic_notation::{
+    decode_move_from_algebraic_notation, encode_move_as_algebraic_notation,
+};
+use crate::codec::forsyth_edwards_notation::{
+    build_game_from_string, build_variant_game_from_string,
+};
+use crate::variant::Variant;
+use crate::Color::White;
+use crate::{ChessGame, Termination};
+
+const STANDARD_STARTING_FEN: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+const SEVEN_TAG_ROSTER: [(&str, &str); 7] = [
+    ("Event", "?"),
+    ("Site", "?"),
+    ("Date", "????.??.??"),
+    ("Round", "?"),
+    ("White", "?"),
+    ("Black", "?"),
+    ("Result", "*"),
+];
+const MAX_LINE_LENGTH: usize = 80;
+const RESULTS: [&str; 4] = ["1-0", "0-1", "1/2-1/2", "*"];
+
+/// Encodes a game in Portable Game Notation.
+///
+/// The seven required tags are always written, using the values given in
+/// `tags` and `?` for the rest, followed by any other tags given. Games that do
+/// not start from the standard starting position get `SetUp` and `FEN` tags.
+/// The moves are written in standard algebraic notation and end with the
+/// value of the `Result` tag. If it is not given the result recorded for the
+/// game is used, see `ChessGame::update_result`, or `*` while there is none.
+/// Finished games get a `Termination` tag unless one is given.
+///
+/// # Arguments
+///
+/// * `game` - The game to encode, from the position it was started in.
+/// * `tags` - Tag names and values, such as `("White", "Carlsen")`.
+///
+/// # Returns
+///
+/// The game in PGN.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::codec::long_algebraic_notation::build_game_from_long_algebraic_notation;
+/// use simple_chess::codec::portable_game_notation::encode_game_as_pgn;
+///
+/// let game = build_game_from_long_algebraic_notation("f2f3 e7e5 g2g4 Qd8h4").unwrap_or_default();
+/// let pgn = encode_game_as_pgn(&game, &[("Result", "0-1")]);
+///
+/// assert!(pgn.contains("[Result \"0-1\"]"));
+/// assert!(pgn.ends_with("1. f3 e5 2. g4 Qh4# 0-1\n"));
+/// ```
+pub fn encode_game_as_pgn<V: Variant>(game: &ChessGame<V>, tags: &[(&str, &str)]) -> String {
+    let game_result = game.get_result().map(|result| result.to_string());
+    let termination = game.get_termination();
+    let starting_fen = game.get_starting_fen();
+
+    let tag_value = |name: &str, default: &str| {
+        tags.iter()
+            .find(|(tag, _)| *tag == name)
+            .map(|(_, value)| value.to_string())
+            .or_else(|| game_result.clone().filter(|_| name == "Result"))
+            .unwrap_or(default.to_string())
+    };
+
+    let mut pgn = String::new();
+    for (name, default) in SEVEN_TAG_ROSTER {
+        pgn.push_str(&format_tag(name, &tag_value(name, default)));
+    }
+    if starting_fen != STANDARD_STARTING_FEN {
+        pgn.push_str(&format_tag("SetUp", "1"));
+        pgn.push_str(&format_tag("FEN", starting_fen));
+    }
+    tags.iter()
+        .filter(|(name, _)| !SEVEN_TAG_ROSTER.iter().any(|(tag, _)| tag == name))
+        .for_each(|(name, value)| pgn.push_str(&format_tag(name, value)));
+    if let Some(termination) = termination {
+        if !tags.iter().any(|(name, _)| *name == "Termination") {
+            let value = match termination {
+                Termination::TimeForfeit => "time forfeit",
+                _ => "normal",
+            };
+            pgn.push_str(&format_tag("Termination", value));
+        }
+    }
+    pgn.push('\n');
+
+    let mut tokens = Vec::new();
+    let mut replay = build_variant_game_from_string(starting_fen, game.get_variant().clone())
+        .expect("the starting position of a game must be valid FEN");
+    for (index, chess_move) in game.get_moves_since_start().iter().enumerate() {
+        if replay.get_current_players_turn() == White {
+            tokens.push(format!("{}.", replay.get_turn_number()));
+        } else if index == 0 {
+            tokens.push(format!("{}...", replay.get_turn_number()));
+        }
+        tokens.push(encode_move_as_algebraic_notation(
+            chess_move,
+            replay.get_board(),
+        ));
+        replay.make_move(*chess_move);
+    }
+    tokens.push(tag_value("Result", "*"));
+
+    let mut line = String::new();
+    for token in tokens {
+        if !line.is_empty() && line.len() + 1 + token.len() > MAX_LINE_LENGTH {
+            pgn.push_str(&line);
+            pgn.push('\n');
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&token);
+    }
+    pgn.push_str(&line);
+    pgn.push('\n');
+    pgn
+}
+
+/// Builds a `ChessGame` from the first game in a Portable Game Notation string.
+///
+/// Games with a `FEN` tag start from that position, others from the standard
+/// starting position. Comments, variations, numeric annotation glyphs and move
+/// numbers are skipped, and the moves are read as standard algebraic notation
+/// up to the game result.
+///
+/// # Arguments
+///
+/// * `pgn` - The PGN text, tags and movetext.
+///
+/// # Returns
+///
+/// - `Ok(ChessGame)` with all the moves of the game made.
+/// - `Err(PortableGameNotationError)` if a tag, the starting position or a
+///   move can not be read.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::codec::long_algebraic_notation::encode_game;
+/// use simple_chess::codec::portable_game_notation::build_game_from_pgn;
+///
+/// let pgn = "[Event \"?\"]\n\n1. e4 {best by test} e5 2. Nf3 (2. f4) Nc6 *\n";
+/// let game = build_game_from_pgn(pgn).unwrap();
+///
+/// assert_eq!("e2e4 e7e5 Ng1f3 Nb8c6", encode_game(&game));
+/// ```
+pub fn build_game_from_pgn(pgn: &str) -> Result<ChessGame, PortableGameNotationError> {
+    let mut fen = None;
+    let mut movetext = String::new();
+    for line in pgn.lines() {
+        let line = line.trim();
+        if movetext.trim().is_empty() && line.starts_with('[') {
+            let (name, value) = parse_tag(line)?;
+            if name == "FEN" {
+                fen = Some(value);
+            }
+        } else {
+            movetext.push_str(line);
+            movetext.push('\n');
+        }
+    }
+
+    let mut game = match fen {
+        Some(fen) => build_game_from_string(&fen).map_err(|error| PortableGameNotationError {
+            reason: error.to_string(),
+        })?,
+        None => ChessGame::new(),
+    };
+
+    for token in strip_annotations(&movetext).split_whitespace() {
+        if RESULTS.contains(&token) {
+            break;
+        }
+        let token = match token.find(|c: char| !c.is_ascii_digit()) {
+            Some(end) if end > 0 && token[end..].starts_with('.') => {
+                token[end..].trim_start_matches('.')
+            }
+            _ => token,
+        };
+        if token.is_empty() {
+            continue;
+        }
+        let chess_move = decode_move_from_algebraic_notation(&game, token).map_err(|error| {
+            PortableGameNotationError {
+                reason: error.reason,
+            }
+        })?;
+        game.make_move(chess_move);
+    }
+
+    Ok(game)
+}
+
+fn parse_tag(line: &str) -> Result<(String, String), PortableGameNotationError> {
+    let invalid_tag = || PortableGameNotationError {
+        reason: format!("Invalid tag {}", line),
+    };
+    let inner = line
+        .strip_prefix('[')
+        .and_then(|line| line.strip_suffix(']'))
+        .ok_or_else(invalid_tag)?;
+    let (name, value) = inner
+        .split_once(char::is_whitespace)
+        .ok_or_else(invalid_tag)?;
+    let value = value
+        .trim()
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .ok_or_else(invalid_tag)?;
+    let value = value.replace("\\\"", "\"").replace("\\\\", "\\");
+    Ok((name.to_string(), value))
+}
+
+/// Replaces comments, variations and numeric annotation glyphs with spaces.
+fn strip_annotations(movetext: &str) -> String {
+    let mut stripped = String::new();
+    let mut variation_depth = 0;
+    let mut chars = movetext.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                chars.by_ref().find(|&c| c == '}');
+                stripped.push(' ');
+            }
+            ';' => {
+                chars.by_ref().find(|&c| c == '\n');
+                stripped.push(' ');
+            }
+            '(' => variation_depth += 1,
+            ')' => {
+                variation_depth -= 1;
+                stripped.push(' ');
+            }
+            '$' => {
+                let _ = chars.by_ref().find(|c| !c.is_ascii_digit());
+                stripped.push(' ');
+            }
+            _ if variation_depth > 0 => {}
+            _ => stripped.push(c),
+        }
+    }
+    stripped
+}
+
+fn format_tag(name: &str, value: &str) -> String {
+    let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("[{name} \"{value}\"]\n")
+}
+
+#[derive(Debug)]
+pub struct PortableGameNotationError {
+    pub reason: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::encode_game_as_string;
+    use crate::codec::universal_chess_interface::decode_move_from_uci;
+    use crate::Color::Black;
+
+    #[test]
+    fn empty_game_has_the_seven_tag_roster() {
+        let pgn = encode_game_as_pgn(&ChessGame::new(), &[("White", "Me"), ("Annotator", "Bot")]);
+        assert_eq!(
+            "[Event \"?\"]\n[Site \"?\"]\n[Date \"????.??.??\"]\n[Round \"?\"]\n[White \"Me\"]\n[Black \"?\"]\n[Result \"*\"]\n[Annotator \"Bot\"]\n\n*\n",
+            pgn
+        );
+    }
+
+    #[test]
+    fn games_from_a_position_record_it_and_start_with_black() {
+        let fen = "4k3/8/8/8/8/8/4p3/K7 b - - 0 40";
+        let mut game = build_game_from_string(fen).unwrap();
+        for uci in ["e2e1q", "a1a2", "e1e2"] {
+            game.make_move(decode_move_from_uci(&game, uci).unwrap());
+        }
+
+        let pgn = encode_game_as_pgn(&game, &[]);
+        assert!(pgn.contains("[SetUp \"1\"]\n[FEN \"4k3/8/8/8/8/8/4p3/K7 b - - 0 40\"]\n"));
+        assert!(pgn.ends_with("\n40... e1=Q+ 41. Ka2 Qe2+ *\n"));
+    }
+
+    #[test]
+    fn long_games_are_wrapped() {
+        let mut game = ChessGame::new();
+        for _ in 0..10 {
+            for uci in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+                game.make_move(decode_move_from_uci(&game, uci).unwrap());
+            }
+        }
+
+        let pgn = encode_game_as_pgn(&game, &[]);
+        let movetext = pgn.split("\n\n").nth(1).unwrap();
+        assert!(movetext.lines().count() > 1);
+        movetext
+            .lines()
+            .for_each(|line| assert!(line.len() <= MAX_LINE_LENGTH));
+    }
+
+    #[test]
+    fn finished_games_record_their_result() {
+        let mut game = ChessGame::new();
+        game.make_move(decode_move_from_uci(&game, "e2e4").unwrap());
+        game.resign(Black).unwrap();
+
+        let pgn = encode_game_as_pgn(&game, &[]);
+        assert!(pgn.contains("[Result \"1-0\"]\n[Termination \"normal\"]\n"));
+        assert!(pgn.ends_with("1. e4 1-0\n"));
+
+        let mut game = build_game_from_string("4k3/8/8/8/8/8/8/4K2Q b - - 0 1").unwrap();
+        game.lose_on_time(White).unwrap();
+        let pgn = encode_game_as_pgn(&game, &[("Termination", "adjudication")]);
+        assert!(pgn.contains("[Result \"1/2-1/2\"]"));
+        assert!(pgn.contains("[Termination \"adjudication\"]"));
+        assert!(!pgn.contains("time forfeit"));
+    }
+
+    #[test]
+    fn encoded_games_can_be_read_back() {
+        let fen = "4k3/8/8/8/8/8/4p3/K7 b - - 0 40";
+        let mut game = build_game_from_string(fen).unwrap();
+        for uci in ["e2e1q", "a1a2", "e1e2"] {
+            game.make_move(decode_move_from_uci(&game, uci).unwrap());
+        }
+
+        let read_game = build_game_from_pgn(&encode_game_as_pgn(&game, &[])).unwrap();
+        assert_eq!(
+            encode_game_as_string(&game),
+            encode_game_as_string(&read_game)
+        );
+        assert_eq!(game.get_moves(), read_game.get_moves());
+    }
+
+    #[test]
+    fn games_from_a_position_with_en_passant_can_be_read_back() {
+        let fen = "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2";
+        let mut game = build_game_from_string(fen).unwrap();
+        for uci in ["g1f3", "b8c6"] {
+            game.make_move(decode_move_from_uci(&game, uci).unwrap());
+        }
+
+        let pgn = encode_game_as_pgn(&game, &[]);
+        assert!(pgn.contains(&format!("[FEN \"{fen}\"]\n")));
+        assert!(pgn.ends_with("\n2. Nf3 Nc6 *\n"));
+
+        let read_game = build_game_from_pgn(&pgn).unwrap();
+        assert_eq!(fen, read_game.get_starting_fen());
+        assert_eq!(
+            encode_game_as_string(&game),
+            encode_game_as_string(&read_game)
+        );
+        assert_eq!(game.get_moves(), read_game.get_moves());
+    }
+
+    #[test]
+    fn annotations_are_skipped() {
+        let pgn = "[White \"A \\\"quoted\\\" name\"]\n\n1. e4 $1 e5 ; king's pawn\n2. Nf3 (2. f4 exf4 (2... d5)) {main line} 2... Nc6 3.Bc4 Bc5 4. 0-0 1-0 5. a3";
+        let game = build_game_from_pgn(pgn).unwrap();
+        assert_eq!(7, game.get_moves().len());
+    }
+
+    #[test]
+    fn bad_moves_are_reported() {
+        let error = build_game_from_pgn("1. e4 e4 *").unwrap_err();
+        assert_eq!("Unable to make move e4", error.reason);
+        assert!(build_game_from_pgn("[Event]\n\n*").is_err());
+    }
+}
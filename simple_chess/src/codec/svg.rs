@@ -0,0 +1,532 @@
+use crate::piece::{ChessPiece, PieceType};
+use crate::variant::Variant;
+use crate::{ChessGame, Color};
+use game_board::{get_file_name, get_rank_name, Board, Coord, Orientation, RenderPiece};
+use std::fmt::Write;
+
+/// The piece drawings are made on a 45 by 45 grid and scaled to the squares.
+const PIECE_GRID: f64 = 45.0;
+const PIECE_BASE: &str = r#"<path d="M11 38h23v-4H11z"/>"#;
+const PAWN: &str = r#"<path d="M17 34h11l-3-13h-5z"/><circle cx="22.5" cy="15" r="6"/>"#;
+const ROOK: &str = r#"<path d="M15 34h15V18H15z"/><path d="M12 11h5v3h3v-3h5v3h3v-3h5v7H12z"/>"#;
+const KNIGHT: &str = r#"<path d="M14 34h17v-5c0-10-4-18-11-20l-2-3-2 4c-4 3-6 8-5 11l3 1 5-3-5 15z"/><circle cx="18" cy="14" r="1.2" class="detail"/>"#;
+const BISHOP: &str = r#"<path d="M22.5 10c-6 5-8 13-5 24h10c3-11 1-19-5-24z"/><circle cx="22.5" cy="8" r="2.5"/><path d="M20 22h5" class="detail"/>"#;
+const QUEEN: &str = r#"<path d="M10 15l4 19h17l4-19-7 9-5.5-13-5.5 13z"/><circle cx="10" cy="14" r="2"/><circle cx="22.5" cy="10" r="2"/><circle cx="35" cy="14" r="2"/>"#;
+const KING: &str = r#"<path d="M13 34c-4-9 3-15 9.5-10 6.5-5 13.5 1 9.5 10z"/><path d="M22.5 6v12M18.5 10h8" fill="none"/>"#;
+
+/// Draws chess boards as self-contained SVG diagrams.
+///
+/// The pieces are drawn with shapes defined in the document itself, so the
+/// diagrams look the same everywhere without needing a chess font. Squares
+/// can be highlighted and arrows drawn between them to annotate a position.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::codec::svg::SvgRenderer;
+/// use simple_chess::game_board::Coord;
+/// use simple_chess::ChessGame;
+///
+/// let svg = SvgRenderer::new()
+///     .set_size(320)
+///     .add_arrow("e2".parse().unwrap(), "e4".parse().unwrap(), "#15781b")
+///     .render_game(&ChessGame::new());
+///
+/// assert!(svg.starts_with("<svg"));
+/// assert!(svg.contains(r#"width="320" height="320""#));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SvgRenderer {
+    size: usize,
+    orientation: Orientation,
+    show_coordinates: bool,
+    light_color: String,
+    dark_color: String,
+    last_move_color: String,
+    check_color: String,
+    highlights: Vec<(Coord, String)>,
+    arrows: Vec<(Coord, Coord, String)>,
+}
+
+impl SvgRenderer {
+    /// Creates a renderer for a 400 pixel wide board in the standard
+    /// orientation, with coordinates and no annotations.
+    pub fn new() -> Self {
+        Self {
+            size: 400,
+            orientation: Orientation::Standard,
+            show_coordinates: true,
+            light_color: "#f0d9b5".to_string(),
+            dark_color: "#b58863".to_string(),
+            last_move_color: "#cdd16a".to_string(),
+            check_color: "#e0282e".to_string(),
+            highlights: Vec::new(),
+            arrows: Vec::new(),
+        }
+    }
+
+    /// Sets the width of the diagram in pixels. The height follows from the
+    /// shape of the board.
+    pub fn set_size(mut self, size: usize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn set_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Draws file letters along the bottom edge and rank numbers along the
+    /// left edge, inside the squares.
+    pub fn set_show_coordinates(mut self, show_coordinates: bool) -> Self {
+        self.show_coordinates = show_coordinates;
+        self
+    }
+
+    /// Sets the fill colors of the light and dark squares, as any SVG color
+    /// such as `#f0d9b5` or `tan`. Colors are escaped before being written
+    /// into the document, as are those of the other setters.
+    pub fn set_square_colors(mut self, light_color: &str, dark_color: &str) -> Self {
+        self.light_color = escape_attribute(light_color);
+        self.dark_color = escape_attribute(dark_color);
+        self
+    }
+
+    /// Sets the colors `render_game` highlights the last move and a king in
+    /// check with.
+    pub fn set_highlight_colors(mut self, last_move_color: &str, check_color: &str) -> Self {
+        self.last_move_color = escape_attribute(last_move_color);
+        self.check_color = escape_attribute(check_color);
+        self
+    }
+
+    /// Highlights a square with a color, drawn half transparent over the
+    /// square.
+    pub fn add_highlight(mut self, square: Coord, color: &str) -> Self {
+        self.highlights.push((square, escape_attribute(color)));
+        self
+    }
+
+    /// Draws an arrow from the center of one square to the center of another.
+    pub fn add_arrow(mut self, from: Coord, to: Coord, color: &str) -> Self {
+        self.arrows.push((from, to, escape_attribute(color)));
+        self
+    }
+
+    /// Draws the position of a game.
+    ///
+    /// On top of the configured annotations, the squares of the last move and
+    /// the king of the player to move, when in check, are highlighted.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game to draw.
+    ///
+    /// # Returns
+    ///
+    /// The SVG document as a string.
+    pub fn render_game<V: Variant>(&self, game: &ChessGame<V>) -> String {
+        let mut renderer = self.clone();
+        if let Some(last_move) = game.get_last_move() {
+            let color = self.last_move_color.clone();
+            if let Some(from) = last_move.get_original_position() {
+                renderer.highlights.insert(0, (from, color.clone()));
+            }
+            renderer
+                .highlights
+                .insert(0, (last_move.get_new_position(), color));
+        }
+
+        let turn = game.get_current_players_turn();
+        if V::is_in_check(turn, game.get_board()) {
+            game.get_board()
+                .iter_pieces()
+                .filter(|(_, _, piece)| {
+                    piece.get_color() == turn && piece.get_piece_type().is_royal()
+                })
+                .for_each(|(col, row, _)| {
+                    renderer
+                        .highlights
+                        .push((Coord::new(col, row), self.check_color.clone()));
+                });
+        }
+
+        renderer.render_board(game.get_board())
+    }
+
+    /// Draws a board with the configured annotations.
+    ///
+    /// # Arguments
+    ///
+    /// * `board` - The board to draw.
+    ///
+    /// # Returns
+    ///
+    /// The SVG document as a string.
+    pub fn render_board(&self, board: &Board<ChessPiece>) -> String {
+        let square = self.size as f64 / board.get_width() as f64;
+        let height = square * board.get_height() as f64;
+        let mut svg = String::new();
+
+        write!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+            self.size,
+            fmt(height),
+            self.size,
+            fmt(height)
+        )
+        .unwrap();
+        svg.push_str(&self.render_piece_definitions(board));
+
+        for (col, row, square_color) in board
+            .iter()
+            .map(|s| (s.get_column(), s.get_row(), s.get_color()))
+        {
+            let (x, y) = self.get_square_origin(board, Coord::new(col, row), square);
+            let fill = match square_color {
+                game_board::SquareColor::White => &self.light_color,
+                game_board::SquareColor::Black => &self.dark_color,
+            };
+            write!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{fill}"/>"#,
+                fmt(x),
+                fmt(y),
+                fmt(square),
+                fmt(square)
+            )
+            .unwrap();
+        }
+
+        for (coord, color) in self.highlights.iter().filter(|(c, _)| c.is_on(board)) {
+            let (x, y) = self.get_square_origin(board, *coord, square);
+            write!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{color}" fill-opacity="0.5"/>"#,
+                fmt(x),
+                fmt(y),
+                fmt(square),
+                fmt(square)
+            )
+            .unwrap();
+        }
+
+        if self.show_coordinates {
+            svg.push_str(&self.render_coordinates(board, square));
+        }
+
+        let scale = square / PIECE_GRID;
+        for (col, row, piece) in board.iter_pieces() {
+            let (x, y) = self.get_square_origin(board, Coord::new(col, row), square);
+            write!(
+                svg,
+                r##"<use xlink:href="#{}" transform="translate({} {}) scale({})"/>"##,
+                get_piece_id(piece),
+                fmt(x),
+                fmt(y),
+                fmt(scale)
+            )
+            .unwrap();
+        }
+
+        for (from, to, color) in &self.arrows {
+            if from.is_on(board) && to.is_on(board) && from != to {
+                svg.push_str(&self.render_arrow(board, *from, *to, color, square));
+            }
+        }
+
+        svg.push_str("</svg>");
+        svg
+    }
+
+    fn get_square_origin(
+        &self,
+        board: &Board<ChessPiece>,
+        coord: Coord,
+        square: f64,
+    ) -> (f64, f64) {
+        let (col, row) = match self.orientation {
+            Orientation::Standard => (coord.get_col(), board.get_height() - 1 - coord.get_row()),
+            Orientation::Flipped => (board.get_width() - 1 - coord.get_col(), coord.get_row()),
+        };
+        (col as f64 * square, row as f64 * square)
+    }
+
+    fn render_piece_definitions(&self, board: &Board<ChessPiece>) -> String {
+        let mut pieces: Vec<ChessPiece> = Vec::new();
+        board.iter_pieces().for_each(|(_, _, piece)| {
+            if !pieces.contains(piece) {
+                pieces.push(*piece);
+            }
+        });
+
+        let mut defs = String::from("<defs>");
+        for piece in pieces {
+            let (fill, stroke) = match piece.get_color() {
+                Color::White => ("#fff", "#000"),
+                Color::Black => ("#000", "#fff"),
+            };
+            let shape = match piece.get_piece_type() {
+                PieceType::Pawn => PAWN.to_string(),
+                PieceType::Rook => ROOK.to_string(),
+                PieceType::Knight => KNIGHT.to_string(),
+                PieceType::Bishop => BISHOP.to_string(),
+                PieceType::Queen => QUEEN.to_string(),
+                PieceType::King => KING.to_string(),
+                _ => format!(
+                    r#"<circle cx="22.5" cy="21" r="12"/><text x="22.5" y="26" font-family="sans-serif" font-size="14" font-weight="bold" text-anchor="middle" class="detail">{}</text>"#,
+                    piece.get_letter().to_ascii_uppercase()
+                ),
+            };
+            write!(
+                defs,
+                r##"<g id="{}" fill="{fill}" stroke="#000" stroke-width="1.5" stroke-linejoin="round">{PIECE_BASE}{}</g>"##,
+                get_piece_id(&piece),
+                shape.replace(
+                    r#"class="detail""#,
+                    &format!(r#"fill="{stroke}" stroke="{stroke}""#)
+                )
+            )
+            .unwrap();
+        }
+        defs.push_str("</defs>");
+        defs
+    }
+
+    fn render_coordinates(&self, board: &Board<ChessPiece>, square: f64) -> String {
+        let mut svg = String::new();
+        let font_size = square / 5.0;
+        let bottom_row = match self.orientation {
+            Orientation::Standard => 0,
+            Orientation::Flipped => board.get_height() - 1,
+        };
+        let left_col = match self.orientation {
+            Orientation::Standard => 0,
+            Orientation::Flipped => board.get_width() - 1,
+        };
+
+        for col in 0..board.get_width() {
+            let coord = Coord::new(col, bottom_row);
+            let (x, y) = self.get_square_origin(board, coord, square);
+            write!(
+                svg,
+                r#"<text x="{}" y="{}" font-family="sans-serif" font-size="{}" fill="{}" text-anchor="end">{}</text>"#,
+                fmt(x + square - font_size / 4.0),
+                fmt(y + square - font_size / 4.0),
+                fmt(font_size),
+                self.get_contrasting_color(coord),
+                get_file_name(col)
+            )
+            .unwrap();
+        }
+
+        for row in 0..board.get_height() {
+            let coord = Coord::new(left_col, row);
+            let (x, y) = self.get_square_origin(board, coord, square);
+            write!(
+                svg,
+                r#"<text x="{}" y="{}" font-family="sans-serif" font-size="{}" fill="{}">{}</text>"#,
+                fmt(x + font_size / 4.0),
+                fmt(y + font_size),
+                fmt(font_size),
+                self.get_contrasting_color(coord),
+                get_rank_name(row)
+            )
+            .unwrap();
+        }
+
+        svg
+    }
+
+    fn get_contrasting_color(&self, coord: Coord) -> &str {
+        if (coord.get_col() + coord.get_row()) % 2 == 1 {
+            &self.dark_color
+        } else {
+            &self.light_color
+        }
+    }
+
+    fn render_arrow(
+        &self,
+        board: &Board<ChessPiece>,
+        from: Coord,
+        to: Coord,
+        color: &str,
+        square: f64,
+    ) -> String {
+        let center = |coord: Coord| {
+            let (x, y) = self.get_square_origin(board, coord, square);
+            (x + square / 2.0, y + square / 2.0)
+        };
+        let (x1, y1) = center(from);
+        let (x2, y2) = center(to);
+        let length = ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt();
+        let (dx, dy) = ((x2 - x1) / length, (y2 - y1) / length);
+
+        let head_length = square * 0.4;
+        let head_width = square * 0.3;
+        let (base_x, base_y) = (x2 - dx * head_length, y2 - dy * head_length);
+        let (left_x, left_y) = (base_x - dy * head_width, base_y + dx * head_width);
+        let (right_x, right_y) = (base_x + dy * head_width, base_y - dx * head_width);
+
+        format!(
+            r#"<g fill="{color}" stroke="{color}" opacity="0.8"><line x1="{}" y1="{}" x2="{}" y2="{}" stroke-width="{}" stroke-linecap="round"/><polygon points="{},{} {},{} {},{}" stroke="none"/></g>"#,
+            fmt(x1),
+            fmt(y1),
+            fmt(base_x),
+            fmt(base_y),
+            fmt(square * 0.15),
+            fmt(x2),
+            fmt(y2),
+            fmt(left_x),
+            fmt(left_y),
+            fmt(right_x),
+            fmt(right_y)
+        )
+    }
+}
+
+impl Default for SvgRenderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn get_piece_id(piece: &ChessPiece) -> String {
+    let color = match piece.get_color() {
+        Color::White => "white",
+        Color::Black => "black",
+    };
+    format!("{color}-{}", piece.get_letter().to_ascii_lowercase())
+}
+
+/// Escapes the characters that would end or break out of an XML attribute.
+fn escape_attribute(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Formats a coordinate with at most two decimals, dropping trailing zeros.
+fn fmt(value: f64) -> String {
+    let formatted = format!("{value:.2}");
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::build_game_from_string;
+
+    #[test]
+    fn only_pieces_on_the_board_are_defined() {
+        let game = build_game_from_string("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").unwrap();
+        let svg = SvgRenderer::new().render_game(&game);
+
+        assert_eq!(3, svg.matches("<g id=").count());
+        assert!(svg.contains(r#"<g id="white-k""#));
+        assert!(svg.contains(r#"<g id="white-p""#));
+        assert!(svg.contains(r#"<g id="black-k""#));
+        assert_eq!(3, svg.matches("<use ").count());
+        assert!(svg.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn squares_follow_the_orientation() {
+        let mut board = Board::build(8, 8).unwrap();
+        board.place_piece(ChessPiece::new(PieceType::Rook, Color::White), 0, 0);
+
+        let svg = SvgRenderer::new().set_size(80).render_board(&board);
+        assert!(svg.contains(r##"xlink:href="#white-r" transform="translate(0 70) scale(0.22)""##));
+
+        let svg = SvgRenderer::new()
+            .set_size(80)
+            .set_orientation(Orientation::Flipped)
+            .render_board(&board);
+        assert!(svg.contains(r##"xlink:href="#white-r" transform="translate(70 0) scale(0.22)""##));
+    }
+
+    #[test]
+    fn game_highlights_last_move_and_check() {
+        let mut game = build_game_from_string("4k3/8/8/8/8/8/8/4K2R w - - 0 1").unwrap();
+        game.make_move(crate::ChessMoveType::Move {
+            original_position: (7, 0),
+            new_position: (7, 7),
+            piece: ChessPiece::new(PieceType::Rook, Color::White),
+            taken_piece: None,
+            promotion: None,
+        });
+
+        let svg = SvgRenderer::new()
+            .set_size(80)
+            .set_highlight_colors("yellow", "red")
+            .render_game(&game);
+        assert!(svg.contains(
+            r#"<rect x="70" y="70" width="10" height="10" fill="yellow" fill-opacity="0.5"/>"#
+        ));
+        assert!(svg.contains(
+            r#"<rect x="70" y="0" width="10" height="10" fill="yellow" fill-opacity="0.5"/>"#
+        ));
+        assert!(svg.contains(
+            r#"<rect x="40" y="0" width="10" height="10" fill="red" fill-opacity="0.5"/>"#
+        ));
+    }
+
+    #[test]
+    fn arrows_point_from_center_to_center() {
+        let board = Board::<ChessPiece>::build(8, 8).unwrap();
+        let svg = SvgRenderer::new()
+            .set_size(80)
+            .add_arrow(Coord::new(0, 0), Coord::new(0, 3), "blue")
+            .render_board(&board);
+
+        assert!(svg.contains(r#"<line x1="5" y1="75" x2="5" y2="49""#));
+        assert!(svg.contains(r#"<polygon points="5,45 8,49 2,49""#));
+    }
+
+    #[test]
+    fn square_colors_and_coordinates_can_be_configured() {
+        let board = Board::<ChessPiece>::build(8, 8).unwrap();
+        let svg = SvgRenderer::new()
+            .set_square_colors("white", "gray")
+            .render_board(&board);
+        assert_eq!(32, svg.matches(r#"fill="white"/>"#).count());
+        assert_eq!(32, svg.matches(r#"fill="gray"/>"#).count());
+        assert_eq!(16, svg.matches("<text").count());
+
+        let svg = SvgRenderer::new()
+            .set_show_coordinates(false)
+            .render_board(&board);
+        assert!(!svg.contains("<text"));
+    }
+
+    #[test]
+    fn colors_are_escaped() {
+        let board = Board::<ChessPiece>::build(8, 8).unwrap();
+        let svg = SvgRenderer::new()
+            .set_square_colors(r#"red"/><script>alert(1)</script><x a=""#, "a&b")
+            .add_arrow(Coord::new(0, 0), Coord::new(0, 3), "'blue'")
+            .render_board(&board);
+
+        assert!(!svg.contains("<script"));
+        assert!(svg.contains(
+            r#"fill="red&quot;/&gt;&lt;script&gt;alert(1)&lt;/script&gt;&lt;x a=&quot;"/>"#
+        ));
+        assert!(svg.contains(r#"fill="a&amp;b"/>"#));
+        assert!(svg.contains(r#"fill="&apos;blue&apos;""#));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::chess_game_state_analyzer::GameState;
+use crate::codec::long_algebraic_notation::get_piece_as_char;
+use crate::variant::Variant;
+use crate::{ChessGame, ChessMoveType};
+use game_board::Coord;
+
+/// Encodes a chess move the way the Universal Chess Interface writes them.
+///
+/// Moves are written as the starting and ending square followed by the
+/// lower case letter of any promotion, as in `e2e4` or `e7e8q`. Castling is
+/// written as the move of the king and drops as in `N@f3`.
+///
+/// # Arguments
+///
+/// * `chess_move_type` - The move to encode.
+///
+/// # Returns
+///
+/// The move in UCI notation.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::codec::universal_chess_interface::encode_move_as_uci;
+/// use simple_chess::piece::{ChessPiece, PieceType};
+/// use simple_chess::Color::White;
+/// use simple_chess::ChessMoveType;
+///
+/// let chess_move = ChessMoveType::Move {
+///     original_position: (4, 6),
+///     new_position: (4, 7),
+///     piece: ChessPiece::new(PieceType::Pawn, White),
+///     taken_piece: None,
+///     promotion: Some(ChessPiece::new(PieceType::Queen, White)),
+/// };
+///
+/// assert_eq!("e7e8q", encode_move_as_uci(&chess_move));
+/// ```
+pub fn encode_move_as_uci(chess_move_type: &ChessMoveType) -> String {
+    match chess_move_type {
+        ChessMoveType::Move {
+            original_position,
+            new_position,
+            promotion,
+            ..
+        }
+        | ChessMoveType::EnPassant {
+            original_position,
+            new_position,
+            promotion,
+            ..
+        } => {
+            let promotion_str = promotion
+                .and_then(|piece| get_piece_as_char(piece.get_piece_type()))
+                .unwrap_or_default()
+                .to_lowercase();
+            format!(
+                "{}{}{}",
+                Coord::from(*original_position),
+                Coord::from(*new_position),
+                promotion_str
+            )
+        }
+        ChessMoveType::Castle {
+            king_original_position,
+            king_new_position,
+            ..
+        } => format!(
+            "{}{}",
+            Coord::from(*king_original_position),
+            Coord::from(*king_new_position)
+        ),
+        ChessMoveType::Drop {
+            piece,
+            new_position,
+        } => format!(
+            "{}@{}",
+            get_piece_as_char(piece.get_piece_type()).unwrap_or(String::from("P")),
+            Coord::from(*new_position)
+        ),
+    }
+}
+
+/// Finds the legal move written in UCI notation.
+///
+/// Castling may also be written as the king moving onto its rook, as UCI does
+/// for Chess960, such as `e1h1`.
+///
+/// # Arguments
+///
+/// * `game` - The game the move is to be made in.
+/// * `notation` - The move in UCI notation, such as `g1f3`.
+///
+/// # Returns
+///
+/// - `Ok(ChessMoveType)` with the matching legal move.
+/// - `Err(UniversalChessInterfaceError)` if no legal move matches the notation.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::codec::universal_chess_interface::decode_move_from_uci;
+/// use simple_chess::ChessGame;
+///
+/// let game = ChessGame::new();
+/// assert!(decode_move_from_uci(&game, "g1f3").is_ok());
+/// assert!(decode_move_from_uci(&game, "g1g3").is_err());
+/// ```
+pub fn decode_move_from_uci<V: Variant>(
+    game: &ChessGame<V>,
+    notation: &str,
+) -> Result<ChessMoveType, UniversalChessInterfaceError> {
+    let legal_moves = match game.clone().get_game_state() {
+        GameState::InProgress { legal_moves, .. } => legal_moves,
+        GameState::Check { legal_moves, .. } => legal_moves,
+        _ => Vec::new(),
+    };
+
+    let notation = notation.trim();
+    legal_moves
+        .into_iter()
+        .find(|legal_move| {
+            if encode_move_as_uci(legal_move).eq_ignore_ascii_case(notation) {
+                return true;
+            }
+            match legal_move {
+                ChessMoveType::Castle {
+                    king_original_position,
+                    rook_original_position,
+                    ..
+                } => {
+                    format!(
+                        "{}{}",
+                        Coord::from(*king_original_position),
+                        Coord::from(*rook_original_position)
+                    ) == notation
+                }
+                _ => false,
+            }
+        })
+        .ok_or_else(|| UniversalChessInterfaceError {
+            reason: format!("Unable to make move {}", notation),
+        })
+}
+
+#[derive(Debug)]
+pub struct UniversalChessInterfaceError {
+    pub reason: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::build_game_from_string;
+
+    #[test]
+    fn castling_is_written_as_the_king_move() {
+        let game = build_game_from_string("4k3/8/8/8/8/8/8/R3K2R w KQ - 0 1").unwrap();
+
+        let short = decode_move_from_uci(&game, "e1g1").unwrap();
+        assert!(matches!(short, ChessMoveType::Castle { .. }));
+        assert_eq!("e1g1", encode_move_as_uci(&short));
+
+        let long = decode_move_from_uci(&game, "e1a1").unwrap();
+        assert_eq!("e1c1", encode_move_as_uci(&long));
+    }
+
+    #[test]
+    fn en_passant_and_promotions_round_trip() {
+        let game = build_game_from_string("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        let promotion = decode_move_from_uci(&game, "b7b8N").unwrap();
+        assert_eq!("b7b8n", encode_move_as_uci(&promotion));
+
+        let mut game = build_game_from_string("4k3/3p4/8/4P3/8/8/8/4K3 b - - 0 1").unwrap();
+        game.make_move(decode_move_from_uci(&game, "d7d5").unwrap());
+        let en_passant = decode_move_from_uci(&game, "e5d6").unwrap();
+        assert!(matches!(en_passant, ChessMoveType::EnPassant { .. }));
+    }
+
+    #[test]
+    fn illegal_moves_are_errors() {
+        let game = ChessGame::new();
+        let error = decode_move_from_uci(&game, "e2e5").unwrap_err();
+        assert_eq!("Unable to make move e2e5", error.reason);
+    }
+}
@@ -3,11 +3,22 @@ pub mod chess_game_builder;
 mod chess_game_move_analyzer;
 pub mod chess_game_state_analyzer;
 mod chess_move;
+pub mod clock;
 pub mod color;
+pub mod mcts;
+pub mod opening_book;
 pub mod piece;
+pub mod player;
+pub mod simulation;
+pub mod tournament;
+pub mod variant;
 
 pub mod codec;
 pub use chess_game::ChessGame;
+pub use chess_game::DrawReason;
+pub use chess_game::GameResult;
+pub use chess_game::GameResultError;
+pub use chess_game::Termination;
 pub use chess_game_builder::ChessGameBuilder;
 pub use chess_move::ChessMoveType;
 pub use color::Color;
@@ -0,0 +1,646 @@
+use crate::chess_game_state_analyzer::GameState;
+use crate::codec::forsyth_edwards_notation::encode_game_as_string;
+use crate::variant::{Standard, Variant};
+use crate::{ChessGame, ChessMoveType};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_distr::Dirichlet;
+
+/// A source of move priors and position values for the tree search.
+///
+/// This is the extension point used to plug a neural network (or any other
+/// heuristic) into the search, AlphaZero style. For every position the search
+/// wants to expand it asks the `PolicyValueFn` for a prior probability for each
+/// legal move, and for an estimate of how good the position is.
+///
+/// The trait is generic over the `Variant` being played, evaluators that work
+/// for any variant can implement it for all `V: Variant`.
+pub trait PolicyValueFn<V: Variant = Standard> {
+    /// Evaluates a position that is about to be expanded.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game in the position being evaluated.
+    /// * `legal_moves` - The legal moves of the player whose turn it is, the
+    ///   game is guaranteed to not be over.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of:
+    /// - `Vec<f32>` - One non-negative prior per entry of `legal_moves`, in the
+    ///   same order. The priors do not need to sum to 1, the search normalizes them.
+    /// - `f32` - The value of the position in the range `-1.0..=1.0`, from the
+    ///   perspective of the player whose turn it is.
+    fn evaluate(&mut self, game: &ChessGame<V>, legal_moves: &[ChessMoveType]) -> (Vec<f32>, f32);
+}
+
+/// Tunable parameters of the Monte Carlo Tree Search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MctsConfig {
+    /// Number of simulations to run for each call to `Mcts::search`.
+    pub simulations: usize,
+    /// Exploration constant of the PUCT formula.
+    pub c_puct: f32,
+    /// Concentration of the Dirichlet noise added to the root priors.
+    pub dirichlet_alpha: f32,
+    /// Fraction of the root priors replaced by Dirichlet noise, `0.0` disables the noise.
+    pub dirichlet_epsilon: f32,
+}
+
+impl Default for MctsConfig {
+    fn default() -> Self {
+        Self {
+            simulations: 800,
+            c_puct: 1.25,
+            dirichlet_alpha: 0.3,
+            dirichlet_epsilon: 0.25,
+        }
+    }
+}
+
+/// Monte Carlo Tree Search using PUCT selection.
+///
+/// Priors and leaf values are provided by a `PolicyValueFn`. The tree is kept
+/// between calls to `search`, so after a move is played it can be reused by
+/// calling `advance` with that move.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::ChessGame;
+/// use simple_chess::mcts::{Mcts, MctsConfig, UniformRandomRollout};
+///
+/// let mut game = ChessGame::new();
+/// let config = MctsConfig {
+///     simulations: 32,
+///     ..MctsConfig::default()
+/// };
+/// let mut mcts = Mcts::new(UniformRandomRollout::new(7, 10), config, 7);
+///
+/// let policy = mcts.search(&game);
+/// assert_eq!(20, policy.len());
+///
+/// let chess_move = mcts.pick_move(1.0).unwrap();
+/// game.make_move(chess_move);
+/// mcts.advance(&chess_move);
+/// ```
+pub struct Mcts<E: PolicyValueFn<V>, V: Variant = Standard> {
+    config: MctsConfig,
+    evaluator: E,
+    rng: StdRng,
+    root: Node,
+    root_game: Option<ChessGame<V>>,
+}
+
+struct Node {
+    visit_count: u32,
+    value_sum: f32,
+    expanded: bool,
+    terminal_value: Option<f32>,
+    children: Vec<Edge>,
+}
+
+struct Edge {
+    chess_move: ChessMoveType,
+    prior: f32,
+    node: Node,
+}
+
+impl Node {
+    fn new() -> Self {
+        Self {
+            visit_count: 0,
+            value_sum: 0.0,
+            expanded: false,
+            terminal_value: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// The mean value of this node, from the perspective of the player who moved into it.
+    fn mean_value(&self) -> f32 {
+        if self.visit_count == 0 {
+            0.0
+        } else {
+            self.value_sum / self.visit_count as f32
+        }
+    }
+}
+
+impl<E: PolicyValueFn<V>, V: Variant> Mcts<E, V> {
+    /// Creates a new search with an empty tree.
+    ///
+    /// # Arguments
+    ///
+    /// * `evaluator` - The `PolicyValueFn` used to expand positions.
+    /// * `config` - The search parameters.
+    /// * `seed` - Seed for the random number generator used for the Dirichlet
+    ///   noise and move picking, the same seed and evaluator give the same search.
+    pub fn new(evaluator: E, config: MctsConfig, seed: u64) -> Self {
+        Self {
+            config,
+            evaluator,
+            rng: StdRng::seed_from_u64(seed),
+            root: Node::new(),
+            root_game: None,
+        }
+    }
+
+    /// Get the search parameters
+    pub fn get_config(&self) -> &MctsConfig {
+        &self.config
+    }
+
+    /// Get the evaluator used by the search
+    pub fn get_evaluator(&self) -> &E {
+        &self.evaluator
+    }
+
+    /// Get a mutable reference to the evaluator used by the search
+    pub fn get_evaluator_mut(&mut self) -> &mut E {
+        &mut self.evaluator
+    }
+
+    /// Runs `MctsConfig::simulations` simulations from the given position.
+    ///
+    /// If the tree already holds this position, for example after calling
+    /// `advance` with the move that was just played, the existing statistics are
+    /// kept and the new simulations are added on top of them. Otherwise the old
+    /// tree is discarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game to search from.
+    ///
+    /// # Returns
+    ///
+    /// The visit-count policy of the root, see `Mcts::policy`.
+    pub fn search(&mut self, game: &ChessGame<V>) -> Vec<(ChessMoveType, f32)> {
+        let is_same_position =
+            self.root_game.as_ref().map(encode_game_as_string) == Some(encode_game_as_string(game));
+        if !is_same_position {
+            self.root = Node::new();
+            self.root_game = Some(game.clone());
+        }
+
+        let root_game = self.root_game.clone().unwrap();
+        if !self.root.expanded && self.root.terminal_value.is_none() {
+            visit(
+                &mut self.root,
+                &mut root_game.clone(),
+                &mut self.evaluator,
+                &self.config,
+                None,
+            );
+        }
+
+        let noisy_priors = self.noisy_root_priors();
+
+        for _ in 0..self.config.simulations {
+            visit(
+                &mut self.root,
+                &mut root_game.clone(),
+                &mut self.evaluator,
+                &self.config,
+                noisy_priors.as_deref(),
+            );
+        }
+
+        self.policy()
+    }
+
+    /// The visit-count distribution over the moves of the root.
+    ///
+    /// These are the policy targets used to train a `PolicyValueFn`, each
+    /// move is paired with the fraction of root visits that went through it.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of every legal move in the root position with its share of the
+    /// visits, empty if no search has been run or the game is over.
+    pub fn policy(&self) -> Vec<(ChessMoveType, f32)> {
+        let total: u32 = self.root.children.iter().map(|e| e.node.visit_count).sum();
+        self.root
+            .children
+            .iter()
+            .map(|edge| {
+                let share = if total == 0 {
+                    0.0
+                } else {
+                    edge.node.visit_count as f32 / total as f32
+                };
+                (edge.chess_move, share)
+            })
+            .collect()
+    }
+
+    /// The mean value of the root from the perspective of the player to move.
+    pub fn root_value(&self) -> f32 {
+        -self.root.mean_value()
+    }
+
+    /// The number of times the root has been visited.
+    pub fn root_visit_count(&self) -> u32 {
+        self.root.visit_count
+    }
+
+    /// Picks a move from the root using the visit counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `temperature` - With a temperature of `0.0` or less the most visited
+    ///   move is returned. Otherwise a move is sampled with a probability
+    ///   proportional to `visit_count ^ (1 / temperature)`, so `1.0` samples
+    ///   from the policy and higher values flatten it. The visit counts are
+    ///   divided by the largest one first, so tiny temperatures approach
+    ///   picking the most visited move instead of overflowing.
+    ///
+    /// # Returns
+    ///
+    /// `None` if there are no moves to pick from.
+    pub fn pick_move(&mut self, temperature: f32) -> Option<ChessMoveType> {
+        let children = &self.root.children;
+        if children.is_empty() {
+            return None;
+        }
+
+        if temperature <= 0.0 {
+            return children
+                .iter()
+                .enumerate()
+                .max_by_key(|(index, edge)| (edge.node.visit_count, usize::MAX - index))
+                .map(|(_, edge)| edge.chess_move);
+        }
+
+        let max_visits = children
+            .iter()
+            .map(|edge| edge.node.visit_count)
+            .max()
+            .unwrap_or_default()
+            .max(1) as f64;
+        let weights: Vec<f64> = children
+            .iter()
+            .map(|edge| (edge.node.visit_count as f64 / max_visits).powf(1.0 / temperature as f64))
+            .collect();
+
+        match WeightedIndex::new(&weights) {
+            Ok(distribution) => Some(children[distribution.sample(&mut self.rng)].chess_move),
+            Err(_) => children.choose(&mut self.rng).map(|edge| edge.chess_move),
+        }
+    }
+
+    /// Moves the root of the tree down to the child reached by `chess_move`.
+    ///
+    /// The statistics gathered for the subtree under that move are kept so the
+    /// next search can build on them, the rest of the tree is dropped. If the
+    /// move is not in the tree the whole tree is discarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `chess_move` - The move that was played in the root position.
+    pub fn advance(&mut self, chess_move: &ChessMoveType) {
+        let children = std::mem::take(&mut self.root.children);
+        let next_root = children
+            .into_iter()
+            .find(|edge| edge.chess_move == *chess_move)
+            .map(|edge| edge.node);
+
+        match (next_root, self.root_game.as_mut()) {
+            (Some(node), Some(game)) => {
+                game.make_move(*chess_move);
+                self.root = node;
+            }
+            _ => {
+                self.root = Node::new();
+                self.root_game = None;
+            }
+        }
+    }
+
+    /// Discards the whole tree.
+    pub fn reset(&mut self) {
+        self.root = Node::new();
+        self.root_game = None;
+    }
+
+    fn noisy_root_priors(&mut self) -> Option<Vec<f32>> {
+        let children = &self.root.children;
+        if self.config.dirichlet_epsilon <= 0.0 || children.len() < 2 {
+            return None;
+        }
+
+        let noise = sample_dirichlet(self.config.dirichlet_alpha, children.len(), &mut self.rng)?;
+        let epsilon = self.config.dirichlet_epsilon;
+        Some(
+            children
+                .iter()
+                .zip(noise)
+                .map(|(edge, noise)| (1.0 - epsilon) * edge.prior + epsilon * noise)
+                .collect(),
+        )
+    }
+}
+
+/// Runs a single simulation through `node`.
+///
+/// Returns the value of the position in `game` from the perspective of the
+/// player whose turn it is, every node stores its value from the perspective
+/// of the player that moved into it.
+fn visit<E: PolicyValueFn<V>, V: Variant>(
+    node: &mut Node,
+    game: &mut ChessGame<V>,
+    evaluator: &mut E,
+    config: &MctsConfig,
+    root_priors: Option<&[f32]>,
+) -> f32 {
+    let value = if let Some(terminal_value) = node.terminal_value {
+        terminal_value
+    } else if !node.expanded {
+        expand(node, game, evaluator)
+    } else {
+        let index = select_child(node, config.c_puct, root_priors);
+        let edge = &mut node.children[index];
+        game.make_move(edge.chess_move);
+        -visit(&mut edge.node, game, evaluator, config, None)
+    };
+
+    node.visit_count += 1;
+    node.value_sum -= value;
+    value
+}
+
+fn expand<E: PolicyValueFn<V>, V: Variant>(
+    node: &mut Node,
+    game: &mut ChessGame<V>,
+    evaluator: &mut E,
+) -> f32 {
+    if game.can_claim_draw().is_some() {
+        node.terminal_value = Some(0.0);
+        return 0.0;
+    }
+
+    let legal_moves = match game.get_game_state() {
+        GameState::InProgress { legal_moves, .. } => legal_moves,
+        GameState::Check { legal_moves, .. } => legal_moves,
+        GameState::Checkmate { .. } => {
+            node.terminal_value = Some(-1.0);
+            return -1.0;
+        }
+        GameState::Stalemate | GameState::VariantDraw => {
+            node.terminal_value = Some(0.0);
+            return 0.0;
+        }
+        GameState::VariantWin { winner } => {
+            let value = if winner == game.get_current_players_turn() {
+                1.0
+            } else {
+                -1.0
+            };
+            node.terminal_value = Some(value);
+            return value;
+        }
+    };
+
+    let (priors, value) = evaluator.evaluate(game, &legal_moves);
+    let priors = normalize_priors(priors, legal_moves.len());
+
+    node.children = legal_moves
+        .into_iter()
+        .zip(priors)
+        .map(|(chess_move, prior)| Edge {
+            chess_move,
+            prior,
+            node: Node::new(),
+        })
+        .collect();
+    node.expanded = true;
+
+    value.clamp(-1.0, 1.0)
+}
+
+fn select_child(node: &Node, c_puct: f32, root_priors: Option<&[f32]>) -> usize {
+    let parent_visits = (node.visit_count.max(1) as f32).sqrt();
+
+    let mut best_index = 0;
+    let mut best_score = f32::NEG_INFINITY;
+    for (index, edge) in node.children.iter().enumerate() {
+        let prior = root_priors.map_or(edge.prior, |priors| priors[index]);
+        let exploration = c_puct * prior * parent_visits / (1.0 + edge.node.visit_count as f32);
+        let score = edge.node.mean_value() + exploration;
+        if score > best_score {
+            best_score = score;
+            best_index = index;
+        }
+    }
+    best_index
+}
+
+fn normalize_priors(priors: Vec<f32>, move_count: usize) -> Vec<f32> {
+    let uniform = 1.0 / move_count as f32;
+    if priors.len() != move_count {
+        return vec![uniform; move_count];
+    }
+
+    let priors: Vec<f32> = priors
+        .into_iter()
+        .map(|p| if p.is_finite() { p.max(0.0) } else { 0.0 })
+        .collect();
+    let total: f32 = priors.iter().sum();
+    if total <= 0.0 {
+        vec![uniform; move_count]
+    } else {
+        priors.into_iter().map(|p| p / total).collect()
+    }
+}
+
+fn sample_dirichlet(alpha: f32, size: usize, rng: &mut StdRng) -> Option<Vec<f32>> {
+    Dirichlet::new_with_size(alpha, size)
+        .ok()
+        .map(|dirichlet| dirichlet.sample(rng))
+}
+
+/// A `PolicyValueFn` that needs no network.
+///
+/// Every legal move gets the same prior, and the value of a position is the
+/// result of playing random moves from it until the game ends or
+/// `max_rollout_moves` moves have been made, in which case it counts as a draw.
+pub struct UniformRandomRollout {
+    rng: StdRng,
+    max_rollout_moves: usize,
+}
+
+impl UniformRandomRollout {
+    /// Creates a new random rollout evaluator.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Seed for the random moves of the rollouts.
+    /// * `max_rollout_moves` - The maximum number of half moves played in a rollout.
+    pub fn new(seed: u64, max_rollout_moves: usize) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            max_rollout_moves,
+        }
+    }
+}
+
+impl<V: Variant> PolicyValueFn<V> for UniformRandomRollout {
+    fn evaluate(&mut self, game: &ChessGame<V>, legal_moves: &[ChessMoveType]) -> (Vec<f32>, f32) {
+        let priors = vec![1.0 / legal_moves.len() as f32; legal_moves.len()];
+
+        let player = game.get_current_players_turn();
+        let mut rollout = game.clone();
+        let mut value = 0.0;
+        for _ in 0..self.max_rollout_moves {
+            if rollout.can_claim_draw().is_some() {
+                break;
+            }
+            let moves = match rollout.get_game_state() {
+                GameState::InProgress { legal_moves, .. } => legal_moves,
+                GameState::Check { legal_moves, .. } => legal_moves,
+                GameState::Checkmate { winner } | GameState::VariantWin { winner } => {
+                    value = if winner == player { 1.0 } else { -1.0 };
+                    break;
+                }
+                GameState::Stalemate | GameState::VariantDraw => break,
+            };
+            rollout.make_move(*moves.choose(&mut self.rng).unwrap());
+        }
+
+        (priors, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::build_game_from_string;
+    use crate::piece::ChessPiece;
+    use crate::piece::PieceType::Queen;
+    use crate::Color::Black;
+
+    fn config(simulations: usize) -> MctsConfig {
+        MctsConfig {
+            simulations,
+            ..MctsConfig::default()
+        }
+    }
+
+    #[test]
+    fn policy_covers_every_legal_move_and_sums_to_one() {
+        let game = ChessGame::new();
+        let mut mcts = Mcts::new(UniformRandomRollout::new(1, 8), config(60), 1);
+
+        let policy = mcts.search(&game);
+        assert_eq!(20, policy.len());
+
+        let total: f32 = policy.iter().map(|(_, share)| share).sum();
+        assert!((total - 1.0).abs() < 1e-4);
+        assert_eq!(61, mcts.root_visit_count());
+    }
+
+    #[test]
+    fn finds_mate_in_one() {
+        let game = build_game_from_string("k7/pp6/8/2q5/8/8/P7/K7 b - - 0 1").unwrap();
+        let mut mcts = Mcts::new(UniformRandomRollout::new(2, 6), config(400), 2);
+
+        mcts.search(&game);
+        let best_move = mcts.pick_move(0.0).unwrap();
+
+        let expected_move = ChessMoveType::Move {
+            original_position: (2, 4),
+            new_position: (2, 0),
+            piece: ChessPiece::new(Queen, Black),
+            taken_piece: None,
+            promotion: None,
+        };
+        assert_eq!(expected_move, best_move);
+        assert!(mcts.root_value() > 0.5);
+    }
+
+    #[test]
+    fn tree_is_reused_after_advancing() {
+        let mut game = ChessGame::new();
+        let mut mcts = Mcts::new(UniformRandomRollout::new(3, 4), config(80), 3);
+
+        mcts.search(&game);
+        let chess_move = mcts.pick_move(0.0).unwrap();
+        let child_visits = mcts
+            .root
+            .children
+            .iter()
+            .find(|edge| edge.chess_move == chess_move)
+            .unwrap()
+            .node
+            .visit_count;
+
+        game.make_move(chess_move);
+        mcts.advance(&chess_move);
+        assert_eq!(child_visits, mcts.root_visit_count());
+
+        mcts.search(&game);
+        assert_eq!(child_visits + 80, mcts.root_visit_count());
+    }
+
+    #[test]
+    fn searching_a_different_position_discards_the_tree() {
+        let mut mcts = Mcts::new(UniformRandomRollout::new(4, 4), config(20), 4);
+        mcts.search(&ChessGame::new());
+
+        let game = build_game_from_string("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").unwrap();
+        mcts.search(&game);
+        assert_eq!(21, mcts.root_visit_count());
+    }
+
+    #[test]
+    fn searches_with_the_same_seed_are_identical() {
+        let game = ChessGame::new();
+        let mut first = Mcts::new(UniformRandomRollout::new(5, 6), config(40), 5);
+        let mut second = Mcts::new(UniformRandomRollout::new(5, 6), config(40), 5);
+
+        assert_eq!(first.search(&game), second.search(&game));
+        assert_eq!(first.pick_move(1.0), second.pick_move(1.0));
+    }
+
+    #[test]
+    fn tiny_temperature_picks_the_most_visited_move() {
+        let game = build_game_from_string("k7/pp6/8/2q5/8/8/P7/K7 b - - 0 1").unwrap();
+        let mut mcts = Mcts::new(UniformRandomRollout::new(2, 6), config(400), 2);
+
+        mcts.search(&game);
+        let most_visited = mcts.pick_move(0.0).unwrap();
+        for _ in 0..10 {
+            assert_eq!(Some(most_visited), mcts.pick_move(0.001));
+        }
+    }
+
+    #[test]
+    fn game_that_is_over_has_no_moves_to_pick() {
+        let game = build_game_from_string("k6R/pp6/8/8/8/8/8/8 b - - 0 1").unwrap();
+        let mut mcts = Mcts::new(UniformRandomRollout::new(6, 4), config(10), 6);
+
+        assert!(mcts.search(&game).is_empty());
+        assert!(mcts.pick_move(0.0).is_none());
+        assert_eq!(-1.0, mcts.root_value());
+    }
+
+    #[test]
+    fn dirichlet_noise_is_a_probability_distribution() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let noise = sample_dirichlet(0.3, 20, &mut rng).unwrap();
+
+        assert_eq!(20, noise.len());
+        assert!(noise.iter().all(|n| *n >= 0.0));
+        assert!((noise.iter().sum::<f32>() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn invalid_priors_fall_back_to_uniform() {
+        assert_eq!(vec![0.5, 0.5], normalize_priors(vec![0.0, 0.0], 2));
+        assert_eq!(vec![0.5, 0.5], normalize_priors(vec![1.0], 2));
+        assert_eq!(vec![0.25, 0.75], normalize_priors(vec![1.0, 3.0], 2));
+        assert_eq!(vec![0.0, 1.0], normalize_priors(vec![f32::NAN, 3.0], 2));
+    }
+}
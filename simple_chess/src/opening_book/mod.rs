@@ -0,0 +1,566 @@
+//! Opening books in the Polyglot `.bin` format.
+//!
+//! A Polyglot book is a list of 16 byte entries sorted by the Zobrist key of
+//! the position they are for. Each entry holds a move and a weight, moves with
+//! a larger weight are played more often. The keys use the random numbers of
+//! the published book format, so books made by other programs can be read.
+//!
+//! # Examples
+//!
+//! ```
+//! use simple_chess::opening_book::{get_polyglot_key, OpeningBook};
+//! use simple_chess::ChessGame;
+//!
+//! let game = ChessGame::new();
+//! assert_eq!(Some(0x463B96181691FC9C), get_polyglot_key(&game));
+//!
+//! let book = OpeningBook::from_bytes(&[]).unwrap();
+//! assert_eq!(None, book.get_best_move(&game));
+//! ```
+
+mod random;
+
+use crate::chess_game_state_analyzer::GameState;
+use crate::piece::PieceType;
+use crate::variant::Variant;
+use crate::ChessMoveType::{Castle, EnPassant, Move};
+use crate::{ChessGame, ChessMoveType, Color};
+use rand::Rng;
+use random::{RANDOM64, RANDOM_CASTLE, RANDOM_EN_PASSANT, RANDOM_PIECE, RANDOM_TURN};
+use std::path::Path;
+
+const ENTRY_SIZE: usize = 16;
+const PROMOTIONS: [PieceType; 4] = [
+    PieceType::Knight,
+    PieceType::Bishop,
+    PieceType::Rook,
+    PieceType::Queen,
+];
+
+/// A move stored in a Polyglot book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BookEntry {
+    /// The Polyglot key of the position the move is played in.
+    pub key: u64,
+    /// The move, with the squares packed into 3 bit fields as in the book
+    /// format. Castling is stored as the king taking its own rook.
+    pub chess_move: u16,
+    /// How often the move should be played compared to the other moves for
+    /// the same position. Moves with a weight of 0 are never chosen.
+    pub weight: u16,
+    /// Learning data written by some book tools, not used when reading.
+    pub learn: u32,
+}
+
+impl BookEntry {
+    /// The square the move starts from, as (column, row).
+    pub fn get_original_position(&self) -> (usize, usize) {
+        (
+            ((self.chess_move >> 6) & 7) as usize,
+            ((self.chess_move >> 9) & 7) as usize,
+        )
+    }
+
+    /// The square the move ends on, as (column, row). For castling this is
+    /// the square of the rook.
+    pub fn get_new_position(&self) -> (usize, usize) {
+        (
+            (self.chess_move & 7) as usize,
+            ((self.chess_move >> 3) & 7) as usize,
+        )
+    }
+
+    /// The piece a pawn is promoted to, if any.
+    pub fn get_promotion(&self) -> Option<PieceType> {
+        match (self.chess_move >> 12) & 7 {
+            0 => None,
+            promotion => PROMOTIONS.get(promotion as usize - 1).copied(),
+        }
+    }
+
+    /// Finds the legal move of the game this entry describes.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game the entry is for.
+    ///
+    /// # Returns
+    ///
+    /// The matching `ChessMoveType`, `None` if the entry is not a legal move
+    /// in the position, as can happen with a corrupt book or a key collision.
+    pub fn to_chess_move<V: Variant>(&self, game: &ChessGame<V>) -> Option<ChessMoveType> {
+        self.find_legal_move(&get_legal_moves(game))
+    }
+
+    fn find_legal_move(&self, legal_moves: &[ChessMoveType]) -> Option<ChessMoveType> {
+        let from = self.get_original_position();
+        let to = self.get_new_position();
+        let promotion = self.get_promotion();
+        legal_moves
+            .iter()
+            .copied()
+            .find(|chess_move| match chess_move {
+                Move {
+                    original_position,
+                    new_position,
+                    promotion: promoted_piece,
+                    ..
+                }
+                | EnPassant {
+                    original_position,
+                    new_position,
+                    promotion: promoted_piece,
+                    ..
+                } => {
+                    *original_position == from
+                        && *new_position == to
+                        && promoted_piece.map(|piece| piece.get_piece_type()) == promotion
+                }
+                Castle {
+                    king_original_position,
+                    rook_original_position,
+                    ..
+                } => {
+                    *king_original_position == from
+                        && *rook_original_position == to
+                        && promotion.is_none()
+                }
+                ChessMoveType::Drop { .. } => false,
+            })
+    }
+}
+
+/// The legal moves of a game, worked out on a copy so lookups can take the
+/// game by reference.
+fn get_legal_moves<V: Variant>(game: &ChessGame<V>) -> Vec<ChessMoveType> {
+    match game.clone().get_game_state() {
+        GameState::InProgress { legal_moves, .. } | GameState::Check { legal_moves, .. } => {
+            legal_moves
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Computes the Polyglot Zobrist key of the position of a game.
+///
+/// The key combines the pieces on the board, the castling rights, the side to
+/// move and the en passant file. As in the book format the en passant file
+/// only counts when a pawn of the side to move stands next to the pawn that
+/// has just moved two squares, whether or not taking it is legal.
+///
+/// # Arguments
+///
+/// * `game` - The game whose current position is keyed.
+///
+/// # Returns
+///
+/// The key, `None` if the board is not 8x8 or has pieces that are not in
+/// standard chess.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::codec::forsyth_edwards_notation::build_game_from_string;
+/// use simple_chess::opening_book::get_polyglot_key;
+///
+/// let game = build_game_from_string(
+///     "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(Some(0x823C9B50FD114196), get_polyglot_key(&game));
+/// ```
+pub fn get_polyglot_key<V: Variant>(game: &ChessGame<V>) -> Option<u64> {
+    let board = game.get_board();
+    if board.get_width() != 8 || board.get_height() != 8 {
+        return None;
+    }
+
+    let mut key = 0;
+    for (col, row, piece) in board.iter_pieces() {
+        let kind = match piece.get_piece_type() {
+            PieceType::Pawn => 0,
+            PieceType::Knight => 1,
+            PieceType::Bishop => 2,
+            PieceType::Rook => 3,
+            PieceType::Queen => 4,
+            PieceType::King => 5,
+            _ => return None,
+        };
+        let kind = kind * 2 + usize::from(piece.get_color() == Color::White);
+        key ^= RANDOM64[RANDOM_PIECE + 64 * kind + 8 * row + col];
+    }
+
+    let (white_long, white_short, black_long, black_short) = game.get_castling_rights();
+    for (index, can_castle) in [white_short, white_long, black_short, black_long]
+        .into_iter()
+        .enumerate()
+    {
+        if can_castle {
+            key ^= RANDOM64[RANDOM_CASTLE + index];
+        }
+    }
+
+    if let Some(col) = get_en_passant_column(game) {
+        key ^= RANDOM64[RANDOM_EN_PASSANT + col];
+    }
+
+    if game.get_current_players_turn() == Color::White {
+        key ^= RANDOM64[RANDOM_TURN];
+    }
+    Some(key)
+}
+
+/// The column of a pawn that has just moved two squares, if a pawn of the
+/// side to move stands next to it.
+fn get_en_passant_column<V: Variant>(game: &ChessGame<V>) -> Option<usize> {
+    let Some(Move {
+        original_position,
+        new_position: (col, row),
+        piece,
+        ..
+    }) = game.get_last_move()
+    else {
+        return None;
+    };
+    if piece.get_piece_type() != PieceType::Pawn || original_position.1.abs_diff(*row) != 2 {
+        return None;
+    }
+
+    let turn = game.get_current_players_turn();
+    let board = game.get_board();
+    let is_capturing_pawn = |col: usize| {
+        board.get_piece_at_space(col, *row).is_some_and(|piece| {
+            piece.get_piece_type() == PieceType::Pawn && piece.get_color() == turn
+        })
+    };
+    let has_capturing_pawn =
+        (*col > 0 && is_capturing_pawn(col - 1)) || (*col < 7 && is_capturing_pawn(col + 1));
+    has_capturing_pawn.then_some(*col)
+}
+
+/// The entries of a Polyglot opening book, sorted by key.
+#[derive(Debug, Clone, Default)]
+pub struct OpeningBook {
+    entries: Vec<BookEntry>,
+}
+
+impl OpeningBook {
+    /// Reads a book from the contents of a Polyglot `.bin` file.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The entries of the book, 16 big-endian bytes each.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(OpeningBook)` with the entries sorted by key, keeping the order
+    ///   of entries with the same key.
+    /// - `Err(OpeningBookError)` if the length is not a whole number of
+    ///   entries.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OpeningBookError> {
+        if !bytes.len().is_multiple_of(ENTRY_SIZE) {
+            return Err(OpeningBookError {
+                reason: format!(
+                    "A Polyglot book is made of {} byte entries, got {} bytes",
+                    ENTRY_SIZE,
+                    bytes.len()
+                ),
+            });
+        }
+
+        let mut entries: Vec<BookEntry> = bytes
+            .chunks_exact(ENTRY_SIZE)
+            .map(|entry| BookEntry {
+                key: u64::from_be_bytes(entry[0..8].try_into().unwrap()),
+                chess_move: u16::from_be_bytes(entry[8..10].try_into().unwrap()),
+                weight: u16::from_be_bytes(entry[10..12].try_into().unwrap()),
+                learn: u32::from_be_bytes(entry[12..16].try_into().unwrap()),
+            })
+            .collect();
+        entries.sort_by_key(|entry| entry.key);
+        Ok(Self { entries })
+    }
+
+    /// Reads a Polyglot `.bin` file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the book.
+    ///
+    /// # Returns
+    ///
+    /// `Err(OpeningBookError)` if the file can not be read or is not a book.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, OpeningBookError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|error| OpeningBookError {
+            reason: format!("Unable to read {}: {}", path.display(), error),
+        })?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// The number of entries in the book.
+    pub fn get_len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The entries stored for a key, in the order of the book.
+    pub fn get_entries(&self, key: u64) -> &[BookEntry] {
+        let start = self.entries.partition_point(|entry| entry.key < key);
+        let end = self.entries.partition_point(|entry| entry.key <= key);
+        &self.entries[start..end]
+    }
+
+    /// The book moves for the current position of a game, with their weights.
+    ///
+    /// Entries that are not legal moves in the position are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game to look up.
+    ///
+    /// # Returns
+    ///
+    /// The legal book moves and their weights, in the order of the book.
+    pub fn get_moves<V: Variant>(&self, game: &ChessGame<V>) -> Vec<(ChessMoveType, u16)> {
+        let Some(key) = get_polyglot_key(game) else {
+            return Vec::new();
+        };
+        let entries = self.get_entries(key);
+        if entries.is_empty() {
+            return Vec::new();
+        }
+        let legal_moves = get_legal_moves(game);
+        entries
+            .iter()
+            .filter_map(|entry| Some((entry.find_legal_move(&legal_moves)?, entry.weight)))
+            .collect()
+    }
+
+    /// The book move with the largest weight, the first in the book on ties.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game to look up.
+    ///
+    /// # Returns
+    ///
+    /// The move, `None` if the position is not in the book.
+    pub fn get_best_move<V: Variant>(&self, game: &ChessGame<V>) -> Option<ChessMoveType> {
+        self.get_moves(game)
+            .into_iter()
+            .rev()
+            .max_by_key(|(_, weight)| *weight)
+            .map(|(chess_move, _)| chess_move)
+    }
+
+    /// Picks a book move at random, in proportion to the weights.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game to look up.
+    /// * `rng` - The source of randomness.
+    ///
+    /// # Returns
+    ///
+    /// The move, `None` if the position is not in the book or all its moves
+    /// have a weight of 0.
+    pub fn get_weighted_random_move<V: Variant>(
+        &self,
+        game: &ChessGame<V>,
+        rng: &mut impl Rng,
+    ) -> Option<ChessMoveType> {
+        let moves = self.get_moves(game);
+        let total: u32 = moves.iter().map(|(_, weight)| u32::from(*weight)).sum();
+        if total == 0 {
+            return None;
+        }
+
+        let mut pick = rng.gen_range(0..total);
+        for (chess_move, weight) in moves {
+            if pick < u32::from(weight) {
+                return Some(chess_move);
+            }
+            pick -= u32::from(weight);
+        }
+        None
+    }
+}
+
+#[derive(Debug)]
+pub struct OpeningBookError {
+    pub reason: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::build_game_from_string;
+    use crate::codec::universal_chess_interface::{decode_move_from_uci, encode_move_as_uci};
+    use crate::variant::Capablanca;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    /// Written by `tests/fixtures/make_small_book.py`.
+    const SMALL_BOOK: &[u8] = include_bytes!("../../tests/fixtures/small_book.bin");
+
+    fn play(uci_moves: &[&str]) -> ChessGame {
+        let mut game = ChessGame::new();
+        for uci in uci_moves {
+            game.make_move(decode_move_from_uci(&game, uci).unwrap());
+        }
+        game
+    }
+
+    fn uci_moves(moves: Vec<(ChessMoveType, u16)>) -> Vec<(String, u16)> {
+        moves
+            .into_iter()
+            .map(|(chess_move, weight)| (encode_move_as_uci(&chess_move), weight))
+            .collect()
+    }
+
+    #[test]
+    fn keys_match_the_published_values() {
+        let positions = [
+            (
+                "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+                0x463B96181691FC9C,
+            ),
+            (
+                "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1",
+                0x823C9B50FD114196,
+            ),
+            (
+                "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2",
+                0x0756B94461C50FB0,
+            ),
+            (
+                "rnbqkbnr/ppp1pppp/8/3pP3/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 2",
+                0x662FAFB965DB29D4,
+            ),
+            (
+                "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+                0x22A48B5A8E47FF78,
+            ),
+            (
+                "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR b kq - 1 3",
+                0x652A607CA3F242C1,
+            ),
+            (
+                "rnbq1bnr/ppp1pkpp/8/3pPp2/8/8/PPPPKPPP/RNBQ1BNR w - - 2 4",
+                0x00FDD303C946BDD9,
+            ),
+            (
+                "rnbqkbnr/p1pppppp/8/8/PpP4P/8/1P1PPPP1/RNBQKBNR b KQkq c3 0 3",
+                0x3C8123EA7B067637,
+            ),
+            (
+                "rnbqkbnr/p1pppppp/8/8/P6P/R1p5/1P1PPPP1/1NBQKBNR b Kkq - 1 4",
+                0x5C3F9B829B279560,
+            ),
+        ];
+
+        for (fen, key) in positions {
+            let game = build_game_from_string(fen).unwrap();
+            assert_eq!(Some(key), get_polyglot_key(&game), "{}", fen);
+        }
+    }
+
+    #[test]
+    fn keys_follow_the_moves_played() {
+        let game = play(&["e2e4", "d7d5", "e4e5", "f7f5"]);
+        assert_eq!(Some(0x22A48B5A8E47FF78), get_polyglot_key(&game));
+
+        let game = play(&["e2e4", "d7d5", "e4e5", "f7f5", "e1e2", "e8f7"]);
+        assert_eq!(Some(0x00FDD303C946BDD9), get_polyglot_key(&game));
+
+        assert_eq!(None, get_polyglot_key(&ChessGame::new_variant(Capablanca)));
+    }
+
+    #[test]
+    fn book_moves_are_looked_up_and_illegal_entries_skipped() {
+        let book = OpeningBook::from_bytes(SMALL_BOOK).unwrap();
+        assert_eq!(8, book.get_len());
+        assert_eq!(4, book.get_entries(0x463B96181691FC9C).len());
+
+        assert_eq!(
+            vec![
+                (String::from("e2e4"), 10),
+                (String::from("d2d4"), 5),
+                (String::from("g1f3"), 1)
+            ],
+            uci_moves(book.get_moves(&ChessGame::new()))
+        );
+        assert_eq!(
+            vec![(String::from("e4d5"), 1)],
+            uci_moves(book.get_moves(&play(&["e2e4", "d7d5"])))
+        );
+        assert!(book.get_moves(&play(&["d2d4"])).is_empty());
+    }
+
+    #[test]
+    fn best_move_takes_the_first_of_the_heaviest() {
+        let book = OpeningBook::from_bytes(SMALL_BOOK).unwrap();
+        let best = |game: &ChessGame| book.get_best_move(game).map(|m| encode_move_as_uci(&m));
+
+        assert_eq!(Some(String::from("e2e4")), best(&ChessGame::new()));
+        assert_eq!(Some(String::from("c7c5")), best(&play(&["e2e4"])));
+        assert_eq!(None, best(&play(&["g1f3"])));
+    }
+
+    #[test]
+    fn random_moves_follow_the_weights() {
+        let book = OpeningBook::from_bytes(SMALL_BOOK).unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        let game = ChessGame::new();
+
+        let mut counts = [0; 3];
+        for _ in 0..1600 {
+            let chess_move = book.get_weighted_random_move(&game, &mut rng).unwrap();
+            match encode_move_as_uci(&chess_move).as_str() {
+                "e2e4" => counts[0] += 1,
+                "d2d4" => counts[1] += 1,
+                "g1f3" => counts[2] += 1,
+                other => panic!("{} is not a book move", other),
+            }
+        }
+        assert!((900..1100).contains(&counts[0]), "{:?}", counts);
+        assert!((400..600).contains(&counts[1]), "{:?}", counts);
+        assert!((50..150).contains(&counts[2]), "{:?}", counts);
+
+        let after_e4 = play(&["e2e4"]);
+        for _ in 0..100 {
+            let chess_move = book.get_weighted_random_move(&after_e4, &mut rng).unwrap();
+            assert_ne!("d7d5", encode_move_as_uci(&chess_move));
+        }
+    }
+
+    #[test]
+    fn castling_and_promotion_entries_are_decoded() {
+        let game = build_game_from_string("4k3/1P6/8/8/8/8/8/R3K2R w KQ - 0 1").unwrap();
+        let key = get_polyglot_key(&game).unwrap();
+        let entry = |chess_move: u16| {
+            let mut bytes = key.to_be_bytes().to_vec();
+            bytes.extend(chess_move.to_be_bytes());
+            bytes.extend([0, 1, 0, 0, 0, 0]);
+            bytes
+        };
+        // e1h1, e1a1, b7b8 promoting to a knight and to a queen.
+        let bytes = [0x0107, 0x0100, 0x1C79, 0x4C79].map(entry).concat();
+        let book = OpeningBook::from_bytes(&bytes).unwrap();
+
+        assert_eq!(
+            vec!["e1g1", "e1c1", "b7b8n", "b7b8q"],
+            book.get_moves(&game)
+                .iter()
+                .map(|(chess_move, _)| encode_move_as_uci(chess_move))
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn truncated_books_are_rejected() {
+        assert!(OpeningBook::from_bytes(&SMALL_BOOK[..20]).is_err());
+        assert!(OpeningBook::open("no/such/book.bin").is_err());
+    }
+}
@@ -0,0 +1,100 @@
+use crate::chess_move::ChessMoveType;
+use crate::piece::{bishop, knight, ChessPiece, PieceType};
+use crate::Color;
+use game_board::Board;
+
+pub fn as_utf_str(color: Color) -> &'static str {
+    match color {
+        Color::White => "🩐",
+        Color::Black => "🩓",
+    }
+}
+
+/// The archbishop moves like a bishop or like a knight.
+pub fn possible_moves(
+    color: Color,
+    position: (usize, usize),
+    board: &Board<ChessPiece>,
+) -> Vec<ChessMoveType> {
+    let mut possible_moves = bishop::possible_moves(color, position, board);
+    possible_moves.append(&mut knight::possible_moves(color, position, board));
+
+    possible_moves
+        .into_iter()
+        .map(|possible_move| match possible_move {
+            ChessMoveType::Move {
+                original_position,
+                new_position,
+                taken_piece,
+                promotion,
+                ..
+            } => ChessMoveType::Move {
+                original_position,
+                new_position,
+                piece: ChessPiece::new(PieceType::Archbishop, color),
+                taken_piece,
+                promotion,
+            },
+            _ => possible_move,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::build_game_from_string;
+    use crate::piece::PieceType::{Archbishop, Pawn};
+    use crate::ChessMoveType::Move;
+    use crate::Color::{Black, White};
+
+    #[test]
+    fn archbishop_moves_like_a_bishop_and_a_knight() {
+        let white_archbishop = ChessPiece::new(Archbishop, White);
+        let game = build_game_from_string("8/8/8/8/3A4/8/8/8 w - - 0 1").unwrap();
+        let board = game.get_board();
+
+        let moves = white_archbishop.possible_moves((3, 3), board, None);
+        assert_eq!(21, moves.len());
+
+        [(0, 0), (7, 7), (0, 6), (6, 0), (1, 2), (4, 5), (5, 4)]
+            .into_iter()
+            .for_each(|new_position| {
+                let expected_move = Move {
+                    original_position: (3, 3),
+                    new_position,
+                    piece: ChessPiece::new(Archbishop, White),
+                    taken_piece: None,
+                    promotion: None,
+                };
+                assert!(moves.contains(&expected_move));
+            });
+    }
+
+    #[test]
+    fn archbishop_can_take() {
+        let black_archbishop = ChessPiece::new(Archbishop, Black);
+        let game = build_game_from_string("8/8/8/4P3/3a4/8/1P6/8 w - - 0 1").unwrap();
+        let board = game.get_board();
+
+        let moves = black_archbishop.possible_moves((3, 3), board, None);
+
+        [(4, 4), (1, 1)].into_iter().for_each(|new_position| {
+            let expected_move = Move {
+                original_position: (3, 3),
+                new_position,
+                piece: ChessPiece::new(Archbishop, Black),
+                taken_piece: Some(ChessPiece::new(Pawn, White)),
+                promotion: None,
+            };
+            assert!(moves.contains(&expected_move));
+        });
+        assert!(!moves.iter().any(|possible_move| matches!(
+            possible_move,
+            Move {
+                new_position: (5, 5),
+                ..
+            }
+        )));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::chess_move::ChessMoveType;
-use crate::piece::{ChessPiece, PieceType};
+use crate::piece::{sliding_moves, ChessPiece, PieceType};
 use crate::Color;
-use game_board::Board;
+use game_board::{Board, DIAGONAL_DIRECTIONS};
 
 pub fn as_utf_str(color: Color) -> &'static str {
     match color {
@@ -15,42 +15,12 @@ pub fn possible_moves(
     position: (usize, usize),
     board: &Board<ChessPiece>,
 ) -> Vec<ChessMoveType> {
-    let mut possible_moves: Vec<ChessMoveType> = Vec::new();
-
-    let directions = [(1i32, 1), (1, -1), (-1, 1), (-1, -1)];
-
-    for dir in directions.iter() {
-        let mut x = position.0 as i32 + dir.0;
-        let mut y = position.1 as i32 + dir.1;
-        while x >= 0 && y >= 0 && x < board.get_width() as i32 && y < board.get_height() as i32 {
-            if let Some(piece) = board.get_piece_at_space(x as usize, y as usize) {
-                if piece.get_color() != color {
-                    possible_moves.push(ChessMoveType::Move {
-                        original_position: position,
-                        new_position: (x as usize, y as usize),
-                        piece: ChessPiece::new(PieceType::Bishop, color),
-                        taken_piece: Some(*piece),
-                        promotion: None,
-                    });
-                    break;
-                } else {
-                    break;
-                }
-            } else {
-                possible_moves.push(ChessMoveType::Move {
-                    original_position: position,
-                    new_position: (x as usize, y as usize),
-                    piece: ChessPiece::new(PieceType::Bishop, color),
-                    taken_piece: None,
-                    promotion: None,
-                });
-            }
-            x += dir.0;
-            y += dir.1;
-        }
-    }
-
-    possible_moves
+    sliding_moves(
+        ChessPiece::new(PieceType::Bishop, color),
+        position,
+        board,
+        &DIAGONAL_DIRECTIONS,
+    )
 }
 
 #[cfg(test)]
@@ -0,0 +1,100 @@
+use crate::chess_move::ChessMoveType;
+use crate::piece::{knight, rook, ChessPiece, PieceType};
+use crate::Color;
+use game_board::Board;
+
+pub fn as_utf_str(color: Color) -> &'static str {
+    match color {
+        Color::White => "🩏",
+        Color::Black => "🩒",
+    }
+}
+
+/// The chancellor moves like a rook or like a knight.
+pub fn possible_moves(
+    color: Color,
+    position: (usize, usize),
+    board: &Board<ChessPiece>,
+) -> Vec<ChessMoveType> {
+    let mut possible_moves = rook::possible_moves(color, position, board);
+    possible_moves.append(&mut knight::possible_moves(color, position, board));
+
+    possible_moves
+        .into_iter()
+        .map(|possible_move| match possible_move {
+            ChessMoveType::Move {
+                original_position,
+                new_position,
+                taken_piece,
+                promotion,
+                ..
+            } => ChessMoveType::Move {
+                original_position,
+                new_position,
+                piece: ChessPiece::new(PieceType::Chancellor, color),
+                taken_piece,
+                promotion,
+            },
+            _ => possible_move,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::build_game_from_string;
+    use crate::piece::PieceType::{Chancellor, Pawn};
+    use crate::ChessMoveType::Move;
+    use crate::Color::{Black, White};
+
+    #[test]
+    fn chancellor_moves_like_a_rook_and_a_knight() {
+        let white_chancellor = ChessPiece::new(Chancellor, White);
+        let game = build_game_from_string("8/8/8/8/3C4/8/8/8 w - - 0 1").unwrap();
+        let board = game.get_board();
+
+        let moves = white_chancellor.possible_moves((3, 3), board, None);
+        assert_eq!(22, moves.len());
+
+        [(3, 0), (3, 7), (0, 3), (7, 3), (1, 2), (4, 5), (5, 4)]
+            .into_iter()
+            .for_each(|new_position| {
+                let expected_move = Move {
+                    original_position: (3, 3),
+                    new_position,
+                    piece: ChessPiece::new(Chancellor, White),
+                    taken_piece: None,
+                    promotion: None,
+                };
+                assert!(moves.contains(&expected_move));
+            });
+    }
+
+    #[test]
+    fn chancellor_can_take() {
+        let black_chancellor = ChessPiece::new(Chancellor, Black);
+        let game = build_game_from_string("8/8/3P4/8/3c4/8/2P5/8 w - - 0 1").unwrap();
+        let board = game.get_board();
+
+        let moves = black_chancellor.possible_moves((3, 3), board, None);
+
+        [(3, 5), (2, 1)].into_iter().for_each(|new_position| {
+            let expected_move = Move {
+                original_position: (3, 3),
+                new_position,
+                piece: ChessPiece::new(Chancellor, Black),
+                taken_piece: Some(ChessPiece::new(Pawn, White)),
+                promotion: None,
+            };
+            assert!(moves.contains(&expected_move));
+        });
+        assert!(!moves.iter().any(|possible_move| matches!(
+            possible_move,
+            Move {
+                new_position: (3, 6),
+                ..
+            }
+        )));
+    }
+}
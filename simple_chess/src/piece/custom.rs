@@ -0,0 +1,435 @@
+use crate::chess_move::ChessMoveType;
+use crate::piece::{ChessPiece, PieceType};
+use crate::Color;
+use game_board::{Board, Coord};
+use std::fmt::{Debug, Formatter};
+use std::sync::RwLock;
+
+/// The FEN characters of the built-in pieces, which custom pieces can not use.
+const BUILT_IN_FEN_CHARS: [char; 8] = ['P', 'R', 'N', 'B', 'Q', 'K', 'A', 'C'];
+
+/// The most custom pieces that can be registered, so that their ids fit in the
+/// byte the binary board encoding writes for them after the Archbishop and
+/// Chancellor.
+const MAX_CUSTOM_PIECES: usize = u8::MAX as usize - 1;
+
+static CUSTOM_PIECES: RwLock<Vec<&'static dyn PieceMovement>> = RwLock::new(Vec::new());
+
+/// Describes how a piece moves, so pieces that are not built into the crate, such
+/// as fairy pieces, can be played with.
+///
+/// A piece is made up of leaps, which jump straight to a square at an offset, and
+/// rides, which slide in a direction until they hit a piece or the edge of the
+/// board. Offsets and directions are given as `(columns, rows)` from White's side
+/// of the board, and are mirrored vertically for Black.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::piece::{register_custom_piece, ChessPiece, PieceMovement};
+/// use simple_chess::Color;
+/// use game_board::Board;
+///
+/// /// Leaps two squares in a straight line.
+/// struct Dabbaba;
+///
+/// impl PieceMovement for Dabbaba {
+///     fn name(&self) -> &str {
+///         "Dabbaba"
+///     }
+///
+///     fn fen_char(&self) -> char {
+///         'D'
+///     }
+///
+///     fn symbol(&self, color: Color) -> &str {
+///         match color {
+///             Color::White => "D",
+///             Color::Black => "d",
+///         }
+///     }
+///
+///     fn leaper_offsets(&self) -> Vec<(i32, i32)> {
+///         vec![(2, 0), (-2, 0), (0, 2), (0, -2)]
+///     }
+/// }
+///
+/// let dabbaba = register_custom_piece(Dabbaba).unwrap();
+/// let board = Board::build(8, 8).unwrap();
+/// let moves = ChessPiece::new(dabbaba, Color::White).possible_moves((0, 0), &board, None);
+/// assert_eq!(2, moves.len());
+/// ```
+pub trait PieceMovement: Send + Sync {
+    /// The name of the piece.
+    fn name(&self) -> &str;
+
+    /// The letter of the piece in FEN and algebraic notation, uppercase. Black
+    /// pieces are written in lowercase.
+    fn fen_char(&self) -> char;
+
+    /// The symbol shown when the piece is displayed.
+    fn symbol(&self, color: Color) -> &str;
+
+    /// The offsets of the squares the piece can jump to, ignoring any pieces in
+    /// between.
+    fn leaper_offsets(&self) -> Vec<(i32, i32)> {
+        Vec::new()
+    }
+
+    /// The directions the piece can slide in, any number of squares.
+    fn rider_directions(&self) -> Vec<(i32, i32)> {
+        Vec::new()
+    }
+
+    /// Whether the piece can only move by taking a piece.
+    fn captures_only(&self) -> bool {
+        false
+    }
+
+    /// Whether the piece can only move to empty squares, and never takes a piece.
+    fn moves_only(&self) -> bool {
+        false
+    }
+
+    /// Whether the piece is royal, so that it can be checked and checkmated like a king.
+    fn is_royal(&self) -> bool {
+        false
+    }
+
+    /// Whether pawns can promote to the piece when it is one of the promotion options.
+    fn can_promote_to(&self) -> bool {
+        true
+    }
+}
+
+/// Identifies a piece registered with `register_custom_piece`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomPieceId(usize);
+
+impl CustomPieceId {
+    /// Get the movement the piece was registered with.
+    pub fn get_movement(&self) -> &'static dyn PieceMovement {
+        CUSTOM_PIECES.read().unwrap()[self.0]
+    }
+
+    /// Get the index of the piece in the order pieces were registered.
+    pub fn get_index(&self) -> usize {
+        self.0
+    }
+}
+
+impl Debug for CustomPieceId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CustomPieceId({}, {})",
+            self.0,
+            self.get_movement().name()
+        )
+    }
+}
+
+/// Registers a custom piece, so it can be placed on boards and read from FEN.
+///
+/// Registered pieces live for the rest of the program.
+///
+/// # Arguments
+///
+/// * `movement` - The description of how the piece moves.
+///
+/// # Returns
+///
+/// * `Ok(PieceType)` - The piece type to create `ChessPiece`s of the custom piece with.
+/// * `Err(String)` - If the FEN character of the piece is not a letter or is already
+///   used by another piece, or if no more pieces can be registered.
+pub fn register_custom_piece(movement: impl PieceMovement + 'static) -> Result<PieceType, String> {
+    let fen_char = movement.fen_char();
+    if !fen_char.is_ascii_uppercase() {
+        return Err(format!(
+            "FEN character of custom piece must be an uppercase letter, received '{fen_char}'"
+        ));
+    }
+
+    let mut custom_pieces = CUSTOM_PIECES.write().unwrap();
+    if BUILT_IN_FEN_CHARS.contains(&fen_char)
+        || custom_pieces
+            .iter()
+            .any(|custom_piece| custom_piece.fen_char() == fen_char)
+    {
+        return Err(format!(
+            "FEN character '{fen_char}' is already used by another piece"
+        ));
+    }
+    if custom_pieces.len() >= MAX_CUSTOM_PIECES {
+        return Err(format!(
+            "Unable to register more than {MAX_CUSTOM_PIECES} custom pieces"
+        ));
+    }
+
+    custom_pieces.push(Box::leak(Box::new(movement)));
+    Ok(PieceType::Custom(CustomPieceId(custom_pieces.len() - 1)))
+}
+
+/// Finds the registered custom piece with a FEN character.
+pub(crate) fn find_custom_piece(fen_char: char) -> Option<PieceType> {
+    CUSTOM_PIECES
+        .read()
+        .unwrap()
+        .iter()
+        .position(|custom_piece| custom_piece.fen_char() == fen_char)
+        .map(|index| PieceType::Custom(CustomPieceId(index)))
+}
+
+pub fn possible_moves(
+    id: CustomPieceId,
+    color: Color,
+    position: (usize, usize),
+    board: &Board<ChessPiece>,
+) -> Vec<ChessMoveType> {
+    let movement = id.get_movement();
+    let piece = ChessPiece::new(PieceType::Custom(id), color);
+    let forward = match color {
+        Color::White => 1,
+        Color::Black => -1,
+    };
+
+    let mut possible_moves: Vec<ChessMoveType> = Vec::new();
+    let mut add_move = |new_position: (usize, usize)| {
+        let taken_piece = board.get_piece_at_space(new_position.0, new_position.1);
+        let allowed = match taken_piece {
+            Some(taken_piece) => taken_piece.get_color() != color && !movement.moves_only(),
+            None => !movement.captures_only(),
+        };
+        if allowed {
+            possible_moves.push(ChessMoveType::Move {
+                original_position: position,
+                new_position,
+                piece,
+                taken_piece: taken_piece.copied(),
+                promotion: None,
+            });
+        }
+    };
+
+    for (col, row) in movement.leaper_offsets() {
+        if let Some(new_position) = board.get_offset(position.0, position.1, col, row * forward) {
+            add_move(new_position);
+        }
+    }
+
+    for (col, row) in movement.rider_directions() {
+        for square in board.ray(Coord::from(position), (col, row * forward)) {
+            add_move(square.into());
+        }
+    }
+
+    possible_moves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess_game_state_analyzer::is_in_check;
+    use crate::codec::forsyth_edwards_notation::{build_game_from_string, encode_game_as_string};
+    use crate::piece::PawnRules;
+    use crate::piece::PieceType::{Pawn, Queen};
+    use crate::ChessMoveType::Move;
+    use crate::Color::{Black, White};
+
+    // The registry is shared by all tests, so every test registers its pieces under
+    // letters no other test uses, including the letters used to test invalid FEN.
+    struct TestPiece {
+        fen_char: char,
+        leaper_offsets: Vec<(i32, i32)>,
+        rider_directions: Vec<(i32, i32)>,
+        captures_only: bool,
+        moves_only: bool,
+        is_royal: bool,
+        can_promote_to: bool,
+    }
+
+    impl TestPiece {
+        fn new(fen_char: char) -> Self {
+            Self {
+                fen_char,
+                leaper_offsets: Vec::new(),
+                rider_directions: Vec::new(),
+                captures_only: false,
+                moves_only: false,
+                is_royal: false,
+                can_promote_to: true,
+            }
+        }
+    }
+
+    impl PieceMovement for TestPiece {
+        fn name(&self) -> &str {
+            "Test piece"
+        }
+
+        fn fen_char(&self) -> char {
+            self.fen_char
+        }
+
+        fn symbol(&self, _color: Color) -> &str {
+            "T"
+        }
+
+        fn leaper_offsets(&self) -> Vec<(i32, i32)> {
+            self.leaper_offsets.clone()
+        }
+
+        fn rider_directions(&self) -> Vec<(i32, i32)> {
+            self.rider_directions.clone()
+        }
+
+        fn captures_only(&self) -> bool {
+            self.captures_only
+        }
+
+        fn moves_only(&self) -> bool {
+            self.moves_only
+        }
+
+        fn is_royal(&self) -> bool {
+            self.is_royal
+        }
+
+        fn can_promote_to(&self) -> bool {
+            self.can_promote_to
+        }
+    }
+
+    fn new_positions(moves: &[ChessMoveType]) -> Vec<(usize, usize)> {
+        moves
+            .iter()
+            .filter_map(|possible_move| match possible_move {
+                Move { new_position, .. } => Some(*new_position),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn leapers_jump_over_pieces_and_riders_are_blocked() {
+        let piece_type = register_custom_piece(TestPiece {
+            leaper_offsets: vec![(0, 2)],
+            rider_directions: vec![(1, 0)],
+            ..TestPiece::new('W')
+        })
+        .unwrap();
+        let game = build_game_from_string("8/8/8/8/8/8/P7/W2p4 w - - 0 1").unwrap();
+
+        let moves =
+            ChessPiece::new(piece_type, White).possible_moves((0, 0), game.get_board(), None);
+
+        assert_eq!(vec![(0, 2), (1, 0), (2, 0), (3, 0)], new_positions(&moves));
+        assert!(moves.contains(&Move {
+            original_position: (0, 0),
+            new_position: (3, 0),
+            piece: ChessPiece::new(piece_type, White),
+            taken_piece: Some(ChessPiece::new(Pawn, Black)),
+            promotion: None,
+        }));
+    }
+
+    #[test]
+    fn offsets_are_mirrored_for_black() {
+        let piece_type = register_custom_piece(TestPiece {
+            leaper_offsets: vec![(1, 1)],
+            ..TestPiece::new('E')
+        })
+        .unwrap();
+        let board = Board::build(8, 8).unwrap();
+
+        let moves = ChessPiece::new(piece_type, Black).possible_moves((3, 3), &board, None);
+        assert_eq!(vec![(4, 2)], new_positions(&moves));
+    }
+
+    #[test]
+    fn capture_only_and_move_only_pieces() {
+        let capturer = register_custom_piece(TestPiece {
+            rider_directions: vec![(0, 1)],
+            captures_only: true,
+            ..TestPiece::new('X')
+        })
+        .unwrap();
+        let mover = register_custom_piece(TestPiece {
+            rider_directions: vec![(0, 1)],
+            moves_only: true,
+            ..TestPiece::new('Y')
+        })
+        .unwrap();
+        let game = build_game_from_string("8/8/8/p7/8/8/8/8 w - - 0 1").unwrap();
+        let board = game.get_board();
+
+        let captures = ChessPiece::new(capturer, White).possible_moves((0, 0), board, None);
+        assert_eq!(vec![(0, 4)], new_positions(&captures));
+
+        let moves = ChessPiece::new(mover, White).possible_moves((0, 0), board, None);
+        assert_eq!(vec![(0, 1), (0, 2), (0, 3)], new_positions(&moves));
+    }
+
+    #[test]
+    fn fen_characters_must_be_unique_uppercase_letters() {
+        assert!(register_custom_piece(TestPiece::new('Q')).is_err());
+        assert!(register_custom_piece(TestPiece::new('z')).is_err());
+
+        assert!(register_custom_piece(TestPiece::new('U')).is_ok());
+        assert!(register_custom_piece(TestPiece::new('U')).is_err());
+    }
+
+    #[test]
+    fn royal_pieces_can_be_checked() {
+        let piece_type = register_custom_piece(TestPiece {
+            leaper_offsets: vec![(1, 0), (-1, 0), (0, 1), (0, -1)],
+            is_royal: true,
+            ..TestPiece::new('G')
+        })
+        .unwrap();
+        assert!(piece_type.is_royal());
+
+        let game = build_game_from_string("g7/8/8/8/8/8/8/r6G w - - 0 1").unwrap();
+        assert!(is_in_check(White, game.get_board()));
+        assert!(!is_in_check(Black, game.get_board()));
+    }
+
+    #[test]
+    fn custom_pieces_round_trip_through_fen() {
+        let piece_type = register_custom_piece(TestPiece::new('J')).unwrap();
+        let fen = "4k2j/8/8/8/8/8/8/J3K3 w - - 0 1";
+        let game = build_game_from_string(fen).unwrap();
+
+        assert_eq!(
+            Some(&ChessPiece::new(piece_type, White)),
+            game.get_board().get_piece_at_space(0, 0)
+        );
+        assert_eq!(
+            Some(&ChessPiece::new(piece_type, Black)),
+            game.get_board().get_piece_at_space(7, 7)
+        );
+        assert_eq!(fen, encode_game_as_string(&game));
+    }
+
+    #[test]
+    fn pawns_only_promote_to_eligible_pieces() {
+        let eligible = register_custom_piece(TestPiece::new('L')).unwrap();
+        let not_eligible = register_custom_piece(TestPiece {
+            can_promote_to: false,
+            ..TestPiece::new('M')
+        })
+        .unwrap();
+        let rules = PawnRules::new().set_promotion_options(vec![Queen, eligible, not_eligible]);
+        let board = Board::build(8, 8).unwrap();
+
+        let promotions = ChessPiece::new(Pawn, White)
+            .possible_moves_with_pawn_rules((0, 6), &board, None, &rules)
+            .into_iter()
+            .filter_map(|possible_move| match possible_move {
+                Move { promotion, .. } => promotion.map(|piece| piece.get_piece_type()),
+                _ => None,
+            })
+            .collect::<Vec<PieceType>>();
+        assert_eq!(vec![Queen, eligible], promotions);
+    }
+}
@@ -2,7 +2,7 @@ use crate::chess_move::ChessMoveType;
 use crate::piece::ChessPiece;
 use crate::piece::PieceType::King;
 use crate::Color;
-use game_board::Board;
+use game_board::{Board, Coord};
 
 pub fn as_utf_str(color: Color) -> &'static str {
     match color {
@@ -17,31 +17,11 @@ pub fn possible_moves(
     board: &Board<ChessPiece>,
 ) -> Vec<ChessMoveType> {
     let mut possible_moves: Vec<ChessMoveType> = Vec::new();
-    let moves = [
-        (0i32, 1),
-        (0, -1),
-        (1, 0),
-        (-1, 0),
-        (1, 1),
-        (1, -1),
-        (-1, 1),
-        (-1, -1),
-    ];
-    for mv in moves.iter() {
-        let new_col = position.0 as i32 + mv.0;
-        let new_row = position.1 as i32 + mv.1;
-
-        if new_col < 0
-            || new_col >= board.get_width() as i32
-            || new_row < 0
-            || new_row >= board.get_height() as i32
-        {
-            continue;
-        }
-
-        let new_col = new_col as usize;
-        let new_row = new_row as usize;
-
+    for (new_col, new_row) in board
+        .neighbours_8(Coord::from(position))
+        .into_iter()
+        .map(<(usize, usize)>::from)
+    {
         match board.get_piece_at_space(new_col, new_row) {
             Some(p) => {
                 if p.get_color() != color {
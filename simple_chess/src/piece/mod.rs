@@ -1,15 +1,24 @@
 use crate::chess_move::ChessMoveType;
+use crate::codec::forsyth_edwards_notation::encode_piece_as_fen_char;
 use crate::Color;
-use game_board::Board;
+use game_board::{Board, Coord, RenderPiece};
 use std::fmt::{Display, Formatter};
 
+mod archbishop;
 mod bishop;
+mod chancellor;
+mod custom;
 mod king;
 mod knight;
 mod pawn;
 mod queen;
 mod rook;
 
+pub use custom::{register_custom_piece, CustomPieceId, PieceMovement};
+pub use pawn::PawnRules;
+
+pub(crate) use custom::find_custom_piece;
+
 #[derive(Debug, PartialEq, Clone, Copy, Eq)]
 pub enum PieceType {
     Pawn,
@@ -18,12 +27,43 @@ pub enum PieceType {
     Bishop,
     Queen,
     King,
+    /// Moves like a bishop or a knight, as in Capablanca and Grand chess.
+    Archbishop,
+    /// Moves like a rook or a knight, as in Capablanca and Grand chess.
+    Chancellor,
+    /// A piece registered with `register_custom_piece`, moving as its `PieceMovement` describes.
+    Custom(CustomPieceId),
+}
+
+impl PieceType {
+    /// Checks if the piece is royal, so that it can be put in check.
+    ///
+    /// # Returns
+    ///
+    /// `true` for the king and for custom pieces that are royal.
+    pub fn is_royal(&self) -> bool {
+        match self {
+            PieceType::King => true,
+            PieceType::Custom(id) => id.get_movement().is_royal(),
+            _ => false,
+        }
+    }
+
+    /// Checks if a pawn can promote to the piece when it is one of the promotion options.
+    ///
+    /// Built-in pieces can always be promoted to, custom pieces decide for themselves.
+    pub fn can_promote_to(&self) -> bool {
+        match self {
+            PieceType::Custom(id) => id.get_movement().can_promote_to(),
+            _ => true,
+        }
+    }
 }
 
 /// ChessPiece represents a single simple_chess piece on the board.
 ///
-/// Each ChessPiece has a specific type (Pawn, Rook, Knight, Bishop, Queen, King)
-/// and a color (White or Black).
+/// Each ChessPiece has a specific type (Pawn, Rook, Knight, Bishop, Queen, King, or one of
+/// the fairy pieces Archbishop and Chancellor, or a registered custom piece) and a color (White or Black).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ChessPiece {
     piece_type: PieceType,
@@ -87,6 +127,21 @@ impl ChessPiece {
         self.piece_type
     }
 
+    /// Returns the same type of piece with the other color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::piece::{ChessPiece, PieceType};
+    /// use simple_chess::Color;
+    ///
+    /// let white_rook = ChessPiece::new(PieceType::Rook, Color::White);
+    /// assert_eq!(ChessPiece::new(PieceType::Rook, Color::Black), white_rook.color_flipped());
+    /// ```
+    pub fn color_flipped(&self) -> Self {
+        Self::new(self.piece_type, self.color.opposite())
+    }
+
     /// Returns the UTF-8 string representation of the simple_chess piece based on its type and color.
     ///
     /// # Examples
@@ -109,6 +164,9 @@ impl ChessPiece {
             PieceType::Bishop => bishop::as_utf_str(self.color),
             PieceType::Knight => knight::as_utf_str(self.color),
             PieceType::Pawn => pawn::as_utf_str(self.color),
+            PieceType::Archbishop => archbishop::as_utf_str(self.color),
+            PieceType::Chancellor => chancellor::as_utf_str(self.color),
+            PieceType::Custom(id) => id.get_movement().symbol(self.color),
         }
     }
 
@@ -150,6 +208,32 @@ impl ChessPiece {
         position: (usize, usize),
         board: &Board<ChessPiece>,
         last_move: Option<&ChessMoveType>,
+    ) -> Vec<ChessMoveType> {
+        self.possible_moves_with_pawn_rules(position, board, last_move, &PawnRules::default())
+    }
+
+    /// Returns a vector of possible moves for the piece, with pawns following the
+    /// given rules instead of the standard ones.
+    ///
+    /// Works like `possible_moves` otherwise, the rules are ignored by all other
+    /// piece types.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The (column, row) of the piece.
+    /// * `board` - The board the piece is on.
+    /// * `last_move` - The last move made, used for en passant.
+    /// * `pawn_rules` - The rules pawns move by.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<ChessMoveType>` containing all possible moves for the piece.
+    pub fn possible_moves_with_pawn_rules(
+        &self,
+        position: (usize, usize),
+        board: &Board<ChessPiece>,
+        last_move: Option<&ChessMoveType>,
+        pawn_rules: &PawnRules,
     ) -> Vec<ChessMoveType> {
         match self.piece_type {
             PieceType::King => king::possible_moves(self.color, position, board),
@@ -157,13 +241,69 @@ impl ChessPiece {
             PieceType::Rook => rook::possible_moves(self.color, position, board),
             PieceType::Bishop => bishop::possible_moves(self.color, position, board),
             PieceType::Knight => knight::possible_moves(self.color, position, board),
-            PieceType::Pawn => pawn::possible_moves(self.color, position, board, last_move),
+            PieceType::Pawn => {
+                pawn::possible_moves(self.color, position, board, last_move, pawn_rules)
+            }
+            PieceType::Archbishop => archbishop::possible_moves(self.color, position, board),
+            PieceType::Chancellor => chancellor::possible_moves(self.color, position, board),
+            PieceType::Custom(id) => custom::possible_moves(id, self.color, position, board),
         }
     }
 }
 
+/// Generates the moves of a piece that slides along `directions` until it
+/// reaches the edge of the board or another piece, taking it if it belongs
+/// to the opponent.
+pub(crate) fn sliding_moves(
+    piece: ChessPiece,
+    position: (usize, usize),
+    board: &Board<ChessPiece>,
+    directions: &[(i32, i32)],
+) -> Vec<ChessMoveType> {
+    let mut possible_moves = Vec::new();
+    for direction in directions {
+        for square in board.ray(Coord::from(position), *direction) {
+            let new_position: (usize, usize) = square.into();
+            let taken_piece = board.get_piece_at_space(new_position.0, new_position.1);
+            if taken_piece.is_some_and(|taken_piece| taken_piece.get_color() == piece.get_color()) {
+                continue;
+            }
+            possible_moves.push(ChessMoveType::Move {
+                original_position: position,
+                new_position,
+                piece,
+                taken_piece: taken_piece.copied(),
+                promotion: None,
+            });
+        }
+    }
+    possible_moves
+}
+
 impl Display for ChessPiece {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.as_utf_str())
     }
 }
+
+impl RenderPiece for ChessPiece {
+    /// The letter of the piece in Forsyth-Edwards Notation, upper case for
+    /// White and lower case for Black.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::game_board::{BoardRenderer, PieceStyle, Theme};
+    /// use simple_chess::ChessGame;
+    ///
+    /// let renderer = BoardRenderer::new()
+    ///     .set_theme(Theme::Plain)
+    ///     .set_piece_style(PieceStyle::Letter);
+    /// let rendered = renderer.render(ChessGame::new().get_board());
+    ///
+    /// assert_eq!(" r  n  b  q  k  b  n  r ", rendered.lines().next().unwrap());
+    /// ```
+    fn get_letter(&self) -> char {
+        encode_piece_as_fen_char(self)
+    }
+}
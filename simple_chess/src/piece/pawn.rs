@@ -10,6 +10,152 @@ const PROMOTION_OPTIONS: [PieceType; 4] = [
     PieceType::Knight,
 ];
 
+/// The rules pawns move by, which chess variants can change.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::piece::PawnRules;
+/// use simple_chess::piece::PieceType::{King, Knight, Queen};
+///
+/// let rules = PawnRules::new()
+///     .set_promotion_options(vec![Queen, Knight, King])
+///     .set_double_step_ranks(vec![0, 1]);
+/// assert_eq!(&[Queen, Knight, King], rules.get_promotion_options());
+/// assert!(rules.can_double_step_from(0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PawnRules {
+    promotion_options: Vec<PieceType>,
+    double_step_ranks: Vec<usize>,
+    en_passant_ranks: Vec<usize>,
+    optional_promotion_ranks: Vec<usize>,
+}
+
+impl PawnRules {
+    /// Creates the standard pawn rules, where pawns move two squares forward from
+    /// their second rank and promote to a queen, rook, bishop or knight.
+    pub fn new() -> Self {
+        Self {
+            promotion_options: PROMOTION_OPTIONS.to_vec(),
+            double_step_ranks: vec![1],
+            en_passant_ranks: vec![1],
+            optional_promotion_ranks: Vec::new(),
+        }
+    }
+
+    /// Check if a pawn can move two squares forward from a rank.
+    ///
+    /// # Arguments
+    ///
+    /// * `rank` - The rank counted from the pawn owner's side of the board, where
+    ///   0 is the player's first rank.
+    pub fn can_double_step_from(&self, rank: usize) -> bool {
+        self.double_step_ranks.contains(&rank)
+    }
+
+    /// Sets the ranks pawns can move two squares forward from.
+    ///
+    /// # Arguments
+    ///
+    /// * `double_step_ranks` - The ranks counted from each player's own side of the
+    ///   board, where 0 is the player's first rank. The standard rules use `[1]`.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The rules with the double step ranks set.
+    pub fn set_double_step_ranks(mut self, double_step_ranks: Vec<usize>) -> Self {
+        self.double_step_ranks = double_step_ranks;
+        self
+    }
+
+    /// Check if a pawn that moved two squares forward from a rank can be taken en passant.
+    ///
+    /// # Arguments
+    ///
+    /// * `rank` - The rank the pawn moved from, counted from the pawn owner's side of the
+    ///   board.
+    pub fn allows_en_passant_from(&self, rank: usize) -> bool {
+        self.en_passant_ranks.contains(&rank)
+    }
+
+    /// Sets the ranks a pawn must have moved two squares forward from to be taken en passant.
+    ///
+    /// # Arguments
+    ///
+    /// * `en_passant_ranks` - The ranks counted from each player's own side of the board. The
+    ///   standard rules use `[1]`, so in Horde a pawn moving two squares from the first rank
+    ///   can not be taken en passant.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The rules with the en passant ranks set.
+    pub fn set_en_passant_ranks(mut self, en_passant_ranks: Vec<usize>) -> Self {
+        self.en_passant_ranks = en_passant_ranks;
+        self
+    }
+
+    /// Check if a pawn may, but does not have to, promote when reaching a rank.
+    ///
+    /// Pawns always promote on the last rank of the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `rank` - The rank counted from the pawn owner's side of the board.
+    pub fn can_promote_early_on(&self, rank: usize) -> bool {
+        self.optional_promotion_ranks.contains(&rank)
+    }
+
+    /// Sets the ranks before the last rank where a pawn may choose to promote, as on the
+    /// eighth and ninth rank in Grand chess.
+    ///
+    /// # Arguments
+    ///
+    /// * `optional_promotion_ranks` - The ranks counted from each player's own side of the
+    ///   board. The standard rules have none.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The rules with the optional promotion ranks set.
+    pub fn set_optional_promotion_ranks(mut self, optional_promotion_ranks: Vec<usize>) -> Self {
+        self.optional_promotion_ranks = optional_promotion_ranks;
+        self
+    }
+
+    /// Get the piece types a pawn can promote to.
+    pub fn get_promotion_options(&self) -> &[PieceType] {
+        &self.promotion_options
+    }
+
+    /// Sets the piece types a pawn can promote to.
+    ///
+    /// # Arguments
+    ///
+    /// * `promotion_options` - The piece types, in the order the promotion moves are generated.
+    ///
+    /// # Returns
+    ///
+    /// * `Self` - The rules with the promotion options set.
+    pub fn set_promotion_options(mut self, promotion_options: Vec<PieceType>) -> Self {
+        self.promotion_options = promotion_options;
+        self
+    }
+}
+
+impl Default for PawnRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a pawn promotes when moving onto a square.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Promotion {
+    None,
+    Optional,
+    Required,
+}
+
 pub fn as_utf_str(color: Color) -> &'static str {
     match color {
         Color::White => "♙",
@@ -22,7 +168,9 @@ pub fn possible_moves(
     position: (usize, usize),
     board: &Board<ChessPiece>,
     last_move_type: Option<&ChessMoveType>,
+    rules: &PawnRules,
 ) -> Vec<ChessMoveType> {
+    let promotion_options = rules.get_promotion_options();
     let mut possible_moves: Vec<ChessMoveType> = Vec::new();
 
     let forward_direction = match color {
@@ -30,63 +178,69 @@ pub fn possible_moves(
         Color::Black => -1,
     };
 
-    let promotion_row = match color {
-        Color::White => board.get_height() - 1,
-        Color::Black => 0,
+    let height = board.get_height();
+    let promotion_at = |row: usize| {
+        let rank = get_relative_rank(color, row, height);
+        if rank == height - 1 {
+            Promotion::Required
+        } else if rules.can_promote_early_on(rank) {
+            Promotion::Optional
+        } else {
+            Promotion::None
+        }
     };
 
-    let next_row = position.1 as i32 + forward_direction;
-    if next_row < 0 || next_row > board.get_height() as i32 {
+    let (col, row) = position;
+    let Some((_, next_row)) = board.get_offset(col, row, 0, forward_direction) else {
         return possible_moves;
-    }
+    };
 
     // Simple move forward
-    if board
-        .get_piece_at_space(position.0, next_row as usize)
-        .is_none()
-    {
+    if board.get_piece_at_space(col, next_row).is_none() {
         possible_moves.append(&mut create_possible_moves(
             position,
-            (position.0, next_row as usize),
+            (col, next_row),
             color,
             None,
-            next_row as usize == promotion_row,
+            promotion_at(next_row),
+            promotion_options,
         ));
 
-        // moving 2 spaces from starting row
-        let starting_row = match color {
-            Color::White => 1,
-            Color::Black => board.get_height() - 2,
-        };
-        if position.1 == starting_row {
-            let double_next_row = position.1 as i32 + 2 * forward_direction;
-            if double_next_row >= 0 && double_next_row < board.get_height() as i32 {
+        // moving 2 spaces from a starting rank
+        if rules.can_double_step_from(get_relative_rank(color, row, height)) {
+            if let Some(double_step) = board.get_offset(col, row, 0, 2 * forward_direction) {
                 if board
-                    .get_piece_at_space(position.0, double_next_row as usize)
+                    .get_piece_at_space(double_step.0, double_step.1)
                     .is_none()
                 {
                     possible_moves.append(&mut create_possible_moves(
                         position,
-                        (position.0, double_next_row as usize),
+                        double_step,
                         color,
                         None,
-                        next_row as usize == promotion_row,
+                        promotion_at(double_step.1),
+                        promotion_options,
                     ));
                 }
             }
         }
     }
 
-    // Taking to the left
-    if position.0 > 0 {
-        if let Some(piece) = board.get_piece_at_space(position.0 - 1, next_row as usize) {
+    // Taking to the left and to the right
+    for side in [-1, 1] {
+        let Some(target) = board.get_offset(col, row, side, forward_direction) else {
+            continue;
+        };
+
+        if let Some(piece) = board.get_piece_at_space(target.0, target.1) {
             if piece.color != color {
                 possible_moves.append(&mut create_possible_moves(
                     position,
-                    (position.0 - 1, next_row as usize),
+                    target,
                     color,
                     Some(*piece),
-                    next_row as usize == promotion_row,
+                    promotion_at(next_row),
+                    promotion_options,
                 ));
             }
         }
@@ -99,89 +253,54 @@ pub fn possible_moves(
             ..
         }) = last_move_type
         {
-            if piece.piece_type == PieceType::Pawn && piece.color != color {
-                let rows_moved = if original_position.1 < new_position.1 {
-                    new_position.1 - original_position.1
-                } else {
-                    original_position.1 - new_position.1
-                };
-
-                if rows_moved == 2
-                    && new_position.0 == position.0 - 1
-                    && new_position.1 == position.1
-                {
-                    possible_moves.append(&mut create_possible_en_passant(
-                        position,
-                        (position.0 - 1, next_row as usize),
-                        color,
-                        *piece,
-                        *new_position,
-                        next_row as usize == promotion_row,
-                    ));
-                }
-            }
-        }
-    }
-
-    // Taking to the right
-    if position.0 < board.get_width() - 1 {
-        if let Some(piece) = board.get_piece_at_space(position.0 + 1, next_row as usize) {
-            if piece.color != color {
-                possible_moves.append(&mut create_possible_moves(
+            if piece.piece_type == PieceType::Pawn
+                && piece.color != color
+                && original_position.1.abs_diff(new_position.1) == 2
+                && *new_position == (target.0, row)
+                && rules.allows_en_passant_from(get_relative_rank(
+                    piece.color,
+                    original_position.1,
+                    height,
+                ))
+            {
+                possible_moves.append(&mut create_possible_en_passant(
                     position,
-                    (position.0 + 1, next_row as usize),
+                    target,
                     color,
-                    Some(*piece),
-                    next_row as usize == promotion_row,
+                    *piece,
+                    *new_position,
+                    promotion_at(next_row),
+                    promotion_options,
                 ));
             }
         }
-
-        // En Passant
-        if let Some(ChessMoveType::Move {
-            piece,
-            new_position,
-            original_position,
-            ..
-        }) = last_move_type
-        {
-            if piece.piece_type == PieceType::Pawn && piece.color != color {
-                let rows_moved = if original_position.1 < new_position.1 {
-                    new_position.1 - original_position.1
-                } else {
-                    original_position.1 - new_position.1
-                };
-
-                if rows_moved == 2
-                    && new_position.0 == position.0 + 1
-                    && new_position.1 == position.1
-                {
-                    possible_moves.append(&mut create_possible_en_passant(
-                        position,
-                        (position.0 + 1, next_row as usize),
-                        color,
-                        *piece,
-                        *new_position,
-                        next_row as usize == promotion_row,
-                    ));
-                }
-            }
-        }
     }
 
     possible_moves
 }
 
+/// Counts a row from the side of the board of the player with the given color.
+fn get_relative_rank(color: Color, row: usize, height: usize) -> usize {
+    match color {
+        Color::White => row,
+        Color::Black => height - 1 - row,
+    }
+}
+
 fn create_possible_moves(
     original_position: (usize, usize),
     new_position: (usize, usize),
     color: Color,
     taken_piece: Option<ChessPiece>,
-    can_promote: bool,
+    promotion: Promotion,
+    promotion_options: &[PieceType],
 ) -> Vec<ChessMoveType> {
     let mut possible_moves: Vec<ChessMoveType> = Vec::new();
-    if can_promote {
-        for option in PROMOTION_OPTIONS.iter() {
+    if promotion != Promotion::None {
+        for option in promotion_options
+            .iter()
+            .filter(|option| option.can_promote_to())
+        {
             possible_moves.push(ChessMoveType::Move {
                 original_position,
                 new_position,
@@ -190,7 +309,8 @@ fn create_possible_moves(
                 promotion: Some(ChessPiece::new(*option, color)),
             })
         }
-    } else {
+    }
+    if promotion != Promotion::Required {
         possible_moves.push(ChessMoveType::Move {
             original_position,
             new_position,
@@ -209,12 +329,16 @@ fn create_possible_en_passant(
     color: Color,
     taken_piece: ChessPiece,
     taken_piece_position: (usize, usize),
-    can_promote: bool,
+    promotion: Promotion,
+    promotion_options: &[PieceType],
 ) -> Vec<ChessMoveType> {
     let mut possible_en_passants: Vec<ChessMoveType> = Vec::new();
 
-    if can_promote {
-        for option in PROMOTION_OPTIONS.iter() {
+    if promotion != Promotion::None {
+        for option in promotion_options
+            .iter()
+            .filter(|option| option.can_promote_to())
+        {
             possible_en_passants.push(ChessMoveType::EnPassant {
                 original_position,
                 new_position,
@@ -224,7 +348,8 @@ fn create_possible_en_passant(
                 promotion: Some(ChessPiece::new(*option, color)),
             })
         }
-    } else {
+    }
+    if promotion != Promotion::Required {
         possible_en_passants.push(ChessMoveType::EnPassant {
             original_position,
             new_position,
@@ -523,4 +648,34 @@ mod tests {
             assert!(moves.contains(&expected_move));
         });
     }
+
+    #[test]
+    fn pawns_double_step_from_configured_ranks() {
+        let game = build_game_from_string("8/8/8/8/8/8/8/P7 w - - 0 1").unwrap();
+        let board = game.get_board();
+
+        let standard = possible_moves(White, (0, 0), board, None, &PawnRules::default());
+        assert_eq!(1, standard.len());
+
+        let rules = PawnRules::new().set_double_step_ranks(vec![0, 1]);
+        let moves = possible_moves(White, (0, 0), board, None, &rules);
+        assert_eq!(2, moves.len());
+        assert!(moves.contains(&Move {
+            original_position: (0, 0),
+            new_position: (0, 2),
+            piece: ChessPiece::new(Pawn, White),
+            taken_piece: None,
+            promotion: None,
+        }));
+    }
+
+    #[test]
+    fn pawns_promote_to_configured_pieces() {
+        let game = build_game_from_string("8/6P1/8/8/8/8/8/8 w - - 0 1").unwrap();
+        let board = game.get_board();
+
+        let rules = PawnRules::new().set_promotion_options(vec![Queen, King]);
+        let moves = possible_moves(White, (6, 6), board, None, &rules);
+        assert_eq!(2, moves.len());
+    }
 }
@@ -0,0 +1,135 @@
+use crate::chess_game_state_analyzer::GameState;
+use crate::variant::{Standard, Variant};
+use crate::{ChessGame, ChessMoveType};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+
+/// Something that can pick the next move in a game of chess.
+///
+/// Implemented by bots, search engines and adapters around human input so
+/// they can all be driven the same way, see `simulation::play_game`. Players
+/// are generic over the `Variant` being played.
+pub trait Player<V: Variant = Standard> {
+    /// Chooses the move to make in the given position.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game to move in, it is guaranteed to not be over.
+    ///
+    /// # Returns
+    ///
+    /// One of the legal moves of the player whose turn it is.
+    fn choose_move(&mut self, game: &ChessGame<V>) -> ChessMoveType;
+
+    /// Resets the player's source of randomness.
+    ///
+    /// Called once before every simulated game, players that do not use
+    /// randomness can keep the default implementation which does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed to use for the rest of the game.
+    fn set_seed(&mut self, _seed: u64) {}
+}
+
+/// A player that picks uniformly at random between the legal moves.
+///
+/// The random number generator is seeded, so the same seed always produces
+/// the same sequence of moves for the same positions.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::ChessGame;
+/// use simple_chess::player::{Player, RandomPlayer};
+///
+/// let game = ChessGame::new();
+/// let mut first = RandomPlayer::new(42);
+/// let mut second = RandomPlayer::new(42);
+///
+/// assert_eq!(first.choose_move(&game), second.choose_move(&game));
+/// ```
+pub struct RandomPlayer {
+    rng: StdRng,
+}
+
+impl RandomPlayer {
+    /// Creates a new random player.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed for the random number generator.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Resets the random number generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The new seed for the random number generator.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+}
+
+impl<V: Variant> Player<V> for RandomPlayer {
+    /// # Panics
+    ///
+    /// Panics if the game is over and there are no legal moves to pick from.
+    fn choose_move(&mut self, game: &ChessGame<V>) -> ChessMoveType {
+        let legal_moves = match game.clone().get_game_state() {
+            GameState::InProgress { legal_moves, .. } => legal_moves,
+            GameState::Check { legal_moves, .. } => legal_moves,
+            _ => Vec::new(),
+        };
+
+        *legal_moves.choose(&mut self.rng).expect("No moves given")
+    }
+
+    fn set_seed(&mut self, seed: u64) {
+        RandomPlayer::set_seed(self, seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::build_game_from_string;
+
+    #[test]
+    fn random_player_picks_a_legal_move() {
+        let mut game = build_game_from_string("4k3/8/8/8/8/8/8/r3K3 w - - 0 1").unwrap();
+        let legal_moves = match game.get_game_state() {
+            GameState::Check { legal_moves, .. } => legal_moves,
+            _ => panic!("Game should be in check"),
+        };
+
+        let mut player = RandomPlayer::new(0);
+        for _ in 0..10 {
+            assert!(legal_moves.contains(&player.choose_move(&game)));
+        }
+    }
+
+    #[test]
+    fn reseeding_restarts_the_sequence() {
+        let game = ChessGame::new();
+        let mut player = RandomPlayer::new(9);
+        let first_moves: Vec<ChessMoveType> = (0..5).map(|_| player.choose_move(&game)).collect();
+
+        player.set_seed(9);
+        let second_moves: Vec<ChessMoveType> = (0..5).map(|_| player.choose_move(&game)).collect();
+
+        assert_eq!(first_moves, second_moves);
+    }
+
+    #[test]
+    #[should_panic]
+    fn random_player_panics_when_there_are_no_moves() {
+        let game = build_game_from_string("k6R/pp6/8/8/8/8/8/8 b - - 0 1").unwrap();
+        RandomPlayer::new(0).choose_move(&game);
+    }
+}
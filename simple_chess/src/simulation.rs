@@ -0,0 +1,463 @@
+use crate::chess_game_state_analyzer::GameState;
+use crate::clock::{GameClock, SystemTimeSource, TimeControl, TimeSource};
+use crate::codec::forsyth_edwards_notation::{
+    build_variant_game_from_string, encode_game_as_string,
+};
+use crate::player::Player;
+use crate::variant::{Standard, Variant};
+use crate::{ChessGame, ChessMoveType, Color, DrawReason};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// How a simulated game ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOutcome {
+    Checkmate {
+        winner: Color,
+    },
+    VariantWin {
+        winner: Color,
+    },
+    VariantDraw,
+    Stalemate,
+    Draw(DrawReason),
+    MoveLimitReached,
+    /// A player ran out of time. There is no winner if the other player could
+    /// not have checkmated.
+    FlagFall {
+        winner: Option<Color>,
+    },
+}
+
+impl GameOutcome {
+    /// The color of the winning player, `None` if the game did not have a winner.
+    pub fn winner(&self) -> Option<Color> {
+        match self {
+            GameOutcome::Checkmate { winner } => Some(*winner),
+            GameOutcome::VariantWin { winner } => Some(*winner),
+            GameOutcome::FlagFall { winner } => *winner,
+            _ => None,
+        }
+    }
+}
+
+/// Options for `play_game`.
+#[derive(Debug, Clone)]
+pub struct PlayOptions<V = Standard> {
+    /// Seed the players are reseeded from before the first move.
+    pub seed: u64,
+    /// The maximum number of half moves to play before stopping the game.
+    pub max_moves: Option<usize>,
+    /// End the game as soon as a draw can be claimed.
+    pub claim_draws: bool,
+    /// The position the game starts from, played with the rules of its variant.
+    pub starting_position: ChessGame<V>,
+    /// The time the players have, `None` for an untimed game.
+    pub time_control: Option<TimeControl>,
+}
+
+impl Default for PlayOptions {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            max_moves: None,
+            claim_draws: true,
+            starting_position: ChessGame::new(),
+            time_control: None,
+        }
+    }
+}
+
+/// Everything needed to reproduce a simulated game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameRecord<V = Standard> {
+    seed: u64,
+    variant: V,
+    starting_fen: String,
+    moves: Vec<ChessMoveType>,
+    outcome: GameOutcome,
+}
+
+impl<V: Variant> GameRecord<V> {
+    /// The seed the players were seeded with.
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// The variant the game was played with, as it was in the starting position.
+    pub fn get_variant(&self) -> &V {
+        &self.variant
+    }
+
+    /// The starting position of the game in Forsyth-Edwards Notation.
+    pub fn get_starting_fen(&self) -> &str {
+        &self.starting_fen
+    }
+
+    /// The moves made in the game, in order.
+    pub fn get_moves(&self) -> &Vec<ChessMoveType> {
+        &self.moves
+    }
+
+    /// How the game ended.
+    pub fn get_outcome(&self) -> GameOutcome {
+        self.outcome
+    }
+
+    /// Rebuilds the game from its starting position and recorded moves.
+    ///
+    /// # Returns
+    ///
+    /// `ChessGame<V>`: The game in its final position.
+    pub fn replay(&self) -> ChessGame<V> {
+        let mut game = build_variant_game_from_string(&self.starting_fen, self.variant.clone())
+            .expect("recorded FEN should be valid");
+        for chess_move in &self.moves {
+            game.make_move(*chess_move);
+        }
+        game
+    }
+}
+
+/// Plays a full game between two players.
+///
+/// Before the first move both players are reseeded from `options.seed`, so
+/// playing again with the same players and options gives exactly the same game.
+///
+/// # Arguments
+///
+/// * `white` - The player playing the white pieces.
+/// * `black` - The player playing the black pieces.
+/// * `options` - The seed, starting position and stopping conditions.
+///
+/// # Returns
+///
+/// A `GameRecord` with the seed, starting position, moves and outcome of the game.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::player::RandomPlayer;
+/// use simple_chess::simulation::{play_game, PlayOptions};
+///
+/// let options = PlayOptions {
+///     seed: 1234,
+///     max_moves: Some(40),
+///     ..PlayOptions::default()
+/// };
+///
+/// let record = play_game(&mut RandomPlayer::new(0), &mut RandomPlayer::new(0), &options);
+/// let replayed = play_game(&mut RandomPlayer::new(5), &mut RandomPlayer::new(6), &options);
+///
+/// assert_eq!(record, replayed);
+/// ```
+pub fn play_game<V: Variant>(
+    white: &mut dyn Player<V>,
+    black: &mut dyn Player<V>,
+    options: &PlayOptions<V>,
+) -> GameRecord<V> {
+    play_game_with_time_source(white, black, options, SystemTimeSource::new())
+}
+
+/// Plays a full game between two players like `play_game`, timing the moves
+/// of a timed game with `time_source`.
+///
+/// The clock of the player to move runs while they choose their move. A
+/// player who runs out of time loses, unless the other player does not have
+/// the material to checkmate in which case the game is drawn, and the move
+/// they were choosing is not made.
+///
+/// # Arguments
+///
+/// * `white` - The player playing the white pieces.
+/// * `black` - The player playing the black pieces.
+/// * `options` - The seed, starting position, time control and stopping conditions.
+/// * `time_source` - Where the clock reads the time from.
+///
+/// # Returns
+///
+/// A `GameRecord` with the seed, starting position, moves and outcome of the game.
+pub fn play_game_with_time_source<V: Variant, T: TimeSource>(
+    white: &mut dyn Player<V>,
+    black: &mut dyn Player<V>,
+    options: &PlayOptions<V>,
+    time_source: T,
+) -> GameRecord<V> {
+    let mut clock = options
+        .time_control
+        .clone()
+        .map(|time_control| GameClock::new(time_control, time_source));
+    let mut seeds = StdRng::seed_from_u64(options.seed);
+    white.set_seed(seeds.gen());
+    black.set_seed(seeds.gen());
+
+    let mut game = options.starting_position.clone();
+    let starting_fen = encode_game_as_string(&game);
+    let mut moves = Vec::new();
+
+    let outcome = loop {
+        if options.claim_draws {
+            if let Some(reason) = game.can_claim_draw() {
+                break GameOutcome::Draw(reason);
+            }
+        }
+
+        if options
+            .max_moves
+            .is_some_and(|max_moves| moves.len() >= max_moves)
+        {
+            break GameOutcome::MoveLimitReached;
+        }
+
+        let turn = match game.get_game_state() {
+            GameState::InProgress { turn, .. } => turn,
+            GameState::Check { turn, .. } => turn,
+            GameState::Checkmate { winner } => break GameOutcome::Checkmate { winner },
+            GameState::Stalemate => break GameOutcome::Stalemate,
+            GameState::VariantWin { winner } => break GameOutcome::VariantWin { winner },
+            GameState::VariantDraw => break GameOutcome::VariantDraw,
+        };
+
+        if let Some(clock) = clock.as_mut() {
+            if clock.get_running() != Some(turn) {
+                clock.start(turn);
+            }
+        }
+
+        let next_move = match turn {
+            Color::White => white.choose_move(&game),
+            Color::Black => black.choose_move(&game),
+        };
+
+        if let Some(flagged) = clock.as_mut().and_then(|clock| clock.press()) {
+            game.lose_on_time(flagged)
+                .expect("The game is not over so it can be lost on time");
+            break GameOutcome::FlagFall {
+                winner: game.get_result().and_then(|result| result.get_winner()),
+            };
+        }
+
+        game.make_move(next_move);
+        moves.push(next_move);
+    };
+
+    GameRecord {
+        seed: options.seed,
+        variant: options.starting_position.get_variant().clone(),
+        starting_fen,
+        moves,
+        outcome,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualTimeSource;
+    use crate::codec::forsyth_edwards_notation::build_game_from_string;
+    use crate::player::RandomPlayer;
+    use crate::variant::KingOfTheHill;
+    use crate::Color::White;
+    use std::time::Duration;
+
+    #[test]
+    fn same_seed_plays_the_same_game() {
+        let options = PlayOptions {
+            seed: 77,
+            ..PlayOptions::default()
+        };
+
+        let first = play_game(
+            &mut RandomPlayer::new(1),
+            &mut RandomPlayer::new(2),
+            &options,
+        );
+        let second = play_game(
+            &mut RandomPlayer::new(3),
+            &mut RandomPlayer::new(4),
+            &options,
+        );
+
+        assert_eq!(first, second);
+        assert_eq!(77, first.get_seed());
+    }
+
+    #[test]
+    fn different_seeds_play_different_games() {
+        let mut white = RandomPlayer::new(0);
+        let mut black = RandomPlayer::new(0);
+        let first = play_game(&mut white, &mut black, &PlayOptions::default());
+        let second = play_game(
+            &mut white,
+            &mut black,
+            &PlayOptions {
+                seed: 1,
+                ..PlayOptions::default()
+            },
+        );
+
+        assert_ne!(first.get_moves(), second.get_moves());
+    }
+
+    #[test]
+    fn replaying_a_record_reaches_the_same_position() {
+        let options = PlayOptions {
+            seed: 5,
+            max_moves: Some(30),
+            ..PlayOptions::default()
+        };
+        let record = play_game(
+            &mut RandomPlayer::new(0),
+            &mut RandomPlayer::new(0),
+            &options,
+        );
+
+        assert_eq!(30, record.get_moves().len());
+        assert_eq!(GameOutcome::MoveLimitReached, record.get_outcome());
+
+        let mut expected = ChessGame::new();
+        record
+            .get_moves()
+            .iter()
+            .for_each(|chess_move| expected.make_move(*chess_move));
+        assert_eq!(
+            encode_game_as_string(&expected),
+            encode_game_as_string(&record.replay())
+        );
+    }
+
+    #[test]
+    fn game_starting_in_checkmate_has_no_moves() {
+        let options = PlayOptions {
+            starting_position: build_game_from_string("k6R/pp6/8/8/8/8/8/8 b - - 0 1").unwrap(),
+            ..PlayOptions::default()
+        };
+        let record = play_game(
+            &mut RandomPlayer::new(0),
+            &mut RandomPlayer::new(0),
+            &options,
+        );
+
+        assert!(record.get_moves().is_empty());
+        assert_eq!(
+            GameOutcome::Checkmate { winner: White },
+            record.get_outcome()
+        );
+        assert_eq!(Some(White), record.get_outcome().winner());
+        assert_eq!("k6R/pp6/8/8/8/8/8/8 b - - 0 1", record.get_starting_fen());
+    }
+
+    #[test]
+    fn games_stop_when_a_draw_can_be_claimed() {
+        let options = PlayOptions {
+            starting_position: build_game_from_string("k7/8/8/8/8/8/8/K7 w - - 0 1").unwrap(),
+            ..PlayOptions::default()
+        };
+        let record = play_game(
+            &mut RandomPlayer::new(0),
+            &mut RandomPlayer::new(0),
+            &options,
+        );
+
+        assert_eq!(
+            GameOutcome::Draw(DrawReason::InsufficientPieces),
+            record.get_outcome()
+        );
+    }
+
+    #[test]
+    fn variant_games_end_by_the_rules_of_the_variant() {
+        let options = PlayOptions {
+            seed: 3,
+            max_moves: None,
+            claim_draws: true,
+            starting_position: build_variant_game_from_string(
+                "8/8/8/8/8/8/4K3/k7 w - - 0 1",
+                KingOfTheHill,
+            )
+            .unwrap(),
+            time_control: None,
+        };
+        let record = play_game(
+            &mut RandomPlayer::new(0),
+            &mut RandomPlayer::new(0),
+            &options,
+        );
+
+        assert_eq!(
+            GameOutcome::VariantWin { winner: White },
+            record.get_outcome()
+        );
+        assert_eq!(
+            encode_game_as_string(&record.replay()),
+            encode_game_as_string(&{
+                let mut game = options.starting_position.clone();
+                record
+                    .get_moves()
+                    .iter()
+                    .for_each(|chess_move| game.make_move(*chess_move));
+                game
+            })
+        );
+    }
+
+    /// Takes a fixed amount of time for every move.
+    struct SlowPlayer {
+        player: RandomPlayer,
+        time_source: ManualTimeSource,
+        time_per_move: Duration,
+    }
+
+    impl Player for SlowPlayer {
+        fn choose_move(&mut self, game: &ChessGame) -> ChessMoveType {
+            self.time_source.advance(self.time_per_move);
+            Player::<Standard>::choose_move(&mut self.player, game)
+        }
+    }
+
+    fn play_timed_game(fen: &str, time_control: &str, white_seconds_per_move: u64) -> GameRecord {
+        let time_source = ManualTimeSource::new();
+        let mut white = SlowPlayer {
+            player: RandomPlayer::new(0),
+            time_source: time_source.clone(),
+            time_per_move: Duration::from_secs(white_seconds_per_move),
+        };
+        let mut black = SlowPlayer {
+            player: RandomPlayer::new(0),
+            time_source: time_source.clone(),
+            time_per_move: Duration::from_secs(1),
+        };
+        let options = PlayOptions {
+            starting_position: build_game_from_string(fen).unwrap(),
+            time_control: Some(time_control.parse().unwrap()),
+            ..PlayOptions::default()
+        };
+        play_game_with_time_source(&mut white, &mut black, &options, time_source)
+    }
+
+    #[test]
+    fn running_out_of_time_loses() {
+        let record = play_timed_game(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "60+1",
+            11,
+        );
+
+        assert_eq!(
+            GameOutcome::FlagFall {
+                winner: Some(Color::Black)
+            },
+            record.get_outcome()
+        );
+        // 60 seconds with an increment of 1 last for 5 moves of 11 seconds.
+        assert_eq!(10, record.get_moves().len());
+    }
+
+    #[test]
+    fn running_out_of_time_against_a_lone_king_draws() {
+        let record = play_timed_game("4k3/8/8/8/8/8/8/R3K3 w - - 0 1", "30", 20);
+
+        assert_eq!(GameOutcome::FlagFall { winner: None }, record.get_outcome());
+        assert_eq!(None, record.get_outcome().winner());
+    }
+}
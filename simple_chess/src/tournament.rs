@@ -0,0 +1,1049 @@
+use crate::clock::TimeControl;
+use crate::codec::forsyth_edwards_notation::{
+    build_variant_game_from_string, ForsythEdwardsNotationError,
+};
+use crate::codec::portable_game_notation::encode_game_as_pgn;
+use crate::player::Player;
+use crate::simulation::{play_game, GameOutcome, PlayOptions};
+use crate::variant::{Standard, Variant};
+use crate::{ChessGame, ChessMoveType, Color, GameResult};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Which entrants play each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Every entrant plays every other entrant.
+    RoundRobin,
+    /// The first entrant plays every other entrant, who do not play each other.
+    Gauntlet,
+}
+
+/// A player taking part in a tournament.
+///
+/// A new player is created for every game, so games can be played at the same
+/// time on different threads and no state is carried from one game to the next.
+pub struct Entrant<V = Standard> {
+    name: String,
+    create_player: Box<dyn Fn() -> Box<dyn Player<V>> + Send + Sync>,
+}
+
+impl<V: Variant> Entrant<V> {
+    /// Creates a new entrant.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name used in the PGN log and the cross table.
+    /// * `create_player` - Creates the player for a game.
+    pub fn new(
+        name: &str,
+        create_player: impl Fn() -> Box<dyn Player<V>> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            create_player: Box::new(create_player),
+        }
+    }
+
+    /// The name of the entrant.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// A finished game of a tournament.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TournamentGame {
+    /// The position of the game in the schedule, starting at 0.
+    pub number: usize,
+    /// The index of the entrant playing white.
+    pub white: usize,
+    /// The index of the entrant playing black.
+    pub black: usize,
+    pub result: GameResult,
+    /// Why the game ended, as in the PGN `Termination` tag.
+    pub termination: String,
+}
+
+/// An estimate of the Elo difference between a player and their opponents,
+/// with the margin of its 95% confidence interval.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EloEstimate {
+    pub difference: f64,
+    pub error_margin: f64,
+}
+
+impl EloEstimate {
+    /// Estimates the Elo difference from game results.
+    ///
+    /// A score of 0% or 100% gives an infinite difference and margin.
+    ///
+    /// # Arguments
+    ///
+    /// * `wins`, `draws`, `losses` - The results of the player.
+    ///
+    /// # Returns
+    ///
+    /// The estimate, `None` if no games were played.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::tournament::EloEstimate;
+    ///
+    /// let estimate = EloEstimate::from_results(30, 40, 30).unwrap();
+    /// assert_eq!(0.0, estimate.difference);
+    /// assert!(estimate.error_margin > 40.0 && estimate.error_margin < 60.0);
+    /// ```
+    pub fn from_results(wins: usize, draws: usize, losses: usize) -> Option<Self> {
+        let games = (wins + draws + losses) as f64;
+        if games == 0.0 {
+            return None;
+        }
+        let (score, variance) = get_score_and_variance(wins, draws, losses);
+        let margin = 1.96 * (variance / games).sqrt();
+
+        let difference = score_to_elo(score);
+        if difference.is_infinite() {
+            return Some(Self {
+                difference,
+                error_margin: f64::INFINITY,
+            });
+        }
+        let lower = score_to_elo((score - margin).max(0.0));
+        let upper = score_to_elo((score + margin).min(1.0));
+        Some(Self {
+            difference,
+            error_margin: (upper - lower) / 2.0,
+        })
+    }
+}
+
+impl Display for EloEstimate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+.0} ± {:.0}", self.difference, self.error_margin)
+    }
+}
+
+/// The average points per game and the variance of the points of one game.
+fn get_score_and_variance(wins: usize, draws: usize, losses: usize) -> (f64, f64) {
+    let games = (wins + draws + losses) as f64;
+    if games == 0.0 {
+        return (0.0, 0.0);
+    }
+    let score = (wins as f64 + draws as f64 / 2.0) / games;
+    let variance = (wins as f64 * (1.0 - score).powi(2)
+        + draws as f64 * (0.5 - score).powi(2)
+        + losses as f64 * score.powi(2))
+        / games;
+    (score, variance)
+}
+
+fn score_to_elo(score: f64) -> f64 {
+    -400.0 * (1.0 / score - 1.0).log10()
+}
+
+fn elo_to_score(elo: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf(-elo / 400.0))
+}
+
+/// The decision of a sequential probability ratio test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SprtStatus {
+    /// More games are needed.
+    Continue,
+    /// The Elo difference is likely `elo0` or less.
+    AcceptH0,
+    /// The Elo difference is likely `elo1` or more.
+    AcceptH1,
+}
+
+/// A sequential probability ratio test of whether a player is stronger than
+/// another, for A/B testing changes with as few games as possible.
+///
+/// Uses the normal approximation of the generalized SPRT on the game scores,
+/// as done by Fishtest and cutechess.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sprt {
+    /// The Elo difference of the null hypothesis.
+    pub elo0: f64,
+    /// The Elo difference of the alternative hypothesis.
+    pub elo1: f64,
+    /// The chance of accepting H1 when H0 is true.
+    pub alpha: f64,
+    /// The chance of accepting H0 when H1 is true.
+    pub beta: f64,
+}
+
+impl Sprt {
+    /// Creates a test between two Elo differences with 5% error rates.
+    pub fn new(elo0: f64, elo1: f64) -> Self {
+        Self {
+            elo0,
+            elo1,
+            alpha: 0.05,
+            beta: 0.05,
+        }
+    }
+
+    /// The log-likelihood ratio of the results.
+    ///
+    /// Stays at 0 until the results differ, as their variance can not be
+    /// estimated before that.
+    ///
+    /// # Arguments
+    ///
+    /// * `wins`, `draws`, `losses` - The results of the tested player.
+    pub fn get_log_likelihood_ratio(&self, wins: usize, draws: usize, losses: usize) -> f64 {
+        let games = (wins + draws + losses) as f64;
+        let (score, variance) = get_score_and_variance(wins, draws, losses);
+        if variance == 0.0 {
+            return 0.0;
+        }
+        let score0 = elo_to_score(self.elo0);
+        let score1 = elo_to_score(self.elo1);
+        games * (score1 - score0) * (2.0 * score - score0 - score1) / (2.0 * variance)
+    }
+
+    /// The log-likelihood ratios at which H0 and H1 are accepted.
+    pub fn get_bounds(&self) -> (f64, f64) {
+        (
+            (self.beta / (1.0 - self.alpha)).ln(),
+            ((1.0 - self.beta) / self.alpha).ln(),
+        )
+    }
+
+    /// Tests the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use simple_chess::tournament::{Sprt, SprtStatus};
+    ///
+    /// let sprt = Sprt::new(0.0, 10.0);
+    /// assert_eq!(SprtStatus::Continue, sprt.test(10, 10, 10));
+    /// assert_eq!(SprtStatus::AcceptH1, sprt.test(700, 400, 500));
+    /// assert_eq!(SprtStatus::AcceptH0, sprt.test(500, 400, 700));
+    /// ```
+    pub fn test(&self, wins: usize, draws: usize, losses: usize) -> SprtStatus {
+        let llr = self.get_log_likelihood_ratio(wins, draws, losses);
+        let (lower, upper) = self.get_bounds();
+        if llr >= upper {
+            SprtStatus::AcceptH1
+        } else if llr <= lower {
+            SprtStatus::AcceptH0
+        } else {
+            SprtStatus::Continue
+        }
+    }
+}
+
+/// The results of an entrant in a tournament.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    pub entrant: usize,
+    pub name: String,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub points: f64,
+    /// The Elo difference to the average opponent.
+    pub elo: Option<EloEstimate>,
+}
+
+#[derive(Debug)]
+pub struct TournamentError {
+    pub reason: String,
+}
+
+/// The games played in a tournament.
+#[derive(Debug, Clone)]
+pub struct TournamentResult {
+    names: Vec<String>,
+    games: Vec<TournamentGame>,
+    sprt: Option<Sprt>,
+}
+
+impl TournamentResult {
+    /// The finished games, ordered by their number.
+    pub fn get_games(&self) -> &Vec<TournamentGame> {
+        &self.games
+    }
+
+    /// The wins, draws and losses of `entrant` against `opponent`, or against
+    /// everyone if `opponent` is `None`.
+    pub fn get_results(&self, entrant: usize, opponent: Option<usize>) -> (usize, usize, usize) {
+        let mut results = (0, 0, 0);
+        for game in &self.games {
+            let (is_white, other) = if game.white == entrant {
+                (true, game.black)
+            } else if game.black == entrant {
+                (false, game.white)
+            } else {
+                continue;
+            };
+            if opponent.is_some_and(|opponent| opponent != other) {
+                continue;
+            }
+            match game.result.get_winner() {
+                None => results.1 += 1,
+                Some(Color::White) if is_white => results.0 += 1,
+                Some(Color::Black) if !is_white => results.0 += 1,
+                _ => results.2 += 1,
+            }
+        }
+        results
+    }
+
+    /// The entrants ordered by points, most first.
+    pub fn get_standings(&self) -> Vec<Standing> {
+        let mut standings: Vec<Standing> = (0..self.names.len())
+            .map(|entrant| {
+                let (wins, draws, losses) = self.get_results(entrant, None);
+                Standing {
+                    entrant,
+                    name: self.names[entrant].clone(),
+                    wins,
+                    draws,
+                    losses,
+                    points: wins as f64 + draws as f64 / 2.0,
+                    elo: EloEstimate::from_results(wins, draws, losses),
+                }
+            })
+            .collect();
+        standings.sort_by(|a, b| b.points.total_cmp(&a.points));
+        standings
+    }
+
+    /// The status of the SPRT of the first entrant against the second, `None`
+    /// if the tournament was not run with one.
+    pub fn get_sprt_status(&self) -> Option<SprtStatus> {
+        self.sprt.map(|sprt| {
+            let (wins, draws, losses) = self.get_results(0, Some(1));
+            sprt.test(wins, draws, losses)
+        })
+    }
+
+    /// Draws the standings with the points scored against each opponent, the
+    /// Elo estimates and, if there was one, the SPRT.
+    pub fn get_cross_table(&self) -> String {
+        let standings = self.get_standings();
+        let name_width = self.names.iter().map(|name| name.len()).max().unwrap_or(0);
+
+        let mut table = format!(
+            "{:>4} {:<name_width$} {:>5} {:>6} {:>12}",
+            "Rank", "Name", "Games", "Points", "Elo"
+        );
+        for rank in 1..=standings.len() {
+            table.push_str(&format!(" {:>7}", rank));
+        }
+        table.push('\n');
+
+        for (rank, standing) in standings.iter().enumerate() {
+            let elo = standing.elo.map(|elo| elo.to_string()).unwrap_or_default();
+            table.push_str(&format!(
+                "{:>4} {:<name_width$} {:>5} {:>6.1} {:>12}",
+                rank + 1,
+                standing.name,
+                standing.wins + standing.draws + standing.losses,
+                standing.points,
+                elo
+            ));
+            for opponent in &standings {
+                let (wins, draws, losses) =
+                    self.get_results(standing.entrant, Some(opponent.entrant));
+                let games = wins + draws + losses;
+                let cell = if opponent.entrant == standing.entrant {
+                    String::from("---")
+                } else if games == 0 {
+                    String::new()
+                } else {
+                    format!("{}/{}", wins as f64 + draws as f64 / 2.0, games)
+                };
+                table.push_str(&format!(" {:>7}", cell));
+            }
+            table.push('\n');
+        }
+
+        if let Some(sprt) = self.sprt {
+            let (wins, draws, losses) = self.get_results(0, Some(1));
+            let (lower, upper) = sprt.get_bounds();
+            table.push_str(&format!(
+                "SPRT elo0={} elo1={}: LLR {:.2} ({:.2}, {:.2}) {:?}\n",
+                sprt.elo0,
+                sprt.elo1,
+                sprt.get_log_likelihood_ratio(wins, draws, losses),
+                lower,
+                upper,
+                sprt.test(wins, draws, losses)
+            ));
+        }
+        table
+    }
+}
+
+/// Plays matches between `Player` implementations and estimates how strong
+/// they are compared to each other.
+///
+/// Every pairing plays every opening twice per round, once with each color.
+/// Games can be played on several threads and each finished game is appended
+/// to a PGN log, which is read back when the tournament is run again so an
+/// interrupted tournament carries on where it stopped. A player that panics
+/// loses the game it panicked in.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::player::RandomPlayer;
+/// use simple_chess::tournament::{Entrant, Tournament};
+/// use simple_chess::ChessGame;
+///
+/// let entrants = vec![
+///     Entrant::new("first", || Box::new(RandomPlayer::new(0))),
+///     Entrant::new("second", || Box::new(RandomPlayer::new(0))),
+/// ];
+/// let result = Tournament::new(entrants, vec![ChessGame::new()])
+///     .set_rounds(2)
+///     .set_max_moves(Some(20))
+///     .run()
+///     .unwrap();
+///
+/// assert_eq!(4, result.get_games().len());
+/// assert!(result.get_cross_table().contains("first"));
+/// ```
+pub struct Tournament<V = Standard> {
+    entrants: Vec<Entrant<V>>,
+    openings: Vec<ChessGame<V>>,
+    format: Format,
+    rounds: usize,
+    concurrency: usize,
+    seed: u64,
+    max_moves: Option<usize>,
+    time_control: Option<TimeControl>,
+    sprt: Option<Sprt>,
+    pgn_log: Option<PathBuf>,
+    event: String,
+}
+
+struct ScheduledGame {
+    white: usize,
+    black: usize,
+    opening: usize,
+}
+
+impl<V: Variant + Send + Sync> Tournament<V> {
+    /// Creates a round robin tournament of one round, played on one thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `entrants` - The players taking part.
+    /// * `openings` - The positions the games start from, see `parse_openings`.
+    pub fn new(entrants: Vec<Entrant<V>>, openings: Vec<ChessGame<V>>) -> Self {
+        Self {
+            entrants,
+            openings,
+            format: Format::RoundRobin,
+            rounds: 1,
+            concurrency: 1,
+            seed: 0,
+            max_moves: None,
+            time_control: None,
+            sprt: None,
+            pgn_log: None,
+            event: String::from("Tournament"),
+        }
+    }
+
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets how many times every pairing plays through the openings.
+    pub fn set_rounds(mut self, rounds: usize) -> Self {
+        self.rounds = rounds;
+        self
+    }
+
+    /// Sets how many games are played at the same time.
+    pub fn set_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Sets the seed every game's own seed is made from.
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the number of half moves after which a game is adjudicated a draw.
+    pub fn set_max_moves(mut self, max_moves: Option<usize>) -> Self {
+        self.max_moves = max_moves;
+        self
+    }
+
+    /// Sets the time the players have for each game, `None` for untimed games.
+    pub fn set_time_control(mut self, time_control: Option<TimeControl>) -> Self {
+        self.time_control = time_control;
+        self
+    }
+
+    /// Runs an SPRT of the first entrant against the second, and stops
+    /// starting new games once it accepts a hypothesis. Needs exactly two
+    /// entrants.
+    pub fn set_sprt(mut self, sprt: Option<Sprt>) -> Self {
+        self.sprt = sprt;
+        self
+    }
+
+    /// Sets the file finished games are appended to, and read back from when
+    /// the tournament is run again.
+    pub fn set_pgn_log(mut self, pgn_log: Option<PathBuf>) -> Self {
+        self.pgn_log = pgn_log;
+        self
+    }
+
+    /// Sets the `Event` tag of the logged games.
+    pub fn set_event(mut self, event: &str) -> Self {
+        self.event = event.to_string();
+        self
+    }
+
+    /// Plays the games of the tournament that have not been played yet.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(TournamentResult)` with every finished game, including those
+    ///   read from the PGN log.
+    /// - `Err(TournamentError)` if the tournament is set up wrong or the PGN
+    ///   log can not be read or written.
+    pub fn run(&self) -> Result<TournamentResult, TournamentError> {
+        if self.entrants.len() < 2 {
+            return Err(error("A tournament needs at least two entrants"));
+        }
+        if self.openings.is_empty() {
+            return Err(error("A tournament needs at least one opening"));
+        }
+        if self.sprt.is_some() && self.entrants.len() != 2 {
+            return Err(error("An SPRT needs exactly two entrants"));
+        }
+
+        let schedule = self.get_schedule();
+        let mut result = TournamentResult {
+            names: self.entrants.iter().map(|e| e.name.clone()).collect(),
+            games: self.read_pgn_log(&schedule)?,
+            sprt: self.sprt,
+        };
+        let finished: HashSet<usize> = result.games.iter().map(|game| game.number).collect();
+        let mut log = match &self.pgn_log {
+            Some(path) => Some(
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .map_err(|e| error(&format!("Unable to open {}: {}", path.display(), e)))?,
+            ),
+            None => None,
+        };
+
+        let next_game = AtomicUsize::new(0);
+        let stop = AtomicBool::new(result.get_sprt_status().is_some_and(is_decided));
+        let mut log_error = None;
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..self.concurrency.max(1) {
+                let sender = sender.clone();
+                let (schedule, finished, next_game, stop) =
+                    (&schedule, &finished, &next_game, &stop);
+                scope.spawn(move || {
+                    while !stop.load(Ordering::Relaxed) {
+                        let number = next_game.fetch_add(1, Ordering::Relaxed);
+                        if number >= schedule.len() {
+                            break;
+                        }
+                        if finished.contains(&number) {
+                            continue;
+                        }
+                        let played = self.play_scheduled_game(number, &schedule[number]);
+                        if sender.send(played).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            drop(sender);
+
+            for (game, pgn) in receiver {
+                if let Some(file) = log.as_mut() {
+                    if let Err(e) = writeln!(file, "{}", pgn).and_then(|_| file.flush()) {
+                        log_error = Some(error(&format!("Unable to write the PGN log: {}", e)));
+                        stop.store(true, Ordering::Relaxed);
+                    }
+                }
+                result.games.push(game);
+                if result.get_sprt_status().is_some_and(is_decided) {
+                    stop.store(true, Ordering::Relaxed);
+                }
+            }
+        });
+
+        if let Some(log_error) = log_error {
+            return Err(log_error);
+        }
+        result.games.sort_by_key(|game| game.number);
+        Ok(result)
+    }
+
+    fn get_schedule(&self) -> Vec<ScheduledGame> {
+        let count = self.entrants.len();
+        let pairings: Vec<(usize, usize)> = match self.format {
+            Format::RoundRobin => (0..count)
+                .flat_map(|a| (a + 1..count).map(move |b| (a, b)))
+                .collect(),
+            Format::Gauntlet => (1..count).map(|b| (0, b)).collect(),
+        };
+
+        let mut schedule = Vec::new();
+        for _ in 0..self.rounds {
+            for opening in 0..self.openings.len() {
+                for &(a, b) in &pairings {
+                    schedule.push(ScheduledGame {
+                        white: a,
+                        black: b,
+                        opening,
+                    });
+                    schedule.push(ScheduledGame {
+                        white: b,
+                        black: a,
+                        opening,
+                    });
+                }
+            }
+        }
+        schedule
+    }
+
+    fn play_scheduled_game(
+        &self,
+        number: usize,
+        scheduled: &ScheduledGame,
+    ) -> (TournamentGame, String) {
+        let mut white = CrashDetector::new((self.entrants[scheduled.white].create_player)());
+        let mut black = CrashDetector::new((self.entrants[scheduled.black].create_player)());
+        let options = PlayOptions {
+            seed: StdRng::seed_from_u64(self.seed.wrapping_add(number as u64)).gen(),
+            max_moves: self.max_moves,
+            claim_draws: true,
+            starting_position: self.openings[scheduled.opening].clone(),
+            time_control: self.time_control.clone(),
+        };
+
+        let played = catch_unwind(AssertUnwindSafe(|| {
+            play_game(&mut white, &mut black, &options)
+        }));
+        let (result, termination, game) = match played {
+            Ok(record) => {
+                let result = match record.get_outcome().winner() {
+                    Some(winner) => GameResult::win_for(winner),
+                    None => GameResult::Draw,
+                };
+                let termination = match record.get_outcome() {
+                    GameOutcome::MoveLimitReached => "adjudication",
+                    GameOutcome::FlagFall { .. } => "time forfeit",
+                    _ => "normal",
+                };
+                (result, termination, record.replay())
+            }
+            Err(panic) => {
+                let result = if white.thinking {
+                    GameResult::BlackWins
+                } else if black.thinking {
+                    GameResult::WhiteWins
+                } else {
+                    resume_unwind(panic)
+                };
+                (
+                    result,
+                    "rules infraction",
+                    options.starting_position.clone(),
+                )
+            }
+        };
+
+        let game_number = (number + 1).to_string();
+        let pgn_result = result.to_string();
+        let time_control = self.time_control.as_ref().map(|tc| tc.to_string());
+        let mut tags = vec![
+            ("Event", self.event.as_str()),
+            ("Round", game_number.as_str()),
+            ("White", self.entrants[scheduled.white].name.as_str()),
+            ("Black", self.entrants[scheduled.black].name.as_str()),
+            ("Result", pgn_result.as_str()),
+            ("Termination", termination),
+        ];
+        if let Some(time_control) = &time_control {
+            tags.push(("TimeControl", time_control.as_str()));
+        }
+        let tournament_game = TournamentGame {
+            number,
+            white: scheduled.white,
+            black: scheduled.black,
+            result,
+            termination: termination.to_string(),
+        };
+        (tournament_game, encode_game_as_pgn(&game, &tags))
+    }
+
+    /// Reads the games already played from the PGN log, using the `Round`
+    /// tag for the game number.
+    fn read_pgn_log(
+        &self,
+        schedule: &[ScheduledGame],
+    ) -> Result<Vec<TournamentGame>, TournamentError> {
+        let path = match &self.pgn_log {
+            Some(path) if path.exists() => path,
+            _ => return Ok(Vec::new()),
+        };
+        let pgn = std::fs::read_to_string(path)
+            .map_err(|e| error(&format!("Unable to read {}: {}", path.display(), e)))?;
+
+        let mut games: Vec<TournamentGame> = Vec::new();
+        let mut tags: Vec<(String, String)> = Vec::new();
+        let lines = pgn.lines().chain(std::iter::once("[Event \"\"]"));
+        for line in lines {
+            let Some((name, value)) = parse_tag(line) else {
+                continue;
+            };
+            if name == "Event" && !tags.is_empty() {
+                if let Some(game) = self.read_logged_game(&tags, schedule)? {
+                    if !games.iter().any(|logged| logged.number == game.number) {
+                        games.push(game);
+                    }
+                }
+                tags.clear();
+            }
+            tags.push((name, value));
+        }
+        Ok(games)
+    }
+
+    fn read_logged_game(
+        &self,
+        tags: &[(String, String)],
+        schedule: &[ScheduledGame],
+    ) -> Result<Option<TournamentGame>, TournamentError> {
+        let tag = |name: &str| {
+            tags.iter()
+                .find(|(tag, _)| tag == name)
+                .map(|(_, value)| value.as_str())
+                .unwrap_or_default()
+        };
+        let Ok(result) = tag("Result").parse::<GameResult>() else {
+            return Ok(None);
+        };
+        let number = match tag("Round").parse::<usize>() {
+            Ok(round) if round >= 1 && round <= schedule.len() => round - 1,
+            _ => return Err(error("The PGN log does not match the tournament")),
+        };
+        let scheduled = &schedule[number];
+        if tag("White") != self.entrants[scheduled.white].name
+            || tag("Black") != self.entrants[scheduled.black].name
+        {
+            return Err(error("The PGN log does not match the tournament"));
+        }
+        Ok(Some(TournamentGame {
+            number,
+            white: scheduled.white,
+            black: scheduled.black,
+            result,
+            termination: tag("Termination").to_string(),
+        }))
+    }
+}
+
+/// Reads starting positions, one per line, in Forsyth-Edwards Notation or
+/// Extended Position Description.
+///
+/// Blank lines and lines starting with `#` are skipped. EPD lines only have the
+/// first four FEN fields, followed by operations that are ignored.
+///
+/// # Arguments
+///
+/// * `openings` - The positions, one per line.
+/// * `variant` - The rules the games are played with.
+///
+/// # Returns
+///
+/// The positions, or the error of the first line that could not be read.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::tournament::parse_openings;
+/// use simple_chess::variant::Standard;
+///
+/// let openings = parse_openings(
+///     "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1\n\
+///      rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - bm Nf3; id \"open\";\n",
+///     Standard,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(2, openings.len());
+/// ```
+pub fn parse_openings<V: Variant>(
+    openings: &str,
+    variant: V,
+) -> Result<Vec<ChessGame<V>>, ForsythEdwardsNotationError> {
+    openings
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            build_variant_game_from_string(line, variant.clone()).or_else(|fen_error| {
+                let fields: Vec<&str> = line.split_whitespace().take(4).collect();
+                if fields.len() < 4 {
+                    return Err(fen_error);
+                }
+                build_variant_game_from_string(
+                    &format!("{} 0 1", fields.join(" ")),
+                    variant.clone(),
+                )
+            })
+        })
+        .collect()
+}
+
+fn parse_tag(line: &str) -> Option<(String, String)> {
+    let inner = line.trim().strip_prefix('[')?.strip_suffix(']')?;
+    let (name, value) = inner.split_once(' ')?;
+    let value = value.trim().strip_prefix('"')?.strip_suffix('"')?;
+    Some((
+        name.to_string(),
+        value.replace("\\\"", "\"").replace("\\\\", "\\"),
+    ))
+}
+
+fn is_decided(status: SprtStatus) -> bool {
+    status != SprtStatus::Continue
+}
+
+fn error(reason: &str) -> TournamentError {
+    TournamentError {
+        reason: reason.to_string(),
+    }
+}
+
+/// Remembers whether the player was choosing a move, so a panic can be blamed
+/// on the right side.
+struct CrashDetector<V> {
+    player: Box<dyn Player<V>>,
+    thinking: bool,
+}
+
+impl<V> CrashDetector<V> {
+    fn new(player: Box<dyn Player<V>>) -> Self {
+        Self {
+            player,
+            thinking: false,
+        }
+    }
+}
+
+impl<V: Variant> Player<V> for CrashDetector<V> {
+    fn choose_move(&mut self, game: &ChessGame<V>) -> ChessMoveType {
+        self.thinking = true;
+        let chess_move = self.player.choose_move(game);
+        self.thinking = false;
+        chess_move
+    }
+
+    fn set_seed(&mut self, seed: u64) {
+        self.player.set_seed(seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::build_game_from_string;
+    use crate::player::RandomPlayer;
+
+    struct CrashingPlayer;
+
+    impl Player for CrashingPlayer {
+        fn choose_move(&mut self, _game: &ChessGame) -> ChessMoveType {
+            panic!("crashed on purpose");
+        }
+    }
+
+    fn random_entrant(name: &str) -> Entrant {
+        Entrant::new(name, || Box::new(RandomPlayer::new(0)))
+    }
+
+    fn log_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "simple_chess_tournament_{}_{}.pgn",
+            name,
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn colors_alternate_for_every_opening() {
+        let openings = parse_openings(
+            "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1\n4k3/4p3/8/8/8/8/8/4K3 w - -\n",
+            Standard,
+        )
+        .unwrap();
+        let entrants = vec![
+            random_entrant("a"),
+            random_entrant("b"),
+            random_entrant("c"),
+        ];
+        let result = Tournament::new(entrants, openings)
+            .set_max_moves(Some(10))
+            .set_concurrency(3)
+            .run()
+            .unwrap();
+
+        let games = result.get_games();
+        assert_eq!(12, games.len());
+        assert!(games.iter().enumerate().all(|(i, game)| game.number == i));
+        for entrant in 0..3 {
+            let as_white = games.iter().filter(|game| game.white == entrant).count();
+            let as_black = games.iter().filter(|game| game.black == entrant).count();
+            assert_eq!((4, 4), (as_white, as_black));
+        }
+    }
+
+    #[test]
+    fn gauntlets_only_pair_the_first_entrant() {
+        let entrants = vec![
+            random_entrant("a"),
+            random_entrant("b"),
+            random_entrant("c"),
+        ];
+        let result = Tournament::new(entrants, vec![ChessGame::new()])
+            .set_format(Format::Gauntlet)
+            .set_max_moves(Some(4))
+            .run()
+            .unwrap();
+
+        assert_eq!(4, result.get_games().len());
+        assert!(result
+            .get_games()
+            .iter()
+            .all(|game| game.white == 0 || game.black == 0));
+    }
+
+    #[test]
+    fn crashing_players_forfeit() {
+        let entrants = vec![
+            Entrant::new("crashes", || Box::new(CrashingPlayer)),
+            random_entrant("random"),
+        ];
+        let result = Tournament::new(entrants, vec![ChessGame::new()])
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            vec![GameResult::BlackWins, GameResult::WhiteWins],
+            result
+                .get_games()
+                .iter()
+                .map(|game| game.result)
+                .collect::<Vec<GameResult>>()
+        );
+        assert_eq!("rules infraction", result.get_games()[0].termination);
+        assert_eq!((0, 0, 2), result.get_results(0, None));
+    }
+
+    #[test]
+    fn logged_games_are_not_played_again() {
+        let path = log_path("resume");
+        let tournament = |rounds| {
+            Tournament::new(
+                vec![random_entrant("a"), random_entrant("b")],
+                vec![ChessGame::new()],
+            )
+            .set_rounds(rounds)
+            .set_max_moves(Some(6))
+            .set_pgn_log(Some(path.clone()))
+        };
+
+        let first = tournament(1).run().unwrap();
+        let second = tournament(2).run().unwrap();
+
+        assert_eq!(first.get_games()[..], second.get_games()[..2]);
+        assert_eq!(4, second.get_games().len());
+        let log = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(4, log.matches("[Event \"Tournament\"]").count());
+        assert!(log.contains("[Round \"4\"]"));
+
+        let renamed = Tournament::new(
+            vec![random_entrant("x"), random_entrant("b")],
+            vec![ChessGame::new()],
+        )
+        .set_pgn_log(Some(path.clone()));
+        assert!(renamed.run().is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn sprt_stops_the_tournament_once_decided() {
+        let entrants = vec![
+            random_entrant("random"),
+            Entrant::new("crashes", || Box::new(CrashingPlayer)),
+        ];
+        let openings = vec![
+            ChessGame::new(),
+            build_game_from_string("k7/8/8/8/8/8/8/K7 w - - 0 1").unwrap(),
+            build_game_from_string("k7/8/8/8/8/8/8/K7 b - - 0 1").unwrap(),
+        ];
+        let result = Tournament::new(entrants, openings)
+            .set_rounds(1000)
+            .set_sprt(Some(Sprt::new(0.0, 50.0)))
+            .run()
+            .unwrap();
+
+        assert_eq!(Some(SprtStatus::AcceptH1), result.get_sprt_status());
+        assert!(result.get_games().len() < 6000);
+        assert!(result.get_cross_table().contains("AcceptH1"));
+    }
+
+    #[test]
+    fn sprt_needs_two_entrants() {
+        let entrants = vec![
+            random_entrant("a"),
+            random_entrant("b"),
+            random_entrant("c"),
+        ];
+        let tournament =
+            Tournament::new(entrants, vec![ChessGame::new()]).set_sprt(Some(Sprt::new(0.0, 5.0)));
+        assert!(tournament.run().is_err());
+    }
+
+    #[test]
+    fn elo_estimates_follow_the_score() {
+        let estimate = EloEstimate::from_results(75, 0, 25).unwrap();
+        assert!((estimate.difference - 190.85).abs() < 0.01);
+        assert_eq!(None, EloEstimate::from_results(0, 0, 0));
+        let perfect = EloEstimate::from_results(3, 0, 0).unwrap();
+        assert!(perfect.difference.is_infinite());
+        assert_eq!("+inf ± inf", perfect.to_string());
+    }
+}
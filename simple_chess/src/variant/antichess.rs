@@ -0,0 +1,210 @@
+use crate::chess_game_state_analyzer::GameState;
+use crate::piece::PieceType::{Bishop, King, Knight, Queen, Rook};
+use crate::piece::{ChessPiece, PawnRules};
+use crate::variant::{pseudo_legal_moves, Variant};
+use crate::{ChessGame, ChessMoveType, Color};
+use game_board::Board;
+
+/// Antichess, also known as losing chess: the first player to lose all their
+/// pieces or to be stalemated wins.
+///
+/// The rules differ from standard chess in that:
+///
+/// - Taking a piece is compulsory. When a capture is available, only captures
+///   are legal.
+/// - The king is an ordinary piece. There is no check, and kings can be taken.
+/// - Pawns can promote to a king.
+/// - There is no castling.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::Color::Black;
+/// use simple_chess::chess_game_state_analyzer::GameState;
+/// use simple_chess::codec::forsyth_edwards_notation::build_variant_game_from_string;
+/// use simple_chess::variant::Antichess;
+///
+/// let mut game =
+///     build_variant_game_from_string("8/8/8/8/8/8/P7/8 b - - 0 1", Antichess).unwrap();
+/// assert_eq!(GameState::VariantWin { winner: Black }, game.get_game_state());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Antichess;
+
+impl Variant for Antichess {
+    fn name(&self) -> &'static str {
+        "Antichess"
+    }
+
+    fn starting_fen(&self) -> Option<&'static str> {
+        Some("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1")
+    }
+
+    fn pawn_rules(&self) -> PawnRules {
+        PawnRules::new().set_promotion_options(vec![Queen, Rook, Bishop, Knight, King])
+    }
+
+    fn legal_moves(game: &mut ChessGame<Self>) -> Vec<ChessMoveType> {
+        let moves = pseudo_legal_moves(game)
+            .into_iter()
+            .filter(|possible_move| !matches!(possible_move, ChessMoveType::Castle { .. }))
+            .collect::<Vec<ChessMoveType>>();
+
+        if moves.iter().any(is_capture) {
+            moves.into_iter().filter(is_capture).collect()
+        } else {
+            moves
+        }
+    }
+
+    fn is_in_check(_color: Color, _board: &Board<ChessPiece>) -> bool {
+        false
+    }
+
+    fn game_end(game: &mut ChessGame<Self>, legal_moves: &[ChessMoveType]) -> Option<GameState> {
+        // Having no pieces left means having no moves left, so both ways of
+        // winning come down to being unable to move.
+        if legal_moves.is_empty() {
+            Some(GameState::VariantWin {
+                winner: game.get_current_players_turn(),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn is_insufficient_material(game: &ChessGame<Self>) -> bool {
+        // A lone bishop on each side on squares of different colours can never
+        // be forced to take the other.
+        let board = game.get_board();
+        let pieces = board
+            .iter_pieces()
+            .map(|(col, row, piece)| (piece, (col + row) % 2))
+            .collect::<Vec<(&ChessPiece, usize)>>();
+
+        match pieces.as_slice() {
+            [(first, first_square), (second, second_square)] => {
+                first.get_piece_type() == Bishop
+                    && second.get_piece_type() == Bishop
+                    && first.get_color() != second.get_color()
+                    && first_square != second_square
+            }
+            _ => false,
+        }
+    }
+}
+
+fn is_capture(chess_move: &ChessMoveType) -> bool {
+    matches!(
+        chess_move,
+        ChessMoveType::Move {
+            taken_piece: Some(_),
+            ..
+        } | ChessMoveType::EnPassant { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::{
+        build_variant_game_from_string, encode_game_as_string,
+    };
+    use crate::piece::PieceType::Pawn;
+    use crate::Color::{Black, White};
+    use game_board::perft;
+
+    fn build_game(fen: &str) -> ChessGame<Antichess> {
+        build_variant_game_from_string(fen, Antichess).unwrap()
+    }
+
+    #[test]
+    fn starts_without_castling_rights() {
+        let game = ChessGame::new_variant(Antichess);
+        assert_eq!(
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1",
+            encode_game_as_string(&game)
+        );
+    }
+
+    #[test]
+    fn perft_starting_position() {
+        let mut game = ChessGame::new_variant(Antichess);
+        assert_eq!(20, perft(&mut game, 1));
+        assert_eq!(400, perft(&mut game, 2));
+        assert_eq!(8067, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_pawn_races() {
+        let mut game = build_game("8/1p6/8/8/8/8/P7/8 w - - 0 1");
+        let expected = [2, 4, 4, 3, 1, 0];
+        for (depth, nodes) in expected.into_iter().enumerate() {
+            assert_eq!(nodes, perft(&mut game, depth + 1));
+        }
+
+        let mut game = build_game("8/2p5/8/8/8/8/P7/8 w - - 0 1");
+        let expected = [2, 4, 4, 4, 4, 4, 4, 4, 12, 36, 312];
+        for (depth, nodes) in expected.into_iter().enumerate() {
+            assert_eq!(nodes, perft(&mut game, depth + 1));
+        }
+    }
+
+    #[test]
+    fn captures_are_compulsory() {
+        let mut game = build_game("4k3/8/8/3p4/4P3/8/8/R3K3 w Q - 0 1");
+        assert_eq!(
+            vec![ChessMoveType::Move {
+                original_position: (4, 3),
+                new_position: (3, 4),
+                piece: ChessPiece::new(Pawn, White),
+                taken_piece: Some(ChessPiece::new(Pawn, Black)),
+                promotion: None,
+            }],
+            Antichess::legal_moves(&mut game)
+        );
+    }
+
+    #[test]
+    fn kings_can_be_taken_and_promoted_to() {
+        let mut game = build_game("3k4/1P6/8/8/8/8/8/3R4 w - - 0 1");
+        let legal_moves = Antichess::legal_moves(&mut game);
+
+        assert!(legal_moves.contains(&ChessMoveType::Move {
+            original_position: (3, 0),
+            new_position: (3, 7),
+            piece: ChessPiece::new(Rook, White),
+            taken_piece: Some(ChessPiece::new(King, Black)),
+            promotion: None,
+        }));
+
+        let mut game = build_game("3k4/1P6/8/8/8/8/8/8 w - - 0 1");
+        assert!(
+            Antichess::legal_moves(&mut game).contains(&ChessMoveType::Move {
+                original_position: (1, 6),
+                new_position: (1, 7),
+                piece: ChessPiece::new(Pawn, White),
+                taken_piece: None,
+                promotion: Some(ChessPiece::new(King, White)),
+            })
+        );
+    }
+
+    #[test]
+    fn stalemated_player_wins() {
+        let mut game = build_game("8/8/8/8/8/p7/P7/8 w - - 0 1");
+        assert_eq!(
+            GameState::VariantWin { winner: White },
+            game.get_game_state()
+        );
+    }
+
+    #[test]
+    fn bishops_on_opposite_colours_are_insufficient_material() {
+        let game = build_game("8/8/8/8/8/8/8/b6B w - - 0 1");
+        assert!(Antichess::is_insufficient_material(&game));
+
+        let game = build_game("8/8/8/8/8/8/8/b5B1 w - - 0 1");
+        assert!(!Antichess::is_insufficient_material(&game));
+    }
+}
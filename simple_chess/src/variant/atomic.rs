@@ -0,0 +1,358 @@
+use crate::chess_game_state_analyzer::{self, GameState};
+use crate::piece::ChessPiece;
+use crate::piece::PieceType::{King, Pawn, Rook};
+use crate::variant::{pseudo_legal_moves, Variant};
+use crate::{ChessGame, ChessMoveType, Color};
+use game_board::Board;
+
+/// Atomic chess: every capture causes an explosion on the capture square.
+///
+/// The explosion removes the capturing piece, the captured piece and every piece
+/// other than a pawn next to the capture square. On top of that:
+///
+/// - Kings can not capture.
+/// - A player wins by exploding the king of the other player. Moves that explode
+///   the player's own king are not allowed.
+/// - Kings standing next to each other are never in check, as capturing one of
+///   them would explode the other.
+///
+/// Undoing a move puts every exploded piece back on the board.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::Color::White;
+/// use simple_chess::chess_game_state_analyzer::GameState;
+/// use simple_chess::codec::forsyth_edwards_notation::build_variant_game_from_string;
+/// use simple_chess::piece::ChessPiece;
+/// use simple_chess::piece::PieceType::{Queen, Rook};
+/// use simple_chess::variant::Atomic;
+/// use simple_chess::ChessMoveType;
+/// use simple_chess::Color::Black;
+///
+/// let mut game =
+///     build_variant_game_from_string("3rk3/8/8/8/8/8/8/3QK3 w - - 0 1", Atomic::new()).unwrap();
+/// game.make_move(ChessMoveType::Move {
+///     original_position: (3, 0),
+///     new_position: (3, 7),
+///     piece: ChessPiece::new(Queen, White),
+///     taken_piece: Some(ChessPiece::new(Rook, Black)),
+///     promotion: None,
+/// });
+///
+/// assert_eq!(GameState::VariantWin { winner: White }, game.get_game_state());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Atomic {
+    explosions: Vec<Vec<((usize, usize), ChessPiece)>>,
+}
+
+impl Atomic {
+    /// Creates the variant.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Variant for Atomic {
+    fn name(&self) -> &'static str {
+        "Atomic"
+    }
+
+    fn legal_moves(game: &mut ChessGame<Self>) -> Vec<ChessMoveType> {
+        let color = game.get_current_players_turn();
+
+        pseudo_legal_moves(game)
+            .into_iter()
+            .filter(|possible_move| {
+                if let ChessMoveType::Move {
+                    piece,
+                    taken_piece: Some(_),
+                    ..
+                } = possible_move
+                {
+                    if piece.get_piece_type() == King {
+                        return false;
+                    }
+                }
+
+                let mut board = game.get_board().clone();
+                possible_move.make_move(&mut board);
+                if let Some(center) = get_explosion_center(possible_move) {
+                    explode(&mut board, center);
+                }
+
+                match (
+                    find_king(color, &board),
+                    find_king(color.opposite(), &board),
+                ) {
+                    (None, _) => false,
+                    (Some(_), None) => true,
+                    (Some(_), Some(_)) => !Self::is_in_check(color, &board),
+                }
+            })
+            .collect()
+    }
+
+    fn is_in_check(color: Color, board: &Board<ChessPiece>) -> bool {
+        let kings_are_adjacent = match (find_king(color, board), find_king(color.opposite(), board))
+        {
+            (Some(king), Some(enemy_king)) => {
+                king.0.abs_diff(enemy_king.0) <= 1 && king.1.abs_diff(enemy_king.1) <= 1
+            }
+            _ => false,
+        };
+        !kings_are_adjacent && chess_game_state_analyzer::is_in_check(color, board)
+    }
+
+    fn game_end(game: &mut ChessGame<Self>, _legal_moves: &[ChessMoveType]) -> Option<GameState> {
+        let turn = game.get_current_players_turn();
+        if find_king(turn, game.get_board()).is_none() {
+            Some(GameState::VariantWin {
+                winner: turn.opposite(),
+            })
+        } else if find_king(turn.opposite(), game.get_board()).is_none() {
+            Some(GameState::VariantWin { winner: turn })
+        } else {
+            None
+        }
+    }
+
+    fn is_insufficient_material(game: &ChessGame<Self>) -> bool {
+        let board = game.get_board();
+        board
+            .iter_pieces()
+            .all(|(_, _, piece)| piece.get_piece_type() == King)
+    }
+
+    fn after_move(game: &mut ChessGame<Self>, chess_move: &ChessMoveType) {
+        let exploded = match get_explosion_center(chess_move) {
+            Some(center) => explode(game.get_board_mut(), center),
+            None => Vec::new(),
+        };
+
+        for (position, piece) in &exploded {
+            if piece.get_piece_type() == Rook {
+                game.revoke_castling_right_for_rook_at(piece.get_color(), *position);
+            }
+        }
+        game.get_variant_mut().explosions.push(exploded);
+    }
+
+    fn before_undo(game: &mut ChessGame<Self>, _chess_move: &ChessMoveType) {
+        if let Some(exploded) = game.get_variant_mut().explosions.pop() {
+            let board = game.get_board_mut();
+            for ((col, row), piece) in exploded {
+                board.place_piece(piece, col, row);
+            }
+        }
+    }
+
+    fn color_flipped(&self, height: usize) -> Self {
+        let explosions = self
+            .explosions
+            .iter()
+            .map(|exploded| {
+                exploded
+                    .iter()
+                    .map(|((col, row), piece)| ((*col, height - 1 - row), piece.color_flipped()))
+                    .collect()
+            })
+            .collect();
+        Self { explosions }
+    }
+}
+
+fn get_explosion_center(chess_move: &ChessMoveType) -> Option<(usize, usize)> {
+    match chess_move {
+        ChessMoveType::Move {
+            new_position,
+            taken_piece: Some(_),
+            ..
+        } => Some(*new_position),
+        ChessMoveType::EnPassant { new_position, .. } => Some(*new_position),
+        _ => None,
+    }
+}
+
+/// Removes the piece on `center` and every piece other than a pawn around it.
+///
+/// Returns the removed pieces with their positions.
+fn explode(
+    board: &mut Board<ChessPiece>,
+    center: (usize, usize),
+) -> Vec<((usize, usize), ChessPiece)> {
+    let mut exploded = Vec::new();
+    if let Some(piece) = board.remove_piece(center.0, center.1) {
+        exploded.push((center, piece));
+    }
+
+    let cols = center.0.saturating_sub(1)..=(center.0 + 1).min(board.get_width() - 1);
+    for col in cols {
+        let rows = center.1.saturating_sub(1)..=(center.1 + 1).min(board.get_height() - 1);
+        for row in rows {
+            let is_pawn = match board.get_piece_at_space(col, row) {
+                Some(piece) => piece.get_piece_type() == Pawn,
+                None => continue,
+            };
+            if !is_pawn {
+                exploded.push(((col, row), board.remove_piece(col, row).unwrap()));
+            }
+        }
+    }
+    exploded
+}
+
+fn find_king(color: Color, board: &Board<ChessPiece>) -> Option<(usize, usize)> {
+    board.find(|piece| *piece == ChessPiece::new(King, color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::{
+        build_variant_game_from_string, encode_game_as_string,
+    };
+    use crate::piece::PieceType::{Bishop, Knight, Queen};
+    use crate::Color::{Black, White};
+    use game_board::perft;
+
+    fn build_game(fen: &str) -> ChessGame<Atomic> {
+        build_variant_game_from_string(fen, Atomic::new()).unwrap()
+    }
+
+    #[test]
+    fn perft_starting_position() {
+        let mut game = ChessGame::new_variant(Atomic::new());
+        assert_eq!(20, perft(&mut game, 1));
+        assert_eq!(400, perft(&mut game, 2));
+        assert_eq!(8902, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_programfox_positions() {
+        let mut game =
+            build_game("rn2kb1r/1pp1p2p/p2q1pp1/3P4/2P3b1/4PN2/PP3PPP/R2QKB1R b KQkq - 0 1");
+        assert_eq!(40, perft(&mut game, 1));
+        assert_eq!(1238, perft(&mut game, 2));
+        assert_eq!(45237, perft(&mut game, 3));
+
+        let mut game = build_game("rn1qkb1r/p5pp/2p5/3p4/N3P3/5P2/PPP4P/R1BQK3 w Qkq - 0 1");
+        assert_eq!(28, perft(&mut game, 1));
+        assert_eq!(833, perft(&mut game, 2));
+        assert_eq!(23353, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_chess_960_castling() {
+        let mut game = build_game("8/8/8/8/8/8/2k5/rR4KR w KQ - 0 1");
+        assert_eq!(18, perft(&mut game, 1));
+        assert_eq!(180, perft(&mut game, 2));
+        assert_eq!(4364, perft(&mut game, 3));
+
+        let mut game = build_game("r3k1rR/5K2/8/8/8/8/8/8 b kq - 0 1");
+        assert_eq!(25, perft(&mut game, 1));
+        assert_eq!(282, perft(&mut game, 2));
+        assert_eq!(6753, perft(&mut game, 3));
+
+        let mut game = build_game("Rr2k1rR/3K4/3p4/8/8/8/7P/8 w kq - 0 1");
+        assert_eq!(21, perft(&mut game, 1));
+        assert_eq!(465, perft(&mut game, 2));
+        assert_eq!(10631, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn capture_explodes_surrounding_pieces_except_pawns() {
+        let mut game = build_game("4k3/8/8/2npb3/3n4/8/8/3QK3 w - - 0 1");
+        game.make_move(ChessMoveType::Move {
+            original_position: (3, 0),
+            new_position: (3, 3),
+            piece: ChessPiece::new(Queen, White),
+            taken_piece: Some(ChessPiece::new(Knight, Black)),
+            promotion: None,
+        });
+
+        assert_eq!(
+            "4k3/8/8/3p4/8/8/8/4K3 b - - 0 1",
+            encode_game_as_string(&game)
+        );
+    }
+
+    #[test]
+    fn undo_restores_exploded_pieces() {
+        let fen = "4k3/8/8/2npb3/3n4/8/8/3QK3 w - - 0 1";
+        let mut game = build_game(fen);
+        game.make_move(ChessMoveType::Move {
+            original_position: (3, 0),
+            new_position: (3, 3),
+            piece: ChessPiece::new(Queen, White),
+            taken_piece: Some(ChessPiece::new(Knight, Black)),
+            promotion: None,
+        });
+        game.undo_last_move();
+
+        assert_eq!(fen, encode_game_as_string(&game));
+        assert_eq!(
+            Some(&ChessPiece::new(Bishop, Black)),
+            game.get_board().get_piece_at_space(4, 4)
+        );
+    }
+
+    #[test]
+    fn color_flipped_game_restores_exploded_pieces() {
+        let mut game = build_game("4k3/8/8/2npb3/3n4/8/8/3QK3 w - - 0 1");
+        game.make_move(ChessMoveType::Move {
+            original_position: (3, 0),
+            new_position: (3, 3),
+            piece: ChessPiece::new(Queen, White),
+            taken_piece: Some(ChessPiece::new(Knight, Black)),
+            promotion: None,
+        });
+
+        let mut flipped = game.color_flipped();
+        flipped.undo_last_move();
+
+        assert_eq!(
+            "3qk3/8/8/3N4/2NPB3/8/8/4K3 b - - 0 1",
+            encode_game_as_string(&flipped)
+        );
+    }
+
+    #[test]
+    fn kings_can_not_capture() {
+        let mut game = build_game("4k3/8/8/8/8/8/3n4/4K3 w - - 0 1");
+        assert!(!Atomic::legal_moves(&mut game)
+            .iter()
+            .any(|legal_move| matches!(
+                legal_move,
+                ChessMoveType::Move {
+                    taken_piece: Some(_),
+                    ..
+                }
+            )));
+    }
+
+    #[test]
+    fn adjacent_kings_are_not_in_check() {
+        let mut game = build_game("8/8/8/8/8/3k4/3K3r/8 w - - 0 1");
+        assert!(!Atomic::is_in_check(White, game.get_board()));
+        assert!(matches!(
+            game.get_game_state(),
+            GameState::InProgress { .. }
+        ));
+    }
+
+    #[test]
+    fn exploding_own_king_is_not_allowed() {
+        let mut game = build_game("4k3/8/8/8/8/8/3p4/2Q1K3 w - - 0 1");
+        assert!(
+            !Atomic::legal_moves(&mut game).contains(&ChessMoveType::Move {
+                original_position: (2, 0),
+                new_position: (3, 1),
+                piece: ChessPiece::new(Queen, White),
+                taken_piece: Some(ChessPiece::new(Pawn, Black)),
+                promotion: None,
+            })
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use crate::piece::PawnRules;
+use crate::piece::PieceType::{Archbishop, Bishop, Chancellor, Knight, Queen, Rook};
+use crate::variant::Variant;
+
+/// Capablanca chess: standard chess on a 10x8 board, with an Archbishop, moving
+/// like a bishop or a knight, and a Chancellor, moving like a rook or a knight.
+///
+/// Castling moves the king to the c or i file, with the rook next to it on the
+/// d or h file. Pawns can also promote to an Archbishop or Chancellor.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::ChessGame;
+/// use simple_chess::game_board::perft;
+/// use simple_chess::variant::Capablanca;
+///
+/// let mut game = ChessGame::new_variant(Capablanca);
+/// assert_eq!(10, game.get_board().get_width());
+/// assert_eq!(28, perft(&mut game, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Capablanca;
+
+impl Variant for Capablanca {
+    fn name(&self) -> &'static str {
+        "Capablanca"
+    }
+
+    fn starting_fen(&self) -> Option<&'static str> {
+        Some("rnabqkbcnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNABQKBCNR w KQkq - 0 1")
+    }
+
+    fn pawn_rules(&self) -> PawnRules {
+        PawnRules::new()
+            .set_promotion_options(vec![Queen, Chancellor, Archbishop, Rook, Bishop, Knight])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::{
+        build_variant_game_from_string, encode_game_as_string,
+    };
+    use crate::piece::ChessPiece;
+    use crate::piece::PieceType::Pawn;
+    use crate::Color::White;
+    use crate::{ChessGame, ChessMoveType};
+    use game_board::perft;
+
+    fn build_game(fen: &str) -> ChessGame<Capablanca> {
+        build_variant_game_from_string(fen, Capablanca).unwrap()
+    }
+
+    #[test]
+    fn starting_position_round_trips() {
+        let game = ChessGame::new_variant(Capablanca);
+        assert_eq!(
+            "rnabqkbcnr/pppppppppp/10/10/10/10/PPPPPPPPPP/RNABQKBCNR w KQkq - 0 1",
+            encode_game_as_string(&game)
+        );
+    }
+
+    #[test]
+    fn perft_starting_position() {
+        let mut game = ChessGame::new_variant(Capablanca);
+        assert_eq!(28, perft(&mut game, 1));
+        assert_eq!(784, perft(&mut game, 2));
+        assert_eq!(25228, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn castling_on_a_wide_board() {
+        let mut game = build_game("r4k3r/10/10/10/10/10/10/R4K3R w KQkq - 0 1");
+        let legal_moves = Capablanca::legal_moves(&mut game);
+
+        assert!(legal_moves.contains(&ChessMoveType::Castle {
+            rook_original_position: (9, 0),
+            rook_new_position: (7, 0),
+            king_original_position: (5, 0),
+            king_new_position: (8, 0),
+        }));
+        assert!(legal_moves.contains(&ChessMoveType::Castle {
+            rook_original_position: (0, 0),
+            rook_new_position: (3, 0),
+            king_original_position: (5, 0),
+            king_new_position: (2, 0),
+        }));
+    }
+
+    #[test]
+    fn pawns_promote_to_fairy_pieces() {
+        let mut game = build_game("5k4/8P1/10/10/10/10/10/5K4 w - - 0 1");
+        let promotions = Capablanca::legal_moves(&mut game)
+            .into_iter()
+            .filter_map(|legal_move| match legal_move {
+                ChessMoveType::Move {
+                    piece, promotion, ..
+                } if piece == ChessPiece::new(Pawn, White) => promotion,
+                _ => None,
+            })
+            .map(|promotion| promotion.get_piece_type())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![Queen, Chancellor, Archbishop, Rook, Bishop, Knight],
+            promotions
+        );
+    }
+}
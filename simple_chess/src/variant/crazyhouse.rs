@@ -0,0 +1,295 @@
+use crate::piece::PieceType;
+use crate::piece::PieceType::{King, Pawn};
+use crate::variant::Variant;
+use crate::Color::{Black, White};
+use crate::{ChessGame, ChessMoveType};
+
+/// Crazyhouse: a captured piece joins the pocket of the player who took it and
+/// can later be dropped onto any empty square instead of making a regular move.
+///
+/// On top of the standard rules:
+///
+/// - Pawns can not be dropped on the first or last rank.
+/// - A piece promoted from a pawn turns back into a pawn when it is captured.
+/// - Dropping a piece between a checking piece and the king blocks the check, so
+///   a position is only checkmate if no drop can save the king either.
+///
+/// The pockets are written to FEN in brackets after the board, `[QNp]`, and
+/// promoted pieces are marked with a '~'.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::ChessMoveType;
+/// use simple_chess::Color::White;
+/// use simple_chess::codec::forsyth_edwards_notation::{
+///     build_variant_game_from_string, encode_game_as_string,
+/// };
+/// use simple_chess::piece::ChessPiece;
+/// use simple_chess::piece::PieceType::Knight;
+/// use simple_chess::variant::Crazyhouse;
+///
+/// let mut game =
+///     build_variant_game_from_string("4k3/8/8/8/8/8/8/4K3[N] w - - 0 1", Crazyhouse::new())
+///         .unwrap();
+/// game.make_move(ChessMoveType::Drop {
+///     piece: ChessPiece::new(Knight, White),
+///     new_position: (3, 5),
+/// });
+///
+/// assert_eq!("4k3/8/3N4/8/8/8/8/4K3[] b - - 1 1", encode_game_as_string(&game));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Crazyhouse {
+    history: Vec<MoveRecord>,
+}
+
+/// What a move changed about the pockets and promoted pieces, so it can be undone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MoveRecord {
+    pocketed: Option<PieceType>,
+    moved_promoted: bool,
+    captured_promoted: bool,
+}
+
+impl Crazyhouse {
+    /// Creates the variant.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Variant for Crazyhouse {
+    fn name(&self) -> &'static str {
+        "Crazyhouse"
+    }
+
+    fn is_insufficient_material(game: &ChessGame<Self>) -> bool {
+        let board = game.get_board();
+        let only_kings = board
+            .iter_pieces()
+            .all(|(_, _, piece)| piece.get_piece_type() == King);
+        only_kings && game.get_pocket(White).is_empty() && game.get_pocket(Black).is_empty()
+    }
+
+    fn after_move(game: &mut ChessGame<Self>, chess_move: &ChessMoveType) {
+        let player = game.get_current_players_turn().opposite();
+        let mut record = MoveRecord {
+            pocketed: None,
+            moved_promoted: false,
+            captured_promoted: false,
+        };
+
+        match chess_move {
+            ChessMoveType::Move {
+                original_position,
+                new_position,
+                taken_piece,
+                promotion,
+                ..
+            } => {
+                record.moved_promoted = game.is_promoted(*original_position);
+                record.captured_promoted = game.is_promoted(*new_position);
+                record.pocketed = taken_piece.map(|taken_piece| match record.captured_promoted {
+                    true => Pawn,
+                    false => taken_piece.get_piece_type(),
+                });
+
+                game.set_promoted(*original_position, false);
+                game.set_promoted(*new_position, record.moved_promoted || promotion.is_some());
+            }
+            ChessMoveType::EnPassant { .. } => record.pocketed = Some(Pawn),
+            ChessMoveType::Castle { .. } | ChessMoveType::Drop { .. } => {}
+        }
+
+        if let Some(piece_type) = record.pocketed {
+            game.add_to_pocket(player, piece_type);
+        }
+        game.get_variant_mut().history.push(record);
+    }
+
+    fn before_undo(game: &mut ChessGame<Self>, chess_move: &ChessMoveType) {
+        let Some(record) = game.get_variant_mut().history.pop() else {
+            return;
+        };
+        let player = game.get_current_players_turn().opposite();
+
+        if let Some(piece_type) = record.pocketed {
+            game.remove_from_pocket(player, piece_type);
+        }
+        if let ChessMoveType::Move {
+            original_position,
+            new_position,
+            ..
+        } = chess_move
+        {
+            game.set_promoted(*new_position, record.captured_promoted);
+            game.set_promoted(*original_position, record.moved_promoted);
+        }
+    }
+
+    fn encode_fen_fields(&self, fields: &mut Vec<String>) {
+        if !fields[0].ends_with(']') {
+            fields[0].push_str("[]");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess_game_state_analyzer::GameState;
+    use crate::codec::forsyth_edwards_notation::{
+        build_variant_game_from_string, encode_game_as_string,
+    };
+    use crate::piece::ChessPiece;
+    use crate::piece::PieceType::{Knight, Queen, Rook};
+    use game_board::perft;
+
+    fn build_game(fen: &str) -> ChessGame<Crazyhouse> {
+        build_variant_game_from_string(fen, Crazyhouse::new()).unwrap()
+    }
+
+    #[test]
+    fn perft_all_drop_types() {
+        let mut game = build_game("2k5/8/8/8/8/8/8/4K3[QRBNPqrbnp] w - - 0 1");
+        assert_eq!(301, perft(&mut game, 1));
+        assert_eq!(75353, perft(&mut game, 2));
+    }
+
+    #[test]
+    fn perft_drops() {
+        let mut game = build_game("2k5/8/8/8/8/8/8/4K3[Qn] w - - 0 1");
+        assert_eq!(67, perft(&mut game, 1));
+        assert_eq!(3083, perft(&mut game, 2));
+        assert_eq!(88634, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_middlegame() {
+        let mut game =
+            build_game("r1bqk2r/pppp1ppp/2n1p3/4P3/1b1Pn3/2NB1N2/PPP2PPP/R1BQK2R[] b KQkq - 0 1");
+        assert_eq!(42, perft(&mut game, 1));
+        assert_eq!(1347, perft(&mut game, 2));
+        assert_eq!(58057, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_promoted_pieces() {
+        let mut game = build_game("4k3/1Q~6/8/8/4b3/8/Kpp5/8/ b - - 0 1");
+        assert_eq!(20, perft(&mut game, 1));
+        assert_eq!(360, perft(&mut game, 2));
+        assert_eq!(5445, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn captured_pieces_join_the_pocket() {
+        let mut game = build_game("4k3/8/8/3q4/8/8/8/3RK3[] w - - 0 1");
+        game.make_move(ChessMoveType::Move {
+            original_position: (3, 0),
+            new_position: (3, 4),
+            piece: ChessPiece::new(Rook, White),
+            taken_piece: Some(ChessPiece::new(Queen, Black)),
+            promotion: None,
+        });
+
+        assert_eq!(&[Queen], game.get_pocket(White));
+        assert_eq!(
+            "4k3/8/8/3R4/8/8/8/4K3[Q] b - - 0 1",
+            encode_game_as_string(&game)
+        );
+    }
+
+    #[test]
+    fn captured_promoted_piece_turns_back_into_a_pawn() {
+        let fen = "4k3/1Q~6/8/8/4b3/8/8/4K3[] b - - 0 1";
+        let mut game = build_game(fen);
+        game.make_move(ChessMoveType::Move {
+            original_position: (4, 3),
+            new_position: (1, 6),
+            piece: ChessPiece::new(PieceType::Bishop, Black),
+            taken_piece: Some(ChessPiece::new(Queen, White)),
+            promotion: None,
+        });
+
+        assert_eq!(&[Pawn], game.get_pocket(Black));
+        assert!(!game.is_promoted((1, 6)));
+
+        game.undo_last_move();
+        assert!(game.get_pocket(Black).is_empty());
+        assert!(game.is_promoted((1, 6)));
+    }
+
+    #[test]
+    fn promoted_pieces_keep_their_mark_when_moving() {
+        let mut game = build_game("4k3/1P6/8/8/8/8/8/4K3[] w - - 0 1");
+        game.make_move(ChessMoveType::Move {
+            original_position: (1, 6),
+            new_position: (1, 7),
+            piece: ChessPiece::new(Pawn, White),
+            taken_piece: None,
+            promotion: Some(ChessPiece::new(Knight, White)),
+        });
+        assert_eq!(
+            "1N~2k3/8/8/8/8/8/8/4K3[] b - - 0 1",
+            encode_game_as_string(&game)
+        );
+    }
+
+    #[test]
+    fn pawns_can_not_be_dropped_on_the_first_or_last_rank() {
+        let mut game = build_game("4k3/8/8/8/8/8/8/4K3[P] w - - 0 1");
+        let drop_rows = Crazyhouse::legal_moves(&mut game)
+            .into_iter()
+            .filter_map(|legal_move| match legal_move {
+                ChessMoveType::Drop { new_position, .. } => Some(new_position.1),
+                _ => None,
+            })
+            .collect::<Vec<usize>>();
+
+        assert_eq!(48, drop_rows.len());
+        assert!(drop_rows.iter().all(|row| (1..7).contains(row)));
+    }
+
+    #[test]
+    fn drops_can_block_check() {
+        let mut game = build_game("k7/8/8/8/8/8/PP6/K6r[N] w - - 0 1");
+        let state = game.get_game_state();
+
+        let GameState::Check { legal_moves, .. } = state else {
+            panic!("expected White to be in check, got {state:?}");
+        };
+        assert_eq!(6, legal_moves.len());
+        assert!(legal_moves.iter().all(|legal_move| matches!(
+            legal_move,
+            ChessMoveType::Drop {
+                new_position: (_, 0),
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn undoing_a_drop_returns_the_piece_to_the_pocket() {
+        let fen = "4k3/8/8/8/8/8/8/4K3[N] w - - 0 1";
+        let mut game = build_game(fen);
+        game.make_move(ChessMoveType::Drop {
+            piece: ChessPiece::new(Knight, White),
+            new_position: (0, 0),
+        });
+        assert!(game.get_pocket(White).is_empty());
+
+        game.undo_last_move();
+        assert_eq!(&[Knight], game.get_pocket(White));
+        assert_eq!(None, game.get_board().get_piece_at_space(0, 0));
+    }
+
+    #[test]
+    fn pieces_in_a_pocket_are_sufficient_material() {
+        let game = build_game("4k3/8/8/8/8/8/8/4K3[] w - - 0 1");
+        assert!(Crazyhouse::is_insufficient_material(&game));
+
+        let game = build_game("4k3/8/8/8/8/8/8/4K3[p] w - - 0 1");
+        assert!(!Crazyhouse::is_insufficient_material(&game));
+    }
+}
@@ -0,0 +1,184 @@
+use crate::piece::PieceType::{Archbishop, Bishop, Chancellor, Knight, Queen, Rook};
+use crate::piece::{PawnRules, PieceType};
+use crate::variant::{standard_legal_moves, Variant};
+use crate::{ChessGame, ChessMoveType};
+
+/// The number of pieces of each type a player starts with, which limits the
+/// pieces a pawn can promote to.
+const STARTING_PIECES: [(PieceType, usize); 6] = [
+    (Queen, 1),
+    (Chancellor, 1),
+    (Archbishop, 1),
+    (Rook, 2),
+    (Bishop, 2),
+    (Knight, 2),
+];
+
+/// Grand chess: chess on a 10x10 board, with an Archbishop, moving like a
+/// bishop or a knight, and a Chancellor, moving like a rook or a knight.
+///
+/// The rules differ from standard chess in that:
+///
+/// - Pawns start on the third rank and can move two squares forward from there.
+/// - Pawns may promote on the eighth and ninth rank, and must promote on the
+///   tenth rank.
+/// - A pawn can only promote to a piece the player has lost, so a pawn that
+///   can not promote can not move to the tenth rank.
+/// - There is no castling.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::ChessGame;
+/// use simple_chess::game_board::perft;
+/// use simple_chess::variant::Grand;
+///
+/// let mut game = ChessGame::new_variant(Grand);
+/// assert_eq!(10, game.get_board().get_height());
+/// assert_eq!(65, perft(&mut game, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Grand;
+
+impl Variant for Grand {
+    fn name(&self) -> &'static str {
+        "Grand"
+    }
+
+    fn starting_fen(&self) -> Option<&'static str> {
+        Some("r8r/1nbqkcabn1/pppppppppp/10/10/10/10/PPPPPPPPPP/1NBQKCABN1/R8R w - - 0 1")
+    }
+
+    fn pawn_rules(&self) -> PawnRules {
+        PawnRules::new()
+            .set_promotion_options(vec![Queen, Chancellor, Archbishop, Rook, Bishop, Knight])
+            .set_double_step_ranks(vec![2])
+            .set_en_passant_ranks(vec![2])
+            .set_optional_promotion_ranks(vec![7, 8])
+    }
+
+    fn legal_moves(game: &mut ChessGame<Self>) -> Vec<ChessMoveType> {
+        let color = game.get_current_players_turn();
+        let board = game.get_board();
+        let lost_pieces = STARTING_PIECES
+            .into_iter()
+            .filter(|(piece_type, starting_count)| {
+                let count = board
+                    .iter_pieces()
+                    .filter(|(_, _, piece)| {
+                        piece.get_piece_type() == *piece_type && piece.get_color() == color
+                    })
+                    .count();
+                count < *starting_count
+            })
+            .map(|(piece_type, _)| piece_type)
+            .collect::<Vec<PieceType>>();
+
+        standard_legal_moves(game)
+            .into_iter()
+            .filter(|legal_move| match legal_move {
+                ChessMoveType::Move {
+                    promotion: Some(promotion),
+                    ..
+                }
+                | ChessMoveType::EnPassant {
+                    promotion: Some(promotion),
+                    ..
+                } => lost_pieces.contains(&promotion.get_piece_type()),
+                _ => true,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::{
+        build_variant_game_from_string, encode_game_as_string,
+    };
+    use crate::piece::ChessPiece;
+    use crate::piece::PieceType::Pawn;
+    use crate::Color::{Black, White};
+    use game_board::perft;
+
+    fn build_game(fen: &str) -> ChessGame<Grand> {
+        build_variant_game_from_string(fen, Grand).unwrap()
+    }
+
+    fn pawn_moves_to(game: &mut ChessGame<Grand>, new_row: usize) -> Vec<Option<PieceType>> {
+        Grand::legal_moves(game)
+            .into_iter()
+            .filter_map(|legal_move| match legal_move {
+                ChessMoveType::Move {
+                    piece,
+                    new_position,
+                    promotion,
+                    ..
+                } if piece.get_piece_type() == Pawn && new_position.1 == new_row => {
+                    Some(promotion.map(|promotion| promotion.get_piece_type()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn starting_position_round_trips() {
+        let game = ChessGame::new_variant(Grand);
+        assert_eq!(
+            "r8r/1nbqkcabn1/pppppppppp/10/10/10/10/PPPPPPPPPP/1NBQKCABN1/R8R w - - 0 1",
+            encode_game_as_string(&game)
+        );
+    }
+
+    #[test]
+    fn perft_starting_position() {
+        let mut game = ChessGame::new_variant(Grand);
+        assert_eq!(65, perft(&mut game, 1));
+        assert_eq!(4225, perft(&mut game, 2));
+        assert_eq!(259514, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn pawns_double_step_from_the_third_rank_and_can_be_taken_en_passant() {
+        let mut game = build_game("4k5/10/10/10/10/3p6/10/4P5/10/4K5 w - - 0 1");
+        game.make_move(ChessMoveType::Move {
+            original_position: (4, 2),
+            new_position: (4, 4),
+            piece: ChessPiece::new(Pawn, White),
+            taken_piece: None,
+            promotion: None,
+        });
+
+        assert!(
+            Grand::legal_moves(&mut game).contains(&ChessMoveType::EnPassant {
+                original_position: (3, 4),
+                new_position: (4, 3),
+                piece: ChessPiece::new(Pawn, Black),
+                taken_piece: ChessPiece::new(Pawn, White),
+                taken_piece_position: (4, 4),
+                promotion: None,
+            })
+        );
+    }
+
+    #[test]
+    fn promotion_is_optional_before_the_last_rank() {
+        let mut game = build_game("4k5/10/10/P9/10/10/10/10/10/4K5 w - - 0 1");
+        let moves = pawn_moves_to(&mut game, 7);
+
+        assert!(moves.contains(&None));
+        assert!(moves.contains(&Some(Queen)));
+        assert_eq!(7, moves.len());
+    }
+
+    #[test]
+    fn pawns_only_promote_to_lost_pieces() {
+        let mut game = build_game("4k5/P9/10/10/10/10/10/10/10/1NBQKCABN1 w - - 0 1");
+        assert_eq!(vec![Some(Rook)], pawn_moves_to(&mut game, 9));
+
+        let mut game = build_game("4k5/P9/10/10/10/10/10/10/10/RNBQKCABNR w - - 0 1");
+        assert!(pawn_moves_to(&mut game, 9).is_empty());
+    }
+}
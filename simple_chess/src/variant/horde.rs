@@ -0,0 +1,122 @@
+use crate::chess_game_state_analyzer::GameState;
+use crate::piece::PawnRules;
+use crate::variant::Variant;
+use crate::Color::{Black, White};
+use crate::{ChessGame, ChessMoveType};
+
+/// Horde: White plays with 36 pawns and no king against the normal army of Black.
+///
+/// White wins by checkmating the black king, Black wins by taking every white
+/// piece. White's pawns on the first rank can move two squares forward, just
+/// like the pawns on the second rank.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::ChessGame;
+/// use simple_chess::game_board::perft;
+/// use simple_chess::variant::Horde;
+///
+/// let mut game = ChessGame::new_variant(Horde);
+/// assert_eq!(8, perft(&mut game, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Horde;
+
+impl Variant for Horde {
+    fn name(&self) -> &'static str {
+        "Horde"
+    }
+
+    fn starting_fen(&self) -> Option<&'static str> {
+        Some("rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1")
+    }
+
+    fn pawn_rules(&self) -> PawnRules {
+        PawnRules::new().set_double_step_ranks(vec![0, 1])
+    }
+
+    fn game_end(game: &mut ChessGame<Self>, _legal_moves: &[ChessMoveType]) -> Option<GameState> {
+        let board = game.get_board();
+        let white_has_pieces = board.find(|piece| piece.get_color() == White).is_some();
+
+        match white_has_pieces {
+            true => None,
+            false => Some(GameState::VariantWin { winner: Black }),
+        }
+    }
+
+    fn is_insufficient_material(_game: &ChessGame<Self>) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::{
+        build_variant_game_from_string, encode_game_as_string,
+    };
+    use crate::piece::ChessPiece;
+    use crate::piece::PieceType::Pawn;
+    use game_board::perft;
+
+    fn build_game(fen: &str) -> ChessGame<Horde> {
+        build_variant_game_from_string(fen, Horde).unwrap()
+    }
+
+    #[test]
+    fn starting_position() {
+        let game = ChessGame::new_variant(Horde);
+        assert_eq!(
+            "rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1",
+            encode_game_as_string(&game)
+        );
+    }
+
+    #[test]
+    fn perft_starting_position() {
+        let mut game = ChessGame::new_variant(Horde);
+        assert_eq!(8, perft(&mut game, 1));
+        assert_eq!(128, perft(&mut game, 2));
+        assert_eq!(1274, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_open_flank() {
+        let mut game = build_game("4k3/pp4q1/3P2p1/8/P3PP2/PPP2r2/PPP5/PPPP4 b - - 0 1");
+        assert_eq!(30, perft(&mut game, 1));
+        assert_eq!(241, perft(&mut game, 2));
+        assert_eq!(6633, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_en_passant() {
+        let mut game = build_game("k7/5p2/4p2P/3p2P1/2p2P2/1p2P2P/p2P2P1/2P2P2 w - - 0 1");
+        assert_eq!(13, perft(&mut game, 1));
+        assert_eq!(172, perft(&mut game, 2));
+        assert_eq!(2205, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn pawns_on_the_first_rank_can_double_step() {
+        let mut game = build_game("4k3/8/8/8/8/8/8/P7 w - - 0 1");
+        let legal_moves = Horde::legal_moves(&mut game);
+        assert!(legal_moves.contains(&ChessMoveType::Move {
+            original_position: (0, 0),
+            new_position: (0, 2),
+            piece: ChessPiece::new(Pawn, White),
+            taken_piece: None,
+            promotion: None,
+        }));
+    }
+
+    #[test]
+    fn black_wins_by_taking_every_white_piece() {
+        let mut game = build_game("4k3/8/8/8/8/8/8/8 w - - 0 1");
+        assert_eq!(
+            GameState::VariantWin { winner: Black },
+            game.get_game_state()
+        );
+    }
+}
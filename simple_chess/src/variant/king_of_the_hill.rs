@@ -0,0 +1,103 @@
+use crate::chess_game_state_analyzer::GameState;
+use crate::piece::PieceType::King;
+use crate::variant::Variant;
+use crate::{ChessGame, ChessMoveType};
+
+/// King of the Hill: on top of the standard rules, a player wins by moving
+/// their king to one of the four centre squares, d4, e4, d5 or e5.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::Color::White;
+/// use simple_chess::chess_game_state_analyzer::GameState;
+/// use simple_chess::codec::forsyth_edwards_notation::build_variant_game_from_string;
+/// use simple_chess::variant::KingOfTheHill;
+///
+/// let mut game =
+///     build_variant_game_from_string("8/8/8/8/4K3/8/8/k7 b - - 0 1", KingOfTheHill).unwrap();
+/// assert_eq!(GameState::VariantWin { winner: White }, game.get_game_state());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct KingOfTheHill;
+
+impl Variant for KingOfTheHill {
+    fn name(&self) -> &'static str {
+        "King of the Hill"
+    }
+
+    fn game_end(game: &mut ChessGame<Self>, _legal_moves: &[ChessMoveType]) -> Option<GameState> {
+        let board = game.get_board();
+        let (width, height) = (board.get_width(), board.get_height());
+
+        for col in (width - 1) / 2..=width / 2 {
+            for row in (height - 1) / 2..=height / 2 {
+                if let Some(piece) = board.get_piece_at_space(col, row) {
+                    if piece.get_piece_type() == King {
+                        return Some(GameState::VariantWin {
+                            winner: piece.get_color(),
+                        });
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    fn is_insufficient_material(_game: &ChessGame<Self>) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::build_variant_game_from_string;
+    use crate::Color::Black;
+    use game_board::perft;
+
+    fn build_game(fen: &str) -> ChessGame<KingOfTheHill> {
+        build_variant_game_from_string(fen, KingOfTheHill).unwrap()
+    }
+
+    #[test]
+    fn perft_starting_position() {
+        let mut game = ChessGame::new_variant(KingOfTheHill);
+        assert_eq!(20, perft(&mut game, 1));
+        assert_eq!(400, perft(&mut game, 2));
+        assert_eq!(8902, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_king_next_to_the_centre() {
+        let mut game = build_game("rnbqkbnr/pppppppp/8/8/8/2K5/PPPPPPPP/RNBQ1BNR w kq - 0 1");
+        assert_eq!(23, perft(&mut game, 1));
+        assert_eq!(440, perft(&mut game, 2));
+        assert_eq!(10676, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_kings_racing_to_the_centre() {
+        let mut game = build_game("8/8/2k5/8/8/8/5K2/8 w - - 0 1");
+        assert_eq!(8, perft(&mut game, 1));
+        assert_eq!(64, perft(&mut game, 2));
+        assert_eq!(384, perft(&mut game, 3));
+        assert_eq!(2901, perft(&mut game, 4));
+    }
+
+    #[test]
+    fn king_on_the_hill_wins() {
+        let mut game = build_game("8/8/8/3k4/8/8/8/K7 w - - 0 1");
+        assert_eq!(
+            GameState::VariantWin { winner: Black },
+            game.get_game_state()
+        );
+        assert_eq!(0, perft(&mut game, 1));
+    }
+
+    #[test]
+    fn bare_kings_can_still_win() {
+        let game = build_game("8/8/8/8/8/8/8/K6k w - - 0 1");
+        assert_eq!(None, game.can_claim_draw());
+    }
+}
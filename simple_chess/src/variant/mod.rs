@@ -0,0 +1,214 @@
+//! Rule variants of chess.
+//!
+//! A `ChessGame` is generic over a `Variant`, which can hook into legal move
+//! generation, game end detection and Forsyth-Edwards Notation. `Standard`
+//! plays by the regular rules of chess and is used when no variant is given.
+//!
+//! # Examples
+//!
+//! ```
+//! use simple_chess::ChessGame;
+//! use simple_chess::game_board::perft;
+//! use simple_chess::variant::KingOfTheHill;
+//!
+//! let mut game = ChessGame::new_variant(KingOfTheHill);
+//! assert_eq!(400, perft(&mut game, 2));
+//! ```
+mod antichess;
+mod atomic;
+mod capablanca;
+mod crazyhouse;
+mod grand;
+mod horde;
+mod king_of_the_hill;
+mod racing_kings;
+mod three_check;
+
+use crate::chess_game_move_analyzer;
+use crate::chess_game_state_analyzer::{self, GameState};
+use crate::piece::{ChessPiece, PawnRules};
+use crate::{ChessGame, ChessMoveType, Color};
+use game_board::Board;
+use std::fmt::Debug;
+
+pub use antichess::Antichess;
+pub use atomic::Atomic;
+pub use capablanca::Capablanca;
+pub use crazyhouse::Crazyhouse;
+pub use grand::Grand;
+pub use horde::Horde;
+pub use king_of_the_hill::KingOfTheHill;
+pub use racing_kings::RacingKings;
+pub use three_check::ThreeCheck;
+
+/// The rules of a chess variant.
+///
+/// All hooks have a default implementation that follows the standard rules, so
+/// a variant only needs to override the rules it changes. Any state a variant
+/// needs, such as the number of checks given in Three-check, is kept in the
+/// value implementing this trait, which is owned by the `ChessGame`.
+pub trait Variant: Debug + Clone + PartialEq + Sized {
+    /// The name of the variant.
+    fn name(&self) -> &'static str;
+
+    /// The position the variant starts from, in Forsyth-Edwards Notation.
+    ///
+    /// # Returns
+    ///
+    /// `Some` FEN string used by `ChessGame::new_variant`, or `None` to start
+    /// from the standard starting position.
+    fn starting_fen(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The rules pawns move by in the variant.
+    fn pawn_rules(&self) -> PawnRules {
+        PawnRules::default()
+    }
+
+    /// Generates the legal moves for the player whose turn it is.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game to generate the moves for.
+    ///
+    /// # Returns
+    ///
+    /// All moves the current player is allowed to make.
+    fn legal_moves(game: &mut ChessGame<Self>) -> Vec<ChessMoveType> {
+        standard_legal_moves(game)
+    }
+
+    /// Checks if the king of a player is in check.
+    ///
+    /// Used to filter the legal moves of the standard rules, to check the squares
+    /// a king castles through and to tell `Check` and `Checkmate` apart.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player whose king to check.
+    /// * `board` - The board to check on.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the king of `color` is in check.
+    fn is_in_check(color: Color, board: &Board<ChessPiece>) -> bool {
+        chess_game_state_analyzer::is_in_check(color, board)
+    }
+
+    /// Checks if the game has ended by a rule of the variant.
+    ///
+    /// Called by `get_game_state` before the standard checkmate and stalemate
+    /// detection, returning `Some` overrides the standard result.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game to check.
+    /// * `legal_moves` - The legal moves of the player whose turn it is.
+    ///
+    /// # Returns
+    ///
+    /// `Some(GameState)` if the variant decides the state of the game, `None`
+    /// to fall back to the standard rules.
+    fn game_end(_game: &mut ChessGame<Self>, _legal_moves: &[ChessMoveType]) -> Option<GameState> {
+        None
+    }
+
+    /// Checks if neither player can win anymore.
+    ///
+    /// # Arguments
+    ///
+    /// * `game` - The game to check.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a draw can be claimed because of insufficient material.
+    fn is_insufficient_material(game: &ChessGame<Self>) -> bool {
+        chess_game_state_analyzer::is_insufficient_material(game.get_board())
+    }
+
+    /// Called at the end of `ChessGame::make_move`, after the move has been made
+    /// and the turn has passed to the other player.
+    fn after_move(_game: &mut ChessGame<Self>, _chess_move: &ChessMoveType) {}
+
+    /// Called at the start of `ChessGame::undo_last_move`, before the move is
+    /// taken back from the board.
+    fn before_undo(_game: &mut ChessGame<Self>, _chess_move: &ChessMoveType) {}
+
+    /// Called by `ChessGame::color_flipped` to swap any state the variant keeps
+    /// for each player, such as the checks given in Three-check.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The height of the board, for state that refers to rows.
+    ///
+    /// # Returns
+    ///
+    /// The state of the variant as seen from the other side of the board.
+    fn color_flipped(&self, _height: usize) -> Self {
+        self.clone()
+    }
+
+    /// Adds the fields of the variant to an encoded FEN string.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - The six standard fields of the FEN string, which can be
+    ///   changed or extended.
+    fn encode_fen_fields(&self, _fields: &mut Vec<String>) {}
+
+    /// Reads the fields of the variant from a FEN string.
+    ///
+    /// # Arguments
+    ///
+    /// * `fields` - The space separated fields of the FEN string. The variant
+    ///   removes the fields it uses, leaving the six standard fields.
+    ///
+    /// # Returns
+    ///
+    /// An error describing the problem if the fields of the variant are invalid.
+    fn decode_fen_fields(&mut self, _fields: &mut Vec<&str>) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// The standard rules of chess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Standard;
+
+impl Variant for Standard {
+    fn name(&self) -> &'static str {
+        "Standard"
+    }
+}
+
+/// Generates the legal moves of the current player following the standard rules.
+///
+/// Useful for variants that only restrict or extend the standard moves.
+///
+/// # Arguments
+///
+/// * `game` - The game to generate the moves for.
+///
+/// # Returns
+///
+/// All moves the current player can make without leaving their king in check.
+pub fn standard_legal_moves<V: Variant>(game: &mut ChessGame<V>) -> Vec<ChessMoveType> {
+    chess_game_move_analyzer::get_legal_moves(game)
+}
+
+/// Generates the moves of the current player's pieces, including castling, without
+/// checking if they leave the player's king in check.
+///
+/// Useful for variants with their own rules on which moves are legal.
+///
+/// # Arguments
+///
+/// * `game` - The game to generate the moves for.
+///
+/// # Returns
+///
+/// All moves the pieces of the current player can make.
+pub fn pseudo_legal_moves<V: Variant>(game: &mut ChessGame<V>) -> Vec<ChessMoveType> {
+    chess_game_move_analyzer::get_pseudo_legal_moves(game)
+}
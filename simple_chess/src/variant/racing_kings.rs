@@ -0,0 +1,164 @@
+use crate::chess_game_state_analyzer::{is_in_check, GameState};
+use crate::piece::PieceType::King;
+use crate::variant::{standard_legal_moves, Variant};
+use crate::Color::{Black, White};
+use crate::{ChessGame, ChessMoveType, Color};
+
+/// Racing Kings: both players race their king to the eighth rank, without ever
+/// giving check.
+///
+/// The game starts with all pieces on the first two ranks and no pawns. Moves
+/// that give check are not allowed. The first king to reach the eighth rank
+/// wins, except that when the white king gets there first Black may answer by
+/// reaching it too, which draws the game.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::ChessGame;
+/// use simple_chess::game_board::perft;
+/// use simple_chess::variant::RacingKings;
+///
+/// let mut game = ChessGame::new_variant(RacingKings);
+/// assert_eq!(21, perft(&mut game, 1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RacingKings;
+
+impl Variant for RacingKings {
+    fn name(&self) -> &'static str {
+        "Racing Kings"
+    }
+
+    fn starting_fen(&self) -> Option<&'static str> {
+        Some("8/8/8/8/8/8/krbnNBRK/qrbnNBRQ w - - 0 1")
+    }
+
+    fn legal_moves(game: &mut ChessGame<Self>) -> Vec<ChessMoveType> {
+        let opponent = game.get_current_players_turn().opposite();
+
+        standard_legal_moves(game)
+            .into_iter()
+            .filter(|possible_move| {
+                let board = game.get_board_mut();
+                possible_move.make_move(board);
+                let gives_check = is_in_check(opponent, board);
+                possible_move.undo_move(board);
+                !gives_check
+            })
+            .collect()
+    }
+
+    fn game_end(game: &mut ChessGame<Self>, legal_moves: &[ChessMoveType]) -> Option<GameState> {
+        let goal_row = game.get_board().get_height() - 1;
+        let white_arrived = king_row(game, White) == Some(goal_row);
+        let black_arrived = king_row(game, Black) == Some(goal_row);
+
+        match (white_arrived, black_arrived) {
+            (true, true) => Some(GameState::VariantDraw),
+            (false, true) => Some(GameState::VariantWin { winner: Black }),
+            (true, false) => {
+                let black_can_follow = game.get_current_players_turn() == Black
+                    && legal_moves.iter().any(|legal_move| {
+                        matches!(
+                            legal_move,
+                            ChessMoveType::Move { piece, new_position, .. }
+                                if piece.get_piece_type() == King && new_position.1 == goal_row
+                        )
+                    });
+                match black_can_follow {
+                    true => None,
+                    false => Some(GameState::VariantWin { winner: White }),
+                }
+            }
+            (false, false) => None,
+        }
+    }
+
+    fn is_insufficient_material(_game: &ChessGame<Self>) -> bool {
+        false
+    }
+}
+
+fn king_row(game: &ChessGame<RacingKings>, color: Color) -> Option<usize> {
+    game.get_board()
+        .find(|piece| piece.get_piece_type() == King && piece.get_color() == color)
+        .map(|(_, row)| row)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::build_variant_game_from_string;
+    use crate::piece::ChessPiece;
+    use crate::piece::PieceType::Rook;
+    use game_board::perft;
+
+    fn build_game(fen: &str) -> ChessGame<RacingKings> {
+        build_variant_game_from_string(fen, RacingKings).unwrap()
+    }
+
+    #[test]
+    fn perft_starting_position() {
+        let mut game = ChessGame::new_variant(RacingKings);
+        assert_eq!(21, perft(&mut game, 1));
+        assert_eq!(421, perft(&mut game, 2));
+        assert_eq!(11264, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_occupied_goal() {
+        let mut game = build_game("4brn1/2K2k2/8/8/8/8/8/8 w - - 0 1");
+        assert_eq!(6, perft(&mut game, 1));
+        assert_eq!(33, perft(&mut game, 2));
+        assert_eq!(178, perft(&mut game, 3));
+        assert_eq!(3151, perft(&mut game, 4));
+    }
+
+    #[test]
+    fn moves_giving_check_are_not_allowed() {
+        let mut game = build_game("8/8/8/8/8/k7/8/1R5K w - - 0 1");
+        let legal_moves = RacingKings::legal_moves(&mut game);
+        let rook_move = |new_position| ChessMoveType::Move {
+            original_position: (1, 0),
+            new_position,
+            piece: ChessPiece::new(Rook, White),
+            taken_piece: None,
+            promotion: None,
+        };
+
+        assert!(!legal_moves.contains(&rook_move((0, 0))));
+        assert!(!legal_moves.contains(&rook_move((1, 2))));
+        assert!(legal_moves.contains(&rook_move((1, 1))));
+    }
+
+    #[test]
+    fn black_can_draw_by_reaching_the_goal_after_white() {
+        let mut game = build_game("4K3/6k1/8/8/8/8/8/8 b - - 0 1");
+        assert!(matches!(
+            game.get_game_state(),
+            GameState::InProgress { .. }
+        ));
+
+        let mut game = build_game("4K1k1/8/8/8/8/8/8/8 w - - 0 1");
+        assert_eq!(GameState::VariantDraw, game.get_game_state());
+    }
+
+    #[test]
+    fn white_wins_when_black_can_not_follow() {
+        let mut game = build_game("4K3/8/8/6k1/8/8/8/8 b - - 0 1");
+        assert_eq!(
+            GameState::VariantWin { winner: White },
+            game.get_game_state()
+        );
+    }
+
+    #[test]
+    fn black_reaching_the_goal_wins() {
+        let mut game = build_game("6k1/8/8/8/8/8/3K4/8 w - - 0 1");
+        assert_eq!(
+            GameState::VariantWin { winner: Black },
+            game.get_game_state()
+        );
+    }
+}
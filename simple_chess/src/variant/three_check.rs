@@ -0,0 +1,255 @@
+use crate::chess_game_state_analyzer::{is_in_check, GameState};
+use crate::piece::PieceType::King;
+use crate::variant::Variant;
+use crate::Color::{Black, White};
+use crate::{ChessGame, ChessMoveType, Color};
+
+const CHECKS_TO_WIN: usize = 3;
+
+/// Three-check: on top of the standard rules, the first player to give check
+/// three times wins the game.
+///
+/// The checks are written to FEN as the number of checks each player still
+/// needs to give, `3+3` at the start of the game, in the field after the en
+/// passant square. The Lichess style of appending the checks given so far,
+/// `+0+0`, is accepted when decoding as well.
+///
+/// # Examples
+///
+/// ```
+/// use simple_chess::ChessGame;
+/// use simple_chess::Color::White;
+/// use simple_chess::codec::forsyth_edwards_notation::encode_game_as_string;
+/// use simple_chess::variant::ThreeCheck;
+///
+/// let game = ChessGame::new_variant(ThreeCheck::new());
+/// assert_eq!(0, game.get_variant().get_checks_given(White));
+/// assert_eq!(
+///     "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 3+3 0 1",
+///     encode_game_as_string(&game)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ThreeCheck {
+    white_checks: usize,
+    black_checks: usize,
+}
+
+impl ThreeCheck {
+    /// Creates the variant with no checks given yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the number of checks a player has given so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player who gave the checks.
+    pub fn get_checks_given(&self, color: Color) -> usize {
+        match color {
+            White => self.white_checks,
+            Black => self.black_checks,
+        }
+    }
+
+    /// Get the number of checks a player still needs to give to win.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The player giving the checks.
+    pub fn get_remaining_checks(&self, color: Color) -> usize {
+        CHECKS_TO_WIN.saturating_sub(self.get_checks_given(color))
+    }
+
+    fn checks_given_mut(&mut self, color: Color) -> &mut usize {
+        match color {
+            White => &mut self.white_checks,
+            Black => &mut self.black_checks,
+        }
+    }
+}
+
+impl Variant for ThreeCheck {
+    fn name(&self) -> &'static str {
+        "Three-check"
+    }
+
+    fn game_end(game: &mut ChessGame<Self>, _legal_moves: &[ChessMoveType]) -> Option<GameState> {
+        [White, Black]
+            .into_iter()
+            .find(|color| game.get_variant().get_remaining_checks(*color) == 0)
+            .map(|winner| GameState::VariantWin { winner })
+    }
+
+    fn is_insufficient_material(game: &ChessGame<Self>) -> bool {
+        let board = game.get_board();
+        board
+            .iter_pieces()
+            .all(|(_, _, piece)| piece.get_piece_type() == King)
+    }
+
+    fn after_move(game: &mut ChessGame<Self>, _chess_move: &ChessMoveType) {
+        let defender = game.get_current_players_turn();
+        if is_in_check(defender, game.get_board()) {
+            *game.get_variant_mut().checks_given_mut(defender.opposite()) += 1;
+        }
+    }
+
+    fn before_undo(game: &mut ChessGame<Self>, _chess_move: &ChessMoveType) {
+        let defender = game.get_current_players_turn();
+        if is_in_check(defender, game.get_board()) {
+            let checks = game.get_variant_mut().checks_given_mut(defender.opposite());
+            *checks = checks.saturating_sub(1);
+        }
+    }
+
+    fn color_flipped(&self, _height: usize) -> Self {
+        Self {
+            white_checks: self.black_checks,
+            black_checks: self.white_checks,
+        }
+    }
+
+    fn encode_fen_fields(&self, fields: &mut Vec<String>) {
+        fields.insert(
+            4,
+            format!(
+                "{}+{}",
+                self.get_remaining_checks(White),
+                self.get_remaining_checks(Black)
+            ),
+        );
+    }
+
+    fn decode_fen_fields(&mut self, fields: &mut Vec<&str>) -> Result<(), String> {
+        let Some(index) = fields.iter().position(|field| field.contains('+')) else {
+            return Ok(());
+        };
+        let field = fields.remove(index);
+
+        let parse = |count: &str| -> Result<usize, String> {
+            count
+                .parse()
+                .map_err(|_| format!("Unable to parse '{field}' into check counters"))
+        };
+
+        match field.strip_prefix('+').map(|given| given.split_once('+')) {
+            Some(Some((white, black))) => {
+                self.white_checks = parse(white)?;
+                self.black_checks = parse(black)?;
+            }
+            Some(None) => {
+                return Err(format!("Unable to parse '{field}' into check counters"));
+            }
+            None => {
+                let (white, black) = field.split_once('+').unwrap();
+                self.white_checks = CHECKS_TO_WIN.saturating_sub(parse(white)?);
+                self.black_checks = CHECKS_TO_WIN.saturating_sub(parse(black)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codec::forsyth_edwards_notation::{
+        build_variant_game_from_string, encode_game_as_string,
+    };
+    use crate::piece::ChessPiece;
+    use crate::piece::PieceType::Queen;
+    use game_board::perft;
+
+    fn build_game(fen: &str) -> ChessGame<ThreeCheck> {
+        build_variant_game_from_string(fen, ThreeCheck::new()).unwrap()
+    }
+
+    #[test]
+    fn perft_kiwipete_with_one_check_remaining() {
+        let mut game =
+            build_game("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 1+1 0 1");
+        assert_eq!(48, perft(&mut game, 1));
+        assert_eq!(2039, perft(&mut game, 2));
+        assert_eq!(97848, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_castling_with_one_check_remaining() {
+        let mut game = build_game("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 1+1 0 1");
+        assert_eq!(26, perft(&mut game, 1));
+        assert_eq!(562, perft(&mut game, 2));
+        assert_eq!(13410, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn perft_after_two_checks_by_black() {
+        let mut game =
+            build_game("rnb1kbnr/pppp1ppp/8/4p3/4P2q/8/PPPP1PPP/RNBQKBNR w KQkq - 3+1 0 3");
+        assert_eq!(26, perft(&mut game, 1));
+        assert_eq!(999, perft(&mut game, 2));
+        assert_eq!(26594, perft(&mut game, 3));
+    }
+
+    #[test]
+    fn checks_are_counted_and_undone() {
+        let mut game = build_game("4k3/8/8/8/8/8/8/3QK3 w - - 3+3 0 1");
+        let check = ChessMoveType::Move {
+            original_position: (3, 0),
+            new_position: (3, 6),
+            piece: ChessPiece::new(Queen, White),
+            taken_piece: None,
+            promotion: None,
+        };
+
+        game.make_move(check);
+        assert_eq!(1, game.get_variant().get_checks_given(White));
+        assert_eq!(
+            "4k3/3Q4/8/8/8/8/8/4K3 b - - 2+3 1 1",
+            encode_game_as_string(&game)
+        );
+
+        game.undo_last_move();
+        assert_eq!(0, game.get_variant().get_checks_given(White));
+    }
+
+    #[test]
+    fn third_check_wins_the_game() {
+        let mut game = build_game("4k3/8/8/8/8/8/8/3QK3 b - - 0+3 0 1");
+        assert_eq!(
+            GameState::VariantWin { winner: White },
+            game.get_game_state()
+        );
+        assert_eq!(0, perft(&mut game, 1));
+    }
+
+    #[test]
+    fn lichess_check_counters_are_decoded() {
+        let game = build_game("4k3/8/8/8/8/8/8/3QK3 w - - 0 1 +2+1");
+        assert_eq!(2, game.get_variant().get_checks_given(White));
+        assert_eq!(1, game.get_variant().get_checks_given(Black));
+        assert_eq!(
+            "4k3/8/8/8/8/8/8/3QK3 w - - 1+2 0 1",
+            encode_game_as_string(&game)
+        );
+    }
+
+    #[test]
+    fn invalid_check_counters_are_an_error() {
+        assert!(build_variant_game_from_string(
+            "4k3/8/8/8/8/8/8/3QK3 w - - x+3 0 1",
+            ThreeCheck::new()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn kings_alone_can_not_give_check() {
+        let game = build_game("4k3/8/8/8/8/8/8/4K3 w - - 3+3 0 1");
+        assert!(ThreeCheck::is_insufficient_material(&game));
+
+        let game = build_game("4k3/8/8/8/8/8/8/3NK3 w - - 3+3 0 1");
+        assert!(!ThreeCheck::is_insufficient_material(&game));
+    }
+}
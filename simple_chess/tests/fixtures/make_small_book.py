@@ -0,0 +1,42 @@
+#!/usr/bin/env python3
+"""Writes small_book.bin, the Polyglot book used by the opening_book tests.
+
+The keys are the published Polyglot keys of the positions, so the book does not
+depend on the key calculation being tested. Entries are 16 big-endian bytes,
+sorted by key: key, move, weight and learn.
+"""
+
+import struct
+from pathlib import Path
+
+START = 0x463B96181691FC9C  # starting position
+AFTER_E4 = 0x823C9B50FD114196  # 1. e4
+AFTER_E4_D5 = 0x0756B94461C50FB0  # 1. e4 d5
+
+
+def encode_move(uci):
+    files = "abcdefgh"
+    promotions = " nbrq"
+    from_file, from_row = files.index(uci[0]), int(uci[1]) - 1
+    to_file, to_row = files.index(uci[2]), int(uci[3]) - 1
+    promotion = promotions.index(uci[4]) if len(uci) > 4 else 0
+    return to_file | to_row << 3 | from_file << 6 | from_row << 9 | promotion << 12
+
+
+ENTRIES = [
+    (START, "e2e4", 10),
+    (START, "d2d4", 5),
+    (START, "g1f3", 1),
+    # Not legal, the reader has to skip it.
+    (START, "e2e5", 50),
+    (AFTER_E4, "c7c5", 3),
+    (AFTER_E4, "e7e5", 3),
+    (AFTER_E4, "d7d5", 0),
+    (AFTER_E4_D5, "e4d5", 1),
+]
+
+book = b"".join(
+    struct.pack(">QHHI", key, encode_move(uci), weight, 0)
+    for key, uci, weight in sorted(ENTRIES, key=lambda entry: entry[0])
+)
+Path(__file__).with_name("small_book.bin").write_bytes(book)
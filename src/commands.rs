@@ -0,0 +1,288 @@
+use crate::search::SearchPlayer;
+use game_board::BoardRenderer;
+use simple_chess::chess_game_state_analyzer::GameState;
+use simple_chess::clock::TimeControlError;
+use simple_chess::codec::forsyth_edwards_notation::{
+    build_game_from_string, encode_game_as_string,
+};
+use simple_chess::codec::long_algebraic_notation::{
+    build_game_from_long_algebraic_notation, encode_game, encode_move_as_long_algebraic_notation,
+};
+use simple_chess::codec::portable_game_notation::build_game_from_pgn;
+use simple_chess::player::{Player, RandomPlayer};
+use simple_chess::tournament::{parse_openings, Entrant, Format, Sprt, Tournament};
+use simple_chess::variant::Standard;
+use simple_chess::ChessGame;
+use std::io::Read;
+
+pub const USAGE: &str = "Commands:
+  fen-to-board <FEN>       draw the position
+  lan-to-fen <moves>       play moves in long algebraic notation and print the FEN
+  pgn-to-lan [file]        print the moves of a PGN game, read from stdin by default, as LAN
+  legal-moves <FEN>        list the legal moves of the position as LAN
+  state <FEN>              print check, checkmate, stalemate and draw claims
+  tournament <players>     play the players, random or search:DEPTH, against each other
+    --rounds N             times every pairing plays each opening with both colors, 1 by default
+    --openings <file>      FEN or EPD starting positions, one per line
+    --gauntlet             only pair the first player with the others
+    --concurrency N        games played at the same time, 1 by default
+    --max-moves N          half moves after which a game is a draw
+    --time-control <tc>    time per game as in the PGN TimeControl tag, such as 60+1
+    --seed N               seed for the random players
+    --pgn <file>           append finished games to the file and skip games already in it
+    --sprt ELO0,ELO1       stop once an SPRT of the first player against the second decides";
+
+/// Runs a conversion or inspection command.
+///
+/// # Arguments
+///
+/// * `command` - The name of the command, such as `fen-to-board`.
+/// * `args` - The arguments that follow the command, joined with spaces so
+///   FEN strings and move lists do not need quoting.
+///
+/// # Returns
+///
+/// - `Some(Ok(String))` with the output of the command.
+/// - `Some(Err(String))` with the codec error message if the input is bad.
+/// - `None` if `command` is not a known command.
+pub fn run(command: &str, args: &[String]) -> Option<Result<String, String>> {
+    let input = args.join(" ");
+    let result = match command {
+        "fen-to-board" => fen_to_board(&input),
+        "lan-to-fen" => lan_to_fen(&input),
+        "pgn-to-lan" => pgn_to_lan(args.first()),
+        "legal-moves" => legal_moves(&input),
+        "state" => state(&input),
+        "tournament" => tournament(args),
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn fen_to_board(fen: &str) -> Result<String, String> {
+    let game = read_fen(fen)?;
+    Ok(BoardRenderer::new()
+        .set_show_labels(true)
+        .render(game.get_board()))
+}
+
+fn lan_to_fen(moves: &str) -> Result<String, String> {
+    let game = build_game_from_long_algebraic_notation(moves).map_err(|error| error.reason)?;
+    Ok(encode_game_as_string(&game))
+}
+
+fn pgn_to_lan(path: Option<&String>) -> Result<String, String> {
+    let mut pgn = String::new();
+    match path {
+        Some(path) => {
+            pgn = std::fs::read_to_string(path).map_err(|error| format!("{}: {}", path, error))?
+        }
+        None => {
+            std::io::stdin()
+                .read_to_string(&mut pgn)
+                .map_err(|error| error.to_string())?;
+        }
+    }
+    let game = build_game_from_pgn(&pgn).map_err(|error| error.reason)?;
+    Ok(encode_game(&game))
+}
+
+fn legal_moves(fen: &str) -> Result<String, String> {
+    let mut game = read_fen(fen)?;
+    let legal_moves = match game.get_game_state() {
+        GameState::InProgress { legal_moves, .. } | GameState::Check { legal_moves, .. } => {
+            legal_moves
+        }
+        _ => Vec::new(),
+    };
+    Ok(legal_moves
+        .iter()
+        .map(encode_move_as_long_algebraic_notation)
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+fn state(fen: &str) -> Result<String, String> {
+    let mut game = read_fen(fen)?;
+    let mut state = match game.get_game_state() {
+        GameState::InProgress { turn, .. } => format!("In progress, {} to move", turn),
+        GameState::Check { turn, .. } => format!("Check, {} to move", turn),
+        GameState::Checkmate { winner } => format!("Checkmate, {} wins", winner),
+        GameState::Stalemate => String::from("Stalemate"),
+        GameState::VariantWin { winner } => format!("{} wins", winner),
+        GameState::VariantDraw => String::from("Draw"),
+    };
+    if let Some(reason) = game.can_claim_draw() {
+        state.push_str(&format!("\nDraw can be claimed by {:?}", reason));
+    }
+    Ok(state)
+}
+
+fn tournament(args: &[String]) -> Result<String, String> {
+    let mut entrants = Vec::new();
+    let mut openings = vec![ChessGame::new()];
+    let mut tournament_options = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            entrants.push(parse_entrant(arg)?);
+            continue;
+        }
+        if arg == "--gauntlet" {
+            tournament_options.push((arg.as_str(), ""));
+            continue;
+        }
+        let value = args.next().ok_or(format!("Missing value for {}", arg))?;
+        if arg == "--openings" {
+            let text =
+                std::fs::read_to_string(value).map_err(|error| format!("{}: {}", value, error))?;
+            openings = parse_openings(&text, Standard).map_err(|error| error.to_string())?;
+        } else {
+            tournament_options.push((arg.as_str(), value.as_str()));
+        }
+    }
+
+    let mut tournament = Tournament::new(entrants, openings);
+    for (option, value) in tournament_options {
+        let number = || {
+            value
+                .parse::<usize>()
+                .map_err(|_| format!("{} must be an unsigned integer", option))
+        };
+        tournament = match option {
+            "--gauntlet" => tournament.set_format(Format::Gauntlet),
+            "--rounds" => tournament.set_rounds(number()?),
+            "--concurrency" => tournament.set_concurrency(number()?),
+            "--max-moves" => tournament.set_max_moves(Some(number()?)),
+            "--seed" => tournament.set_seed(number()? as u64),
+            "--pgn" => tournament.set_pgn_log(Some(value.into())),
+            "--time-control" => {
+                let time_control = value
+                    .parse()
+                    .map_err(|error: TimeControlError| error.reason)?;
+                tournament.set_time_control(Some(time_control))
+            }
+            "--sprt" => {
+                let bounds: Vec<f64> = value
+                    .split(',')
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| {
+                    String::from("--sprt must be two Elo differences, such as 0,10")
+                })?;
+                match bounds[..] {
+                    [elo0, elo1] => tournament.set_sprt(Some(Sprt::new(elo0, elo1))),
+                    _ => {
+                        return Err(String::from(
+                            "--sprt must be two Elo differences, such as 0,10",
+                        ))
+                    }
+                }
+            }
+            _ => return Err(format!("Unknown option {}", option)),
+        };
+    }
+
+    let result = tournament.run().map_err(|error| error.reason)?;
+    Ok(result.get_cross_table().trim_end().to_string())
+}
+
+fn parse_entrant(name: &str) -> Result<Entrant, String> {
+    let create_player: Box<dyn Fn() -> Box<dyn Player> + Send + Sync> = match name.split_once(':') {
+        None if name == "random" => Box::new(|| Box::new(RandomPlayer::new(0))),
+        Some(("search", depth)) => match depth.parse::<usize>() {
+            Ok(depth) if depth > 0 => Box::new(move || Box::new(SearchPlayer::new(depth))),
+            _ => return Err(format!("Invalid search depth in {}", name)),
+        },
+        _ => {
+            return Err(format!(
+                "Unknown player {}, use random or search:DEPTH",
+                name
+            ))
+        }
+    };
+    Ok(Entrant::new(name, create_player))
+}
+
+fn read_fen(fen: &str) -> Result<ChessGame, String> {
+    if fen.is_empty() {
+        return Err(String::from("Missing FEN"));
+    }
+    build_game_from_string(fen).map_err(|error| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_command(command: &str, input: &str) -> Result<String, String> {
+        let args: Vec<String> = input.split(' ').map(String::from).collect();
+        run(command, &args).unwrap()
+    }
+
+    #[test]
+    fn lan_to_fen_plays_the_moves() {
+        assert_eq!(
+            Ok(String::from(
+                "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2"
+            )),
+            run_command("lan-to-fen", "e2e4 e7e5")
+        );
+        assert_eq!(
+            Err(String::from("Unable to make move e2e5")),
+            run_command("lan-to-fen", "e2e5")
+        );
+    }
+
+    #[test]
+    fn legal_moves_are_listed_as_lan() {
+        let moves = run_command("legal-moves", "4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert_eq!(5, moves.lines().count());
+        assert!(moves.lines().any(|m| m == "Ke1d2"));
+    }
+
+    #[test]
+    fn state_reports_mate_and_draw_claims() {
+        assert_eq!(
+            Ok(String::from("Checkmate, Black wins")),
+            run_command(
+                "state",
+                "rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3"
+            )
+        );
+        assert_eq!(
+            Ok(String::from(
+                "In progress, White to move\nDraw can be claimed by InsufficientPieces"
+            )),
+            run_command("state", "4k3/8/8/8/8/8/8/4K3 w - - 0 1")
+        );
+    }
+
+    #[test]
+    fn tournament_prints_the_cross_table() {
+        let table = run_command(
+            "tournament",
+            "random search:1 --max-moves 4 --concurrency 2 --sprt 0,10 --time-control 60+1",
+        )
+        .unwrap();
+        assert!(table.starts_with("Rank"));
+        assert!(table.contains("search:1"));
+        assert!(table.ends_with("Continue"));
+    }
+
+    #[test]
+    fn bad_input_reports_the_codec_error() {
+        assert!(run_command("state", "not a fen").is_err());
+        assert_eq!(
+            Err(String::from("Missing FEN")),
+            run_command("fen-to-board", "")
+        );
+        assert!(run("unknown", &[]).is_none());
+        assert!(run_command("tournament", "random chess").is_err());
+        assert!(run_command("tournament", "random").is_err());
+        assert_eq!(
+            Err(String::from("Invalid time control 60+")),
+            run_command("tournament", "random random --time-control 60+")
+        );
+    }
+}
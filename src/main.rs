@@ -1,81 +1,147 @@
-use game_board::Board;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
-use simple_chess::chess_game_state_analyzer::GameState;
-use simple_chess::codec::long_algebraic_notation::encode_move_as_long_algebraic_notation;
-use simple_chess::piece::ChessPiece;
-use simple_chess::Color::{Black, White};
-use simple_chess::{ChessGame, ChessMoveType, Color};
+mod commands;
+mod play;
+mod search;
 
-fn main() {
-    let mut game = ChessGame::new();
+use game_board::{PieceStyle, Theme};
+use play::{play, Opponent, Settings};
+use rand::{thread_rng, Rng};
+use simple_chess::Color;
+use std::io::{self, BufRead, Write};
+use std::process::exit;
 
-    loop {
-        let state = game.get_game_state();
+const USAGE: &str = "Usage: chess_cli [options]
+       chess_cli <command> [arguments]
 
-        if let Some(_reason) = game.can_claim_draw() {
-            break;
-        }
+Options:
+  --color white|black            the color you play, asked for if not given
+  --opponent random|search       the computer player, asked for if not given
+  --depth N                      how many moves the search looks ahead, 2 by default
+  --seed N                       seed for the random player
+  --theme 256|truecolor|plain    the board colors, 256 by default
+  --letters                      draw pieces as letters instead of symbols";
 
-        let next_move = match state {
-            GameState::InProgress { legal_moves, turn } => match turn {
-                Color::White => pick_random_move(legal_moves),
-                Color::Black => pick_random_move(legal_moves),
-            },
-            GameState::Check { legal_moves, turn } => match turn {
-                Color::White => pick_random_move(legal_moves),
-                Color::Black => pick_random_move(legal_moves),
-            },
-            GameState::Checkmate { winner: _winner } => {
-                break;
-            }
-            GameState::Stalemate => {
-                break;
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some((command, command_args)) = args.split_first() {
+        if let Some(result) = commands::run(command, command_args) {
+            match result {
+                Ok(output) => println!("{}", output),
+                Err(message) => {
+                    eprintln!("{}", message);
+                    exit(1);
+                }
             }
-        };
-
-        game.make_move(next_move);
-    }
-
-    let mut current_turn = White;
-    let mut current_turn_number = 1;
-    for m in game.get_moves() {
-        if current_turn == White {
-            print!("{}.", current_turn_number);
+            return;
         }
+    }
 
-        let move_str = encode_move_as_long_algebraic_notation(m);
-        print!("{}", move_str);
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+    let stdout = io::stdout();
+    let mut output = stdout.lock();
 
-        if current_turn == Black {
-            print!("\n");
-            current_turn_number = current_turn_number + 1;
-        } else {
-            print!(" ");
+    let settings = match parse_settings(args.into_iter(), &mut input, &mut output) {
+        Ok(settings) => settings,
+        Err(message) => {
+            eprintln!("{}\n{}\n\n{}", message, USAGE, commands::USAGE);
+            exit(2);
         }
-        current_turn = current_turn.opposite();
+    };
+
+    if let Err(error) = play(settings, &mut input, &mut output) {
+        eprintln!("{}", error);
+        exit(1);
     }
 }
 
-fn _list_moves_and_select_one(
-    moves: Vec<ChessMoveType>,
-    board: &Board<ChessPiece>,
-) -> ChessMoveType {
-    println!("{}", board);
-    for (index, chess_move) in moves.iter().enumerate() {
-        println!("{}. {}", index, chess_move);
+/// Builds the settings from the command line, asking for the color and the
+/// opponent when they are not given.
+fn parse_settings(
+    mut args: impl Iterator<Item = String>,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<Settings, String> {
+    let mut human = None;
+    let mut opponent = None;
+    let mut depth = 2;
+    let mut seed = None;
+    let mut theme = Theme::Ansi256;
+    let mut piece_style = PieceStyle::Glyph;
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("Missing value for {}", arg));
+        match arg.as_str() {
+            "--color" => human = Some(parse_color(&value()?)?),
+            "--opponent" => opponent = Some(value()?),
+            "--depth" => {
+                depth = match value()?.parse() {
+                    Ok(depth) if depth > 0 => depth,
+                    _ => return Err("Depth must be a positive integer".to_string()),
+                }
+            }
+            "--seed" => {
+                seed = Some(
+                    value()?
+                        .parse()
+                        .map_err(|_| "Seed must be an unsigned integer".to_string())?,
+                )
+            }
+            "--theme" => {
+                theme = match value()?.as_str() {
+                    "256" => Theme::Ansi256,
+                    "truecolor" => Theme::TrueColor,
+                    "plain" => Theme::Plain,
+                    other => return Err(format!("Unknown theme {}", other)),
+                }
+            }
+            "--letters" => piece_style = PieceStyle::Letter,
+            "--help" | "-h" => return Err("Play chess against the computer".to_string()),
+            other => return Err(format!("Unknown option {}", other)),
+        }
     }
 
-    let mut input = String::new();
-    std::io::stdin()
-        .read_line(&mut input)
-        .expect("Failed to read line");
-    let input: usize = input.trim().parse().expect("Please enter a valid number");
+    let human = match human {
+        Some(human) => human,
+        None => parse_color(&ask(input, output, "Play as white or black? ")?)?,
+    };
+    let opponent = match opponent {
+        Some(opponent) => opponent,
+        None => ask(input, output, "Play against random or search? ")?,
+    };
+    let opponent = match opponent.as_str() {
+        "random" => Opponent::Random,
+        "search" => Opponent::Search { depth },
+        other => return Err(format!("Unknown opponent {}", other)),
+    };
 
-    moves[input]
+    Ok(Settings {
+        human,
+        opponent,
+        seed: seed.unwrap_or_else(|| thread_rng().gen()),
+        theme,
+        piece_style,
+    })
 }
 
-fn pick_random_move(moves: Vec<ChessMoveType>) -> ChessMoveType {
-    let mut rng = thread_rng();
-    *moves.choose(&mut rng).expect("No moves given")
+fn parse_color(color: &str) -> Result<Color, String> {
+    match color.to_ascii_lowercase().as_str() {
+        "white" | "w" => Ok(Color::White),
+        "black" | "b" => Ok(Color::Black),
+        other => Err(format!("Unknown color {}", other)),
+    }
+}
+
+fn ask(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    question: &str,
+) -> Result<String, String> {
+    write!(output, "{}", question).map_err(|error| error.to_string())?;
+    output.flush().map_err(|error| error.to_string())?;
+    let mut answer = String::new();
+    match input.read_line(&mut answer) {
+        Ok(0) => Err("No answer given".to_string()),
+        Ok(_) => Ok(answer.trim().to_ascii_lowercase()),
+        Err(error) => Err(error.to_string()),
+    }
 }
@@ -0,0 +1,339 @@
+use crate::search::{best_move, evaluate_material, random_move};
+use game_board::{BoardRenderer, Coord, Orientation, PieceStyle, Theme, TurnBasedGame};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use simple_chess::chess_game_state_analyzer::is_in_check;
+use simple_chess::codec::algebraic_notation::{
+    decode_move_from_algebraic_notation, encode_move_as_algebraic_notation,
+};
+use simple_chess::codec::forsyth_edwards_notation::encode_game_as_string;
+use simple_chess::codec::long_algebraic_notation::encode_move_as_long_algebraic_notation;
+use simple_chess::codec::portable_game_notation::encode_game_as_pgn;
+use simple_chess::codec::universal_chess_interface::decode_move_from_uci;
+use simple_chess::piece::PieceType;
+use simple_chess::{ChessGame, ChessMoveType, Color, Termination};
+use std::io::{self, BufRead, Write};
+
+const HELP: &str = "Enter a move as SAN (Nf3), UCI (g1f3) or LAN (Ng1f3), or one of:
+  moves          list the legal moves
+  fen            print the position as FEN
+  flip           turn the board around
+  undo           take back your last move
+  resign         give up the game
+  draw           claim or offer a draw
+  save [path]    write the game as PGN, to game.pgn by default
+  help           show this message
+  quit           leave without finishing the game";
+
+/// Who the human plays against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opponent {
+    /// Plays a random legal move.
+    Random,
+    /// Searches `depth` moves ahead, counting material.
+    Search { depth: usize },
+}
+
+/// The options for an interactive game.
+#[derive(Debug, Clone, Copy)]
+pub struct Settings {
+    pub human: Color,
+    pub opponent: Opponent,
+    pub seed: u64,
+    pub theme: Theme,
+    pub piece_style: PieceStyle,
+}
+
+/// Plays a game between a human, reading commands from `input`, and the
+/// computer, until the game is over or the human quits.
+///
+/// # Arguments
+///
+/// * `settings` - The human's color, the opponent and how to draw the board.
+/// * `input` - Where the human's moves and commands are read from.
+/// * `output` - Where the board and messages are written to.
+pub fn play(
+    settings: Settings,
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<ChessGame> {
+    let mut game = ChessGame::new();
+    let mut rng = StdRng::seed_from_u64(settings.seed);
+    let mut orientation = match settings.human {
+        Color::White => Orientation::Standard,
+        Color::Black => Orientation::Flipped,
+    };
+
+    loop {
+        if game.update_result().is_some() {
+            break;
+        }
+
+        if game.get_current_players_turn() != settings.human {
+            // The computer claims a draw as soon as it can, the human has to
+            // ask for one.
+            if let Ok(reason) = game.claim_draw() {
+                writeln!(output, "The computer claims a draw by {:?}", reason)?;
+                continue;
+            }
+            let computer_move = match settings.opponent {
+                Opponent::Random => random_move(&mut game, &mut rng),
+                Opponent::Search { depth } => best_move(&mut game, depth, &evaluate_material),
+            }
+            .expect("The game is not over so there is a legal move");
+            writeln!(
+                output,
+                "Computer plays {}",
+                encode_move_as_algebraic_notation(&computer_move, game.get_board())
+            )?;
+            game.make_move(computer_move);
+            continue;
+        }
+
+        writeln!(output, "{}", render(&game, settings, orientation))?;
+        if let Some(reason) = game.can_claim_draw() {
+            writeln!(output, "You can claim a draw by {:?}, type draw", reason)?;
+        }
+        write!(output, "{} to move> ", game.get_current_players_turn())?;
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(output)?;
+            break;
+        }
+        let mut words = line.split_whitespace();
+        let command = match words.next() {
+            Some(command) => command,
+            None => continue,
+        };
+
+        match command {
+            "help" => writeln!(output, "{}", HELP)?,
+            "moves" => {
+                let moves: Vec<String> = game
+                    .legal_moves()
+                    .iter()
+                    .map(|m| encode_move_as_algebraic_notation(m, game.get_board()))
+                    .collect();
+                writeln!(output, "{}", moves.join(" "))?;
+            }
+            "fen" => writeln!(output, "{}", encode_game_as_string(&game))?,
+            "flip" => {
+                orientation = match orientation {
+                    Orientation::Standard => Orientation::Flipped,
+                    Orientation::Flipped => Orientation::Standard,
+                }
+            }
+            "undo" => {
+                let human_moves = match settings.human {
+                    Color::White => game.get_moves().len(),
+                    Color::Black => game.get_moves().len() / 2,
+                };
+                if human_moves == 0 {
+                    writeln!(output, "There is no move to take back")?;
+                    continue;
+                }
+                game.undo_last_move();
+                while game.get_current_players_turn() != settings.human {
+                    game.undo_last_move();
+                }
+            }
+            "resign" => game
+                .resign(settings.human)
+                .expect("The game is not over so it can be resigned"),
+            "draw" => draw(&mut game, settings.human, output)?,
+            "save" => {
+                let path = words.next().unwrap_or("game.pgn");
+                let pgn = encode_game_as_pgn(&game, &pgn_tags(settings));
+                match std::fs::write(path, pgn) {
+                    Ok(()) => writeln!(output, "Saved the game to {}", path)?,
+                    Err(error) => writeln!(output, "Unable to save the game: {}", error)?,
+                }
+            }
+            "quit" | "exit" => break,
+            _ => match decode_move(&mut game, command) {
+                Some(chess_move) => game.make_move(chess_move),
+                None => writeln!(
+                    output,
+                    "Unable to understand {}, type help for the commands",
+                    command
+                )?,
+            },
+        }
+    }
+
+    writeln!(output, "{}", render(&game, settings, orientation))?;
+    writeln!(output, "{}", describe_result(&game))?;
+    Ok(game)
+}
+
+/// Claims a draw for the human when the rules allow it, otherwise offers one
+/// to the computer.
+fn draw(game: &mut ChessGame, human: Color, output: &mut impl Write) -> io::Result<()> {
+    if let Ok(reason) = game.claim_draw() {
+        return writeln!(output, "Draw claimed by {:?}", reason);
+    }
+    game.offer_draw(human)
+        .expect("The game is not over so a draw can be offered");
+    // The computer is happy to split the point when it is behind.
+    if evaluate_material(game) > 0 {
+        game.accept_draw(human.opposite())
+            .expect("A draw has been offered");
+        writeln!(output, "The computer accepts the draw")
+    } else {
+        writeln!(output, "The computer declines the draw")
+    }
+}
+
+/// Reads a move in SAN, UCI or LAN, whichever matches a legal move.
+fn decode_move(game: &mut ChessGame, notation: &str) -> Option<ChessMoveType> {
+    if let Ok(chess_move) = decode_move_from_algebraic_notation(game, notation) {
+        return Some(chess_move);
+    }
+    if let Ok(chess_move) = decode_move_from_uci(game, notation) {
+        return Some(chess_move);
+    }
+    game.legal_moves().into_iter().find(|chess_move| {
+        encode_move_as_long_algebraic_notation(chess_move).eq_ignore_ascii_case(notation)
+    })
+}
+
+fn render(game: &ChessGame, settings: Settings, orientation: Orientation) -> String {
+    let last_move = game.get_last_move().and_then(|last_move| {
+        last_move
+            .get_original_position()
+            .map(|from| (from, last_move.get_new_position()))
+    });
+
+    let turn = game.get_current_players_turn();
+    let mut highlighted_squares = Vec::new();
+    if is_in_check(turn, game.get_board()) {
+        let king = game
+            .get_board()
+            .find(|piece| piece.get_piece_type() == PieceType::King && piece.get_color() == turn);
+        highlighted_squares.extend(king.map(Coord::from));
+    }
+
+    BoardRenderer::new()
+        .set_theme(settings.theme)
+        .set_piece_style(settings.piece_style)
+        .set_orientation(orientation)
+        .set_show_labels(true)
+        .set_last_move(last_move)
+        .set_highlighted_squares(highlighted_squares)
+        .render(game.get_board())
+}
+
+fn pgn_tags(settings: Settings) -> Vec<(&'static str, &'static str)> {
+    let computer = match settings.opponent {
+        Opponent::Random => "Computer (random)",
+        Opponent::Search { .. } => "Computer (search)",
+    };
+    let (white, black) = match settings.human {
+        Color::White => ("Human", computer),
+        Color::Black => (computer, "Human"),
+    };
+    vec![("White", white), ("Black", black)]
+}
+
+fn describe_result(game: &ChessGame) -> String {
+    let (result, termination) = match (game.get_result(), game.get_termination()) {
+        (Some(result), Some(termination)) => (result, termination),
+        _ => return "Game abandoned *".to_string(),
+    };
+    let reason = match (termination, result.get_winner()) {
+        (Termination::Checkmate, Some(winner)) => format!("Checkmate, {} wins", winner),
+        (Termination::Resignation, Some(winner)) => format!("{} resigns", winner.opposite()),
+        (Termination::Stalemate, _) => "Draw by stalemate".to_string(),
+        (Termination::DrawAgreement, _) => "Draw agreed".to_string(),
+        (Termination::DrawClaim(reason), _) => format!("Draw by {:?}", reason),
+        (Termination::TimeForfeit, _) => "Lost on time".to_string(),
+        _ => "Game over".to_string(),
+    };
+    format!("{} {}", reason, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use simple_chess::codec::forsyth_edwards_notation::build_game_from_string;
+    use simple_chess::DrawReason::InsufficientPieces;
+
+    fn settings(human: Color) -> Settings {
+        Settings {
+            human,
+            opponent: Opponent::Search { depth: 1 },
+            seed: 0,
+            theme: Theme::Plain,
+            piece_style: PieceStyle::Letter,
+        }
+    }
+
+    fn play_script(human: Color, script: &str) -> (ChessGame, String) {
+        let mut output = Vec::new();
+        let game = play(settings(human), &mut script.as_bytes(), &mut output).unwrap();
+        (game, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn accepts_san_uci_and_lan_moves() {
+        let (game, output) = play_script(Color::White, "e4\ng1f3\nBf1c4\n");
+        assert_eq!(6, game.get_moves().len());
+        assert_eq!(3, output.matches("Computer plays").count());
+        assert!(output.ends_with("Game abandoned *\n"));
+    }
+
+    #[test]
+    fn undo_takes_back_the_humans_move() {
+        let (game, _) = play_script(Color::White, "e4\nundo\n");
+        assert_eq!(0, game.get_moves().len());
+
+        let (game, output) = play_script(Color::Black, "undo\n");
+        assert_eq!(1, game.get_moves().len());
+        assert!(output.contains("There is no move to take back"));
+    }
+
+    #[test]
+    fn rejects_unknown_input() {
+        let (game, output) = play_script(Color::White, "e5\n");
+        assert_eq!(0, game.get_moves().len());
+        assert!(output.contains("Unable to understand e5"));
+    }
+
+    #[test]
+    fn resigning_ends_the_game() {
+        let (_, output) = play_script(Color::Black, "resign\n");
+        assert!(output.ends_with("Black resigns 1-0\n"));
+    }
+
+    #[test]
+    fn declined_draw_offers_leave_the_game_in_progress() {
+        let path = std::env::temp_dir().join("chess_cli_play_draw_test.pgn");
+        let script = format!("e4\ndraw\nsave {}\n", path.display());
+        let (game, output) = play_script(Color::White, &script);
+        let pgn = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(output.contains("The computer declines the draw"));
+        assert_eq!(None, game.get_result());
+        assert!(pgn.contains("[Result \"*\"]"));
+    }
+
+    #[test]
+    fn draw_claims_when_the_rules_allow_it() {
+        let mut output = Vec::new();
+        let mut game = build_game_from_string("k7/8/8/8/8/8/8/K7 w - - 0 1").unwrap();
+        draw(&mut game, Color::White, &mut output).unwrap();
+
+        assert_eq!(
+            "Draw claimed by InsufficientPieces\n",
+            String::from_utf8(output).unwrap()
+        );
+        assert_eq!(
+            Some(Termination::DrawClaim(InsufficientPieces)),
+            game.get_termination()
+        );
+        assert_eq!(None, game.get_draw_offer());
+    }
+}
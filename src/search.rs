@@ -0,0 +1,178 @@
+use game_board::{Outcome, TurnBasedGame};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use simple_chess::piece::PieceType;
+use simple_chess::player::Player;
+use simple_chess::variant::Variant;
+use simple_chess::{ChessGame, ChessMoveType};
+
+/// The score of a won position, larger than any evaluation.
+const WIN_SCORE: i32 = 1_000_000;
+
+/// Picks one of the legal moves uniformly at random.
+///
+/// # Returns
+///
+/// The chosen move, `None` if there are no legal moves.
+pub fn random_move<G: TurnBasedGame>(game: &mut G, rng: &mut StdRng) -> Option<G::Move> {
+    game.legal_moves().choose(rng).cloned()
+}
+
+/// Finds the best move with a depth limited negamax search with alpha-beta
+/// pruning.
+///
+/// # Arguments
+///
+/// * `game` - The position to search, restored before returning.
+/// * `depth` - The number of moves to look ahead, at least 1.
+/// * `evaluate` - Scores a position for the player whose turn it is.
+///
+/// # Returns
+///
+/// The best move found, the first one of equally good moves, or `None` if
+/// there are no legal moves.
+pub fn best_move<G: TurnBasedGame>(
+    game: &mut G,
+    depth: usize,
+    evaluate: &impl Fn(&G) -> i32,
+) -> Option<G::Move> {
+    let mut best: Option<(i32, G::Move)> = None;
+    for game_move in game.legal_moves() {
+        let alpha = best.as_ref().map_or(-WIN_SCORE * 2, |(score, _)| *score);
+        game.apply(game_move.clone());
+        let score = -negamax(game, depth.max(1) - 1, -WIN_SCORE * 2, -alpha, evaluate);
+        game.undo();
+        if best
+            .as_ref()
+            .is_none_or(|(best_score, _)| score > *best_score)
+        {
+            best = Some((score, game_move));
+        }
+    }
+    best.map(|(_, game_move)| game_move)
+}
+
+/// Scores a chess position by counting material, in centipawns, for the
+/// player whose turn it is.
+pub fn evaluate_material<V: Variant>(game: &ChessGame<V>) -> i32 {
+    let turn = game.get_current_players_turn();
+    game.get_board()
+        .iter_pieces()
+        .map(|(_, _, piece)| {
+            let value = match piece.get_piece_type() {
+                PieceType::Pawn => 100,
+                PieceType::Knight | PieceType::Bishop => 300,
+                PieceType::Rook => 500,
+                PieceType::Archbishop => 700,
+                PieceType::Chancellor => 800,
+                PieceType::Queen => 900,
+                _ => 0,
+            };
+            if piece.get_color() == turn {
+                value
+            } else {
+                -value
+            }
+        })
+        .sum()
+}
+
+/// A `Player` that plays the `best_move` of a material counting search.
+pub struct SearchPlayer {
+    depth: usize,
+}
+
+impl SearchPlayer {
+    pub fn new(depth: usize) -> Self {
+        Self { depth }
+    }
+}
+
+impl<V: Variant> Player<V> for SearchPlayer {
+    fn choose_move(&mut self, game: &ChessGame<V>) -> ChessMoveType {
+        best_move(&mut game.clone(), self.depth, &evaluate_material)
+            .expect("The game is not over so there is a legal move")
+    }
+}
+
+fn negamax<G: TurnBasedGame>(
+    game: &mut G,
+    depth: usize,
+    mut alpha: i32,
+    beta: i32,
+    evaluate: &impl Fn(&G) -> i32,
+) -> i32 {
+    match game.outcome() {
+        // Wins found with more depth left are closer, so prefer them.
+        Some(Outcome::Win(winner)) if winner == game.current_player() => {
+            return WIN_SCORE + depth as i32
+        }
+        Some(Outcome::Win(_)) => return -WIN_SCORE - depth as i32,
+        Some(Outcome::Draw) => return 0,
+        None => {}
+    }
+    if depth == 0 {
+        return evaluate(game);
+    }
+
+    let mut best = -WIN_SCORE * 2;
+    for game_move in game.legal_moves() {
+        game.apply(game_move);
+        let score = -negamax(game, depth - 1, -beta, -alpha, evaluate);
+        game.undo();
+
+        best = best.max(score);
+        alpha = alpha.max(score);
+        if alpha >= beta {
+            break;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use simple_chess::codec::forsyth_edwards_notation::build_game_from_string;
+    use simple_chess::codec::universal_chess_interface::encode_move_as_uci;
+
+    #[test]
+    fn finds_mate_in_one() {
+        let mut game = build_game_from_string("k7/pp6/8/8/8/8/8/K6R w - - 0 1").unwrap();
+        let chess_move = best_move(&mut game, 2, &evaluate_material).unwrap();
+        assert_eq!("h1h8", encode_move_as_uci(&chess_move));
+        assert_eq!(0, game.get_moves().len());
+    }
+
+    #[test]
+    fn prefers_the_shorter_mate() {
+        let mut game = build_game_from_string("k7/pp6/8/8/8/8/1R6/K6R w - - 0 1").unwrap();
+        let chess_move = best_move(&mut game, 3, &evaluate_material).unwrap();
+        assert_eq!("h1h8", encode_move_as_uci(&chess_move));
+    }
+
+    #[test]
+    fn wins_material() {
+        let mut game = build_game_from_string("4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1").unwrap();
+        let chess_move = best_move(&mut game, 1, &evaluate_material).unwrap();
+        assert_eq!("d1d5", encode_move_as_uci(&chess_move));
+    }
+
+    #[test]
+    fn evaluates_material_for_the_side_to_move() {
+        let game = build_game_from_string("4k3/8/8/3q4/8/8/8/3RK3 b - - 0 1").unwrap();
+        assert_eq!(400, evaluate_material(&game));
+        assert_eq!(0, evaluate_material(&ChessGame::new()));
+    }
+
+    #[test]
+    fn random_move_is_legal_and_seeded() {
+        let mut game = ChessGame::new();
+        let legal_moves = game.legal_moves();
+        let first = random_move(&mut game, &mut StdRng::seed_from_u64(3)).unwrap();
+        let second = random_move(&mut game, &mut StdRng::seed_from_u64(3)).unwrap();
+        assert!(legal_moves.contains(&first));
+        assert_eq!(first, second);
+    }
+}